@@ -0,0 +1,337 @@
+//! MediChain Node Client for the API
+//!
+//! © 2025 Trustware. All rights reserved.
+//!
+//! Reads on-chain state the API must never keep its own copy of:
+//! - Consent directives (`AccessControl::Consents`), so every API instance
+//!   applies the directives the patient last set on-chain
+//! - Access grants (`AccessControl::ActiveAccess`), so only providers the
+//!   patient granted Regular or Full access count as treating providers
+
+use pallet_access_control::{AccessType, ConsentDirectives};
+use parity_scale_codec::Decode;
+use serde::{de::DeserializeOwned, Deserialize};
+use sp_core::crypto::{AccountId32, Ss58Codec};
+use sp_core::hashing::{blake2_128, twox_128};
+use std::time::Duration;
+
+/// Node client configuration
+#[derive(Clone)]
+pub struct ChainClient {
+    /// HTTP JSON-RPC endpoint of a MediChain node (e.g., "http://localhost:9944")
+    rpc_url: String,
+    /// HTTP client
+    client: reqwest::Client,
+}
+
+/// JSON-RPC response envelope
+#[derive(Debug, Deserialize)]
+struct RpcResponse<T> {
+    result: Option<T>,
+    error: Option<RpcError>,
+}
+
+/// JSON-RPC error object
+#[derive(Debug, Deserialize)]
+struct RpcError {
+    message: String,
+}
+
+/// Block header, as returned by `chain_getHeader` (only the number is read)
+#[derive(Debug, Deserialize)]
+struct Header {
+    /// Hex-encoded block number
+    number: String,
+}
+
+/// Access grant as stored in `AccessControl::ActiveAccess`
+///
+/// Mirrors `pallet_access_control::AccessLog` for the runtime's 32-byte
+/// accounts and `u32` block numbers.
+#[derive(Debug, Decode)]
+struct StoredAccessLog {
+    _accessor: AccountId32,
+    access_type: AccessType,
+    _granted_at: u32,
+    expires_at: Option<u32>,
+    _reason_hash: [u8; 32],
+    revoked: bool,
+}
+
+/// Errors that can occur while reading chain state
+#[derive(Debug)]
+pub enum ChainError {
+    /// Node RPC request failed
+    RequestFailed(String),
+    /// Node returned an RPC error
+    Rpc(String),
+    /// Failed to decode the stored value
+    DecodeError(String),
+    /// Account is not a valid SS58 address
+    InvalidAccount(String),
+    /// Network timeout
+    Timeout,
+}
+
+impl std::fmt::Display for ChainError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::RequestFailed(msg) => write!(f, "Node request failed: {}", msg),
+            Self::Rpc(msg) => write!(f, "Node RPC error: {}", msg),
+            Self::DecodeError(msg) => write!(f, "Failed to decode chain state: {}", msg),
+            Self::InvalidAccount(account) => write!(f, "Invalid account: {}", account),
+            Self::Timeout => write!(f, "Node request timed out"),
+        }
+    }
+}
+
+impl std::error::Error for ChainError {}
+
+impl From<reqwest::Error> for ChainError {
+    fn from(err: reqwest::Error) -> Self {
+        if err.is_timeout() {
+            Self::Timeout
+        } else {
+            Self::RequestFailed(err.to_string())
+        }
+    }
+}
+
+impl ChainClient {
+    /// Create a new node client with default local configuration
+    pub fn new_local() -> Self {
+        Self::new("http://localhost:9944".to_string())
+    }
+
+    /// Create a new node client with a custom RPC endpoint
+    pub fn new(rpc_url: String) -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(10))
+            .build()
+            .expect("Failed to create HTTP client");
+
+        Self { rpc_url, client }
+    }
+
+    /// Consent directives in force for a patient account (defaults if never set)
+    ///
+    /// # Arguments
+    /// * `account` - SS58 address of the patient's on-chain account
+    pub async fn consent_directives(&self, account: &str) -> Result<ConsentDirectives, ChainError> {
+        let account = Self::parse_account(account)?;
+        match self.storage(&Self::consents_key(&account)).await? {
+            Some(value) => Self::decode_directives(&value),
+            None => Ok(ConsentDirectives::default()),
+        }
+    }
+
+    /// Whether `provider` holds a valid Regular or Full grant to `patient`
+    ///
+    /// Same rule as `AccessControl::consent_viewer`: emergency grants, revoked
+    /// grants and grants past their expiry block do not make a treating provider.
+    ///
+    /// # Arguments
+    /// * `patient` - SS58 address of the patient's on-chain account
+    /// * `provider` - SS58 address of the provider's on-chain account
+    pub async fn has_treating_access(
+        &self,
+        patient: &str,
+        provider: &str,
+    ) -> Result<bool, ChainError> {
+        let patient = Self::parse_account(patient)?;
+        let provider = Self::parse_account(provider)?;
+        let Some(value) = self
+            .storage(&Self::active_access_key(&patient, &provider))
+            .await?
+        else {
+            return Ok(false);
+        };
+        let now = self.best_block_number().await?;
+        Self::decode_treating(&value, now)
+    }
+
+    /// Number of the node's best block
+    async fn best_block_number(&self) -> Result<u32, ChainError> {
+        let header: Header = self
+            .rpc("chain_getHeader", serde_json::json!([]))
+            .await?
+            .ok_or_else(|| ChainError::Rpc("Node returned no header".to_string()))?;
+        u32::from_str_radix(header.number.trim_start_matches("0x"), 16)
+            .map_err(|e| ChainError::DecodeError(e.to_string()))
+    }
+
+    /// Hex-encoded value stored under `key`, if any
+    async fn storage(&self, key: &[u8]) -> Result<Option<String>, ChainError> {
+        let key = format!("0x{}", hex::encode(key));
+        self.rpc("state_getStorage", serde_json::json!([key])).await
+    }
+
+    /// Send a JSON-RPC request to the node and return its result
+    async fn rpc<T: DeserializeOwned>(
+        &self,
+        method: &str,
+        params: serde_json::Value,
+    ) -> Result<Option<T>, ChainError> {
+        let response: RpcResponse<Option<T>> = self
+            .client
+            .post(&self.rpc_url)
+            .json(&serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "method": method,
+                "params": params,
+            }))
+            .send()
+            .await?
+            .json()
+            .await?;
+
+        if let Some(error) = response.error {
+            return Err(ChainError::Rpc(error.message));
+        }
+        Ok(response.result.flatten())
+    }
+
+    /// Parse an SS58 address into an on-chain account
+    fn parse_account(account: &str) -> Result<AccountId32, ChainError> {
+        AccountId32::from_ss58check(account)
+            .map_err(|_| ChainError::InvalidAccount(account.to_string()))
+    }
+
+    /// Storage key of `AccessControl::Consents` for `account` (`Blake2_128Concat` hasher)
+    pub fn consents_key(account: &AccountId32) -> Vec<u8> {
+        let account: &[u8] = account.as_ref();
+        [
+            &twox_128(b"AccessControl")[..],
+            &twox_128(b"Consents")[..],
+            &blake2_128(account)[..],
+            account,
+        ]
+        .concat()
+    }
+
+    /// Storage key of `AccessControl::ActiveAccess` for `(patient, accessor)`
+    /// (`Blake2_128Concat` hashers)
+    pub fn active_access_key(patient: &AccountId32, accessor: &AccountId32) -> Vec<u8> {
+        let patient: &[u8] = patient.as_ref();
+        let accessor: &[u8] = accessor.as_ref();
+        [
+            &twox_128(b"AccessControl")[..],
+            &twox_128(b"ActiveAccess")[..],
+            &blake2_128(patient)[..],
+            patient,
+            &blake2_128(accessor)[..],
+            accessor,
+        ]
+        .concat()
+    }
+
+    /// Whether a hex-encoded `AccessLog` is a Regular or Full grant valid at block `now`
+    fn decode_treating(value: &str, now: u32) -> Result<bool, ChainError> {
+        let bytes = hex::decode(value.trim_start_matches("0x"))
+            .map_err(|e| ChainError::DecodeError(e.to_string()))?;
+        let access = StoredAccessLog::decode(&mut &bytes[..])
+            .map_err(|e| ChainError::DecodeError(e.to_string()))?;
+        Ok(
+            matches!(access.access_type, AccessType::Regular | AccessType::Full)
+                && !access.revoked
+                && !access.expires_at.is_some_and(|expires_at| now > expires_at),
+        )
+    }
+
+    /// Decode the directives from a hex-encoded `ConsentRecord`
+    ///
+    /// The record starts with its directives, so the rest (who set them and
+    /// when) is left undecoded.
+    fn decode_directives(value: &str) -> Result<ConsentDirectives, ChainError> {
+        let bytes = hex::decode(value.trim_start_matches("0x"))
+            .map_err(|e| ChainError::DecodeError(e.to_string()))?;
+        ConsentDirectives::decode(&mut &bytes[..])
+            .map_err(|e| ChainError::DecodeError(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use parity_scale_codec::Encode;
+
+    #[test]
+    fn test_consents_key_layout() {
+        let account = AccountId32::new([7u8; 32]);
+        let key = ChainClient::consents_key(&account);
+
+        assert_eq!(key.len(), 16 + 16 + 16 + 32);
+        assert_eq!(&key[..16], &twox_128(b"AccessControl")[..]);
+        assert_eq!(&key[16..32], &twox_128(b"Consents")[..]);
+        assert_eq!(&key[48..], &[7u8; 32][..]);
+    }
+
+    #[test]
+    fn test_decode_directives_from_record() {
+        let directives = ConsentDirectives {
+            share_emergency_subset: false,
+            restrict_sensitive_to_treating: true,
+            allow_research_use: false,
+            disclose_organ_donor: false,
+        };
+        // Directives followed by updated_by and updated_at, as stored on-chain
+        let mut record = directives.encode();
+        record.extend_from_slice(&[1u8; 32]);
+        record.extend_from_slice(&42u32.encode());
+
+        let value = format!("0x{}", hex::encode(record));
+        assert_eq!(ChainClient::decode_directives(&value).unwrap(), directives);
+        assert!(ChainClient::decode_directives("0x01").is_err());
+    }
+
+    #[test]
+    fn test_active_access_key_layout() {
+        let patient = AccountId32::new([1u8; 32]);
+        let accessor = AccountId32::new([2u8; 32]);
+        let key = ChainClient::active_access_key(&patient, &accessor);
+
+        assert_eq!(key.len(), 16 + 16 + (16 + 32) * 2);
+        assert_eq!(&key[16..32], &twox_128(b"ActiveAccess")[..]);
+        assert_eq!(&key[48..80], &[1u8; 32][..]);
+        assert_eq!(&key[96..], &[2u8; 32][..]);
+    }
+
+    #[test]
+    fn test_decode_treating_grants() {
+        let grant = |access_type: AccessType, expires_at: Option<u32>, revoked: bool| {
+            let mut log = [2u8; 32].to_vec();
+            log.extend(access_type.encode());
+            log.extend(5u32.encode());
+            log.extend(expires_at.encode());
+            log.extend([0u8; 32]);
+            log.extend(revoked.encode());
+            format!("0x{}", hex::encode(log))
+        };
+
+        assert!(ChainClient::decode_treating(&grant(AccessType::Full, None, false), 100).unwrap());
+        assert!(
+            ChainClient::decode_treating(&grant(AccessType::Regular, Some(100), false), 100)
+                .unwrap()
+        );
+        // Expired, revoked and emergency grants do not make a treating provider
+        assert!(
+            !ChainClient::decode_treating(&grant(AccessType::Regular, Some(99), false), 100)
+                .unwrap()
+        );
+        assert!(!ChainClient::decode_treating(&grant(AccessType::Full, None, true), 100).unwrap());
+        assert!(!ChainClient::decode_treating(
+            &grant(AccessType::Emergency, Some(200), false),
+            100
+        )
+        .unwrap());
+        assert!(ChainClient::decode_treating("0x01", 100).is_err());
+    }
+
+    #[test]
+    fn test_chain_error_display() {
+        let err = ChainError::InvalidAccount("not-ss58".to_string());
+        assert!(err.to_string().contains("not-ss58"));
+        assert!(ChainError::Timeout.to_string().contains("timed out"));
+    }
+}
@@ -365,7 +365,11 @@ impl IpfsClient {
     }
 }
 
-/// Record reference stored on-chain (minimal data)
+/// Record reference (minimal data)
+///
+/// Mirrors `pallet_medical_records::MedicalDocument` (indexed by patient and
+/// document id); `record_type` maps to its `DocumentType`. The API still keeps
+/// these in memory rather than reading them from the chain.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MedicalRecordReference {
     /// IPFS hash of encrypted content
@@ -378,6 +382,12 @@ pub struct MedicalRecordReference {
     pub uploaded_at: i64,
     /// SHA-256 hash of original content (for integrity verification)
     pub content_checksum: String,
+    /// User who uploaded the record
+    #[serde(default)]
+    pub uploaded_by: String,
+    /// Sensitive record (e.g. HIV test), withheld where consent restricts sensitive data
+    #[serde(default)]
+    pub sensitive: bool,
 }
 
 #[cfg(test)]
@@ -462,6 +472,8 @@ mod tests {
             record_type: "imaging".to_string(),
             uploaded_at: 1704067200,
             content_checksum: "abc123def456".to_string(),
+            uploaded_by: "DOC-001".to_string(),
+            sensitive: false,
         };
 
         let json = serde_json::to_string(&reference).unwrap();
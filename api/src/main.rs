@@ -23,11 +23,15 @@ use std::collections::HashMap;
 use std::sync::RwLock;
 use uuid::Uuid;
 
+mod chain;
 mod ipfs;
 mod nfc_simulator;
 
+use chain::{ChainClient, ChainError};
 use ipfs::{EncryptedMetadata, IpfsClient, IpfsError, MedicalRecordReference};
 use nfc_simulator::{CardRegistry, NFCCard, NationalIdType, QRCodeData};
+use pallet_access_control::{ConsentDirectives, ConsentViewer, Disclosure, Permission};
+use sp_core::crypto::{AccountId32, Ss58Codec};
 
 // ============================================================================
 // Data Types
@@ -42,20 +46,26 @@ pub enum Role {
     LabTechnician,
     Pharmacist,
     Patient,
+    PrivacyOfficer,
 }
 
 impl Role {
-    /// Check if this role is a healthcare provider (can register patients)
-    pub fn is_healthcare_provider(&self) -> bool {
-        matches!(
-            self,
-            Role::Admin | Role::Doctor | Role::Nurse | Role::LabTechnician | Role::Pharmacist
-        )
+    /// The matching on-chain role
+    pub fn on_chain(&self) -> pallet_access_control::Role {
+        match self {
+            Role::Admin => pallet_access_control::Role::Admin,
+            Role::Doctor => pallet_access_control::Role::Doctor,
+            Role::Nurse => pallet_access_control::Role::Nurse,
+            Role::LabTechnician => pallet_access_control::Role::LabTechnician,
+            Role::Pharmacist => pallet_access_control::Role::Pharmacist,
+            Role::Patient => pallet_access_control::Role::Patient,
+            Role::PrivacyOfficer => pallet_access_control::Role::PrivacyOfficer,
+        }
     }
 
-    /// Check if this role can edit medical records
-    pub fn can_edit_medical_records(&self) -> bool {
-        matches!(self, Role::Admin | Role::Doctor | Role::Nurse)
+    /// Check the pallet's permission matrix, so API and chain agree on every role check
+    pub fn has_permission(&self, permission: Permission) -> bool {
+        self.on_chain().has_permission(permission)
     }
 
     /// Check if this role is admin
@@ -73,6 +83,7 @@ impl std::fmt::Display for Role {
             Role::LabTechnician => write!(f, "LabTechnician"),
             Role::Pharmacist => write!(f, "Pharmacist"),
             Role::Patient => write!(f, "Patient"),
+            Role::PrivacyOfficer => write!(f, "PrivacyOfficer"),
         }
     }
 }
@@ -85,6 +96,10 @@ pub struct User {
     pub role: Role,
     pub created_at: DateTime<Utc>,
     pub created_by: Option<String>,
+    /// SS58 account of the user on-chain; providers need one for the patient's
+    /// access grants (`AccessControl::ActiveAccess`) to count
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub account: Option<String>,
 }
 
 /// Blood types supported by the system
@@ -121,6 +136,10 @@ pub struct EmergencyContact {
     pub name: String,
     pub phone: String,
     pub relationship: String,
+    /// SS58 account of the contact when appointed as the patient's on-chain
+    /// guardian (`pallet_access_control::Guardians`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub guardian_account: Option<String>,
 }
 
 /// Patient emergency information (visible without full consent)
@@ -131,12 +150,30 @@ pub struct EmergencyInfo {
     pub allergies: Vec<String>,
     pub current_medications: Vec<String>,
     pub chronic_conditions: Vec<String>,
+    /// Conditions the patient may withhold from non-treating staff (e.g. HIV status)
+    #[serde(default)]
+    pub sensitive_conditions: Vec<String>,
     pub emergency_contacts: Vec<EmergencyContact>,
-    pub organ_donor: bool,
+    /// `None` when the patient's consent directives withhold donor status
+    pub organ_donor: Option<bool>,
     pub dnr_status: bool,
     pub last_updated: DateTime<Utc>,
 }
 
+impl EmergencyInfo {
+    /// Copy with the fields `disclosure` withholds removed
+    pub fn redacted(&self, disclosure: &Disclosure) -> Self {
+        let mut info = self.clone();
+        if !disclosure.sensitive_conditions {
+            info.sensitive_conditions.clear();
+        }
+        if !disclosure.organ_donor {
+            info.organ_donor = None;
+        }
+        info
+    }
+}
+
 /// Full patient profile
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PatientProfile {
@@ -144,6 +181,10 @@ pub struct PatientProfile {
     pub full_name: String,
     pub date_of_birth: String,
     pub national_id: String,
+    /// SS58 account of the patient on-chain; their consent directives are
+    /// read from `AccessControl::Consents` for it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub account: Option<String>,
     pub emergency_info: EmergencyInfo,
     pub created_at: DateTime<Utc>,
     pub last_updated: DateTime<Utc>,
@@ -181,12 +222,21 @@ pub struct RegisterPatientRequest {
     pub date_of_birth: String,
     pub national_id: String,
     pub blood_type: String,
+    /// SS58 account of the patient on-chain, once they have one
+    #[serde(default)]
+    pub account: Option<String>,
     pub allergies: Vec<String>,
     pub current_medications: Vec<String>,
     pub chronic_conditions: Vec<String>,
     pub emergency_contact_name: String,
     pub emergency_contact_phone: String,
     pub emergency_contact_relationship: String,
+    /// Guardian account of the emergency contact, if they act for the patient
+    #[serde(default)]
+    pub emergency_contact_guardian_account: Option<String>,
+    /// Conditions the patient may withhold from non-treating staff (e.g. HIV status)
+    #[serde(default)]
+    pub sensitive_conditions: Vec<String>,
     pub organ_donor: bool,
     pub dnr_status: bool,
 }
@@ -253,6 +303,9 @@ pub struct AssignRoleRequest {
     pub user_id: String,
     pub username: String,
     pub role: String,
+    /// SS58 account of the user on-chain, if they have one
+    #[serde(default)]
+    pub account: Option<String>,
 }
 
 #[derive(Debug, Serialize)]
@@ -287,19 +340,14 @@ pub struct ErrorResponse {
 // ============================================================================
 
 /// Status of lab result submission
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
 pub enum LabResultStatus {
+    #[default]
     Pending,
     Approved,
     Rejected,
 }
 
-impl Default for LabResultStatus {
-    fn default() -> Self {
-        LabResultStatus::Pending
-    }
-}
-
 impl std::fmt::Display for LabResultStatus {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -418,6 +466,9 @@ pub struct UploadMedicalRecordRequest {
     pub content_type: String,
     /// Record type (e.g., "lab_result", "imaging", "prescription")
     pub record_type: String,
+    /// Sensitive record (e.g. HIV test), withheld where consent restricts sensitive data
+    #[serde(default)]
+    pub sensitive: bool,
 }
 
 #[derive(Debug, Serialize)]
@@ -466,6 +517,10 @@ pub struct AppState {
     pub access_logs: RwLock<Vec<AccessLogEntry>>,
     pub users: RwLock<HashMap<String, User>>,
     /// Medical record references (patient_id -> list of record refs)
+    ///
+    /// Kept in memory only and lost on restart. Backing this with the on-chain
+    /// `MedicalRecords::Documents` index is deferred: the API holds no signing
+    /// keys to submit `add_document`, and `sensitive` has no on-chain field yet.
     pub medical_records: RwLock<HashMap<String, Vec<MedicalRecordReference>>>,
     /// Lab result submissions pending approval (submission_id -> submission)
     pub lab_submissions: RwLock<HashMap<String, LabResultSubmission>>,
@@ -475,6 +530,8 @@ pub struct AppState {
     pub encryption_key: medichain_crypto::EncryptionKey,
     /// NFC Card registry for demo
     pub card_registry: CardRegistry,
+    /// Node client; consent directives are always read from `AccessControl::Consents`
+    pub chain_client: ChainClient,
 }
 
 impl AppState {
@@ -493,6 +550,7 @@ impl AppState {
             ipfs_client: IpfsClient::new_local(),
             encryption_key,
             card_registry: CardRegistry::new(),
+            chain_client: ChainClient::new_local(),
         };
         state.seed_demo_data();
         state
@@ -735,8 +793,10 @@ impl AppState {
                     name: contact.0.to_string(),
                     phone: contact.1.to_string(),
                     relationship: contact.2.to_string(),
+                    guardian_account: None,
                 }],
-                organ_donor: *donor,
+                sensitive_conditions: Vec::new(),
+                organ_donor: Some(*donor),
                 dnr_status: *dnr,
                 last_updated: Utc::now(),
             };
@@ -746,6 +806,7 @@ impl AppState {
                 full_name: name.to_string(),
                 date_of_birth: dob.to_string(),
                 national_id: nat_id.to_string(),
+                account: None,
                 emergency_info,
                 created_at: Utc::now(),
                 last_updated: Utc::now(),
@@ -774,6 +835,7 @@ impl AppState {
                 role: Role::Patient,
                 created_at: Utc::now(),
                 created_by: Some("DOC-001".to_string()),
+                account: None,
             };
             self.users.write().unwrap().insert(patient_id, patient_user);
         }
@@ -788,6 +850,7 @@ impl AppState {
                 role: Role::Admin,
                 created_at: Utc::now(),
                 created_by: None,
+                account: None,
             },
             User {
                 user_id: "DOC-001".to_string(),
@@ -795,6 +858,7 @@ impl AppState {
                 role: Role::Doctor,
                 created_at: Utc::now(),
                 created_by: Some("ADMIN-001".to_string()),
+                account: None,
             },
             User {
                 user_id: "DOC-002".to_string(),
@@ -802,6 +866,7 @@ impl AppState {
                 role: Role::Doctor,
                 created_at: Utc::now(),
                 created_by: Some("ADMIN-001".to_string()),
+                account: None,
             },
             User {
                 user_id: "NURSE-001".to_string(),
@@ -809,6 +874,7 @@ impl AppState {
                 role: Role::Nurse,
                 created_at: Utc::now(),
                 created_by: Some("ADMIN-001".to_string()),
+                account: None,
             },
             User {
                 user_id: "NURSE-002".to_string(),
@@ -816,6 +882,7 @@ impl AppState {
                 role: Role::Nurse,
                 created_at: Utc::now(),
                 created_by: Some("ADMIN-001".to_string()),
+                account: None,
             },
             User {
                 user_id: "LAB-001".to_string(),
@@ -823,6 +890,7 @@ impl AppState {
                 role: Role::LabTechnician,
                 created_at: Utc::now(),
                 created_by: Some("ADMIN-001".to_string()),
+                account: None,
             },
             User {
                 user_id: "PHARM-001".to_string(),
@@ -830,6 +898,7 @@ impl AppState {
                 role: Role::Pharmacist,
                 created_at: Utc::now(),
                 created_by: Some("ADMIN-001".to_string()),
+                account: None,
             },
         ];
 
@@ -1015,6 +1084,106 @@ fn parse_blood_type(s: &str) -> Result<BloodType, String> {
     }
 }
 
+/// Consent directives in force for a patient, read from the chain
+///
+/// Patients without an on-chain account cannot have set any, so the
+/// defaults apply.
+async fn consent_directives(
+    data: &AppState,
+    patient_id: &str,
+) -> Result<ConsentDirectives, ChainError> {
+    let account = data
+        .patients
+        .read()
+        .unwrap()
+        .get(patient_id)
+        .and_then(|p| p.account.clone());
+    match account {
+        Some(account) => data.chain_client.consent_directives(&account).await,
+        None => Ok(ConsentDirectives::default()),
+    }
+}
+
+/// Response when consent directives cannot be read from the chain
+///
+/// Fails closed: nothing consent-gated is served without the directives.
+fn chain_unavailable(err: &ChainError) -> HttpResponse {
+    log::error!("Failed to read consent directives: {}", err);
+    HttpResponse::ServiceUnavailable().json(ErrorResponse {
+        success: false,
+        error: "Consent directives could not be read from the chain".to_string(),
+        code: "CHAIN_UNAVAILABLE".to_string(),
+    })
+}
+
+/// Whether `user_id` holds a valid Regular or Full grant to the patient on-chain
+///
+/// Both need an on-chain account; without one there can be no grant.
+async fn has_treating_access(
+    data: &AppState,
+    user_id: &str,
+    patient_id: &str,
+) -> Result<bool, ChainError> {
+    let patient_account = data
+        .patients
+        .read()
+        .unwrap()
+        .get(patient_id)
+        .and_then(|p| p.account.clone());
+    let user_account = data
+        .users
+        .read()
+        .unwrap()
+        .get(user_id)
+        .and_then(|u| u.account.clone());
+    match (patient_account, user_account) {
+        (Some(patient), Some(user)) => data.chain_client.has_treating_access(&patient, &user).await,
+        _ => Ok(false),
+    }
+}
+
+/// What `user_id` may see of a patient's records under their consent directives
+///
+/// Only providers the patient granted Regular or Full access on-chain count as
+/// treating providers, as in `AccessControl::consent_viewer`.
+async fn records_disclosure(
+    data: &AppState,
+    user_id: &str,
+    patient_id: &str,
+) -> Result<Disclosure, ChainError> {
+    let viewer = if user_id == patient_id {
+        ConsentViewer::PatientOrGuardian
+    } else if has_treating_access(data, user_id, patient_id).await? {
+        ConsentViewer::TreatingProvider
+    } else {
+        ConsentViewer::EmergencyProvider
+    };
+    Ok(consent_directives(data, patient_id)
+        .await?
+        .disclosure(viewer))
+}
+
+/// Validate an SS58 guardian account before linking it to an emergency contact
+fn parse_guardian_account(s: &str) -> Result<String, String> {
+    AccountId32::from_ss58check(s.trim())
+        .map(|_| s.trim().to_string())
+        .map_err(|_| format!("Invalid guardian account: {}", s))
+}
+
+/// Validate a patient's SS58 account before reading their directives from the chain
+fn parse_patient_account(s: &str) -> Result<String, String> {
+    AccountId32::from_ss58check(s.trim())
+        .map(|_| s.trim().to_string())
+        .map_err(|_| format!("Invalid patient account: {}", s))
+}
+
+/// Validate a user's SS58 account before reading their access grants from the chain
+fn parse_user_account(s: &str) -> Result<String, String> {
+    AccountId32::from_ss58check(s.trim())
+        .map(|_| s.trim().to_string())
+        .map_err(|_| format!("Invalid user account: {}", s))
+}
+
 fn parse_role(s: &str) -> Result<Role, String> {
     match s.to_lowercase().as_str() {
         "admin" => Ok(Role::Admin),
@@ -1023,7 +1192,8 @@ fn parse_role(s: &str) -> Result<Role, String> {
         "labtechnician" | "lab_technician" | "lab" => Ok(Role::LabTechnician),
         "pharmacist" => Ok(Role::Pharmacist),
         "patient" => Ok(Role::Patient),
-        _ => Err(format!("Invalid role: {}. Valid roles: Admin, Doctor, Nurse, LabTechnician, Pharmacist, Patient", s)),
+        "privacyofficer" | "privacy_officer" => Ok(Role::PrivacyOfficer),
+        _ => Err(format!("Invalid role: {}. Valid roles: Admin, Doctor, Nurse, LabTechnician, Pharmacist, Patient, PrivacyOfficer", s)),
     }
 }
 
@@ -1106,7 +1276,10 @@ async fn register_patient(
         }
     };
 
-    if !current_user.role.is_healthcare_provider() {
+    if !current_user
+        .role
+        .has_permission(Permission::RegisterPatient)
+    {
         return HttpResponse::Forbidden().json(ErrorResponse {
             success: false,
             error: format!(
@@ -1130,6 +1303,40 @@ async fn register_patient(
         }
     };
 
+    let guardian_account = match req
+        .emergency_contact_guardian_account
+        .as_deref()
+        .map(parse_guardian_account)
+        .transpose()
+    {
+        Ok(account) => account,
+        Err(e) => {
+            return HttpResponse::BadRequest().json(RegisterPatientResponse {
+                success: false,
+                patient_id: String::new(),
+                nfc_tag_id: String::new(),
+                message: e,
+            });
+        }
+    };
+
+    let patient_account = match req
+        .account
+        .as_deref()
+        .map(parse_patient_account)
+        .transpose()
+    {
+        Ok(account) => account,
+        Err(e) => {
+            return HttpResponse::BadRequest().json(RegisterPatientResponse {
+                success: false,
+                patient_id: String::new(),
+                nfc_tag_id: String::new(),
+                message: e,
+            });
+        }
+    };
+
     // Generate IDs
     let patient_id = format!(
         "PAT-{}",
@@ -1159,8 +1366,10 @@ async fn register_patient(
             name: req.emergency_contact_name.clone(),
             phone: req.emergency_contact_phone.clone(),
             relationship: req.emergency_contact_relationship.clone(),
+            guardian_account,
         }],
-        organ_donor: req.organ_donor,
+        sensitive_conditions: req.sensitive_conditions.clone(),
+        organ_donor: Some(req.organ_donor),
         dnr_status: req.dnr_status,
         last_updated: Utc::now(),
     };
@@ -1171,6 +1380,7 @@ async fn register_patient(
         full_name: req.full_name.clone(),
         date_of_birth: req.date_of_birth.clone(),
         national_id: req.national_id.clone(),
+        account: patient_account,
         emergency_info,
         created_at: Utc::now(),
         last_updated: Utc::now(),
@@ -1202,6 +1412,7 @@ async fn register_patient(
         role: Role::Patient,
         created_at: Utc::now(),
         created_by: Some(current_user_id.clone()),
+        account: None,
     };
     data.users
         .write()
@@ -1245,11 +1456,35 @@ async fn emergency_access(
         }
     };
 
+    // Card taps are not treating providers: apply the patient's directives
+    let disclosure = match consent_directives(&data, &patient_id).await {
+        Ok(directives) => directives.disclosure(ConsentViewer::EmergencyProvider),
+        Err(e) => {
+            log::error!("Failed to read consent directives: {}", e);
+            return HttpResponse::ServiceUnavailable().json(EmergencyAccessResponse {
+                success: false,
+                access_id: String::new(),
+                emergency_info: None,
+                message: "Consent directives could not be read from the chain.".to_string(),
+            });
+        }
+    };
+    if !disclosure.emergency_subset {
+        return HttpResponse::Forbidden().json(EmergencyAccessResponse {
+            success: false,
+            access_id: String::new(),
+            emergency_info: None,
+            message: "Patient's consent directives withhold emergency information from \
+                      non-treating providers."
+                .to_string(),
+        });
+    }
+
     // Get patient emergency info
     let emergency_info = {
         let patients = data.patients.read().unwrap();
         match patients.get(&patient_id) {
-            Some(p) => p.emergency_info.clone(),
+            Some(p) => p.emergency_info.redacted(&disclosure),
             None => {
                 return HttpResponse::NotFound().json(EmergencyAccessResponse {
                     success: false,
@@ -1415,11 +1650,14 @@ pub struct UpdatePatientRequest {
     pub allergies: Option<Vec<String>>,
     pub current_medications: Option<Vec<String>>,
     pub chronic_conditions: Option<Vec<String>>,
+    pub sensitive_conditions: Option<Vec<String>>,
     pub organ_donor: Option<bool>,
     pub dnr_status: Option<bool>,
     pub emergency_contact_name: Option<String>,
     pub emergency_contact_phone: Option<String>,
     pub emergency_contact_relationship: Option<String>,
+    /// Link the emergency contact to a guardian account (empty string unlinks)
+    pub emergency_contact_guardian_account: Option<String>,
 }
 
 /// Update patient response
@@ -1467,7 +1705,7 @@ async fn update_patient(
     };
 
     // CRITICAL: Only Doctor, Nurse, or Admin can edit records
-    if !current_user.role.can_edit_medical_records() {
+    if !current_user.role.has_permission(Permission::EditRecord) {
         return HttpResponse::Forbidden().json(ErrorResponse {
             success: false,
             error: format!(
@@ -1478,6 +1716,22 @@ async fn update_patient(
         });
     }
 
+    // Empty string unlinks the guardian; anything else must be a valid account
+    let guardian_account = match req.emergency_contact_guardian_account.as_deref() {
+        None => None,
+        Some("") => Some(None),
+        Some(account) => match parse_guardian_account(account) {
+            Ok(account) => Some(Some(account)),
+            Err(e) => {
+                return HttpResponse::BadRequest().json(ErrorResponse {
+                    success: false,
+                    error: e,
+                    code: "INVALID_GUARDIAN_ACCOUNT".to_string(),
+                });
+            }
+        },
+    };
+
     // Update patient record
     let mut patients = data.patients.write().unwrap();
     let patient = match patients.get_mut(&patient_id) {
@@ -1501,8 +1755,11 @@ async fn update_patient(
     if let Some(conditions) = &req.chronic_conditions {
         patient.emergency_info.chronic_conditions = conditions.clone();
     }
+    if let Some(conditions) = &req.sensitive_conditions {
+        patient.emergency_info.sensitive_conditions = conditions.clone();
+    }
     if let Some(organ_donor) = req.organ_donor {
-        patient.emergency_info.organ_donor = organ_donor;
+        patient.emergency_info.organ_donor = Some(organ_donor);
     }
     if let Some(dnr) = req.dnr_status {
         patient.emergency_info.dnr_status = dnr;
@@ -1512,6 +1769,7 @@ async fn update_patient(
     if req.emergency_contact_name.is_some()
         || req.emergency_contact_phone.is_some()
         || req.emergency_contact_relationship.is_some()
+        || guardian_account.is_some()
     {
         if let Some(contact) = patient.emergency_info.emergency_contacts.get_mut(0) {
             if let Some(name) = &req.emergency_contact_name {
@@ -1523,6 +1781,9 @@ async fn update_patient(
             if let Some(rel) = &req.emergency_contact_relationship {
                 contact.relationship = rel.clone();
             }
+            if let Some(account) = guardian_account {
+                contact.guardian_account = account;
+            }
         }
     }
 
@@ -1543,6 +1804,62 @@ async fn update_patient(
     })
 }
 
+/// Get a patient's consent directives (the patient or a provider)
+#[get("/api/patients/{patient_id}/consent")]
+async fn get_consent(
+    data: web::Data<AppState>,
+    http_req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let patient_id = path.into_inner();
+
+    let current_user_id = match get_current_user_id(&http_req) {
+        Some(id) => id,
+        None => {
+            return HttpResponse::Unauthorized().json(ErrorResponse {
+                success: false,
+                error: "Missing X-User-Id header".to_string(),
+                code: "UNAUTHORIZED".to_string(),
+            });
+        }
+    };
+
+    let current_user = match get_user(&data, &current_user_id) {
+        Some(u) => u,
+        None => {
+            return HttpResponse::Unauthorized().json(ErrorResponse {
+                success: false,
+                error: "User not found".to_string(),
+                code: "USER_NOT_FOUND".to_string(),
+            });
+        }
+    };
+
+    if !current_user.role.has_permission(Permission::ViewRecords) && current_user_id != patient_id {
+        return HttpResponse::Forbidden().json(ErrorResponse {
+            success: false,
+            error: "Patients can only view their own consent directives".to_string(),
+            code: "ACCESS_DENIED".to_string(),
+        });
+    }
+
+    if !data.patients.read().unwrap().contains_key(&patient_id) {
+        return HttpResponse::NotFound().json(ErrorResponse {
+            success: false,
+            error: "Patient not found".to_string(),
+            code: "PATIENT_NOT_FOUND".to_string(),
+        });
+    }
+
+    match consent_directives(&data, &patient_id).await {
+        Ok(directives) => HttpResponse::Ok().json(serde_json::json!({
+            "patient_id": patient_id,
+            "directives": directives,
+        })),
+        Err(e) => chain_unavailable(&e),
+    }
+}
+
 /// Get demo info
 #[get("/api/demo")]
 async fn demo_info() -> impl Responder {
@@ -1577,6 +1894,7 @@ async fn demo_info() -> impl Responder {
             "health": "GET /health",
             "register": "POST /api/register (requires: Doctor, Nurse, Admin)",
             "update_patient": "PUT /api/patients/{patient_id} (requires: Doctor, Nurse, Admin)",
+            "consent": "GET /api/patients/{patient_id}/consent (set on-chain by the patient)",
             "get_my_records": "GET /api/my-records (Patient: own records only)",
             "emergency_access": "POST /api/emergency-access",
             "simulate_nfc": "POST /api/simulate-nfc-tap",
@@ -1655,6 +1973,17 @@ async fn assign_role(
         });
     }
 
+    let account = match body.account.as_deref().map(parse_user_account).transpose() {
+        Ok(account) => account,
+        Err(e) => {
+            return HttpResponse::BadRequest().json(ErrorResponse {
+                success: false,
+                error: e,
+                code: "INVALID_ACCOUNT".to_string(),
+            });
+        }
+    };
+
     // Create new user
     let user = User {
         user_id: body.user_id.clone(),
@@ -1662,6 +1991,7 @@ async fn assign_role(
         role: role.clone(),
         created_at: Utc::now(),
         created_by: Some(current_user_id.clone()),
+        account,
     };
 
     data.users
@@ -1823,13 +2153,10 @@ async fn get_my_records(data: web::Data<AppState>, req: HttpRequest) -> impl Res
         }
     };
 
-    // Find patient record matching user_id
-    let patients = data.patients.read().unwrap();
-
     // For patients, they can only see their own records
     // For healthcare providers, they can see all records
     if current_user.role == Role::Patient {
-        match patients.get(&current_user_id) {
+        match data.patients.read().unwrap().get(&current_user_id) {
             Some(patient) => HttpResponse::Ok().json(patient),
             None => HttpResponse::NotFound().json(ErrorResponse {
                 success: false,
@@ -1837,10 +2164,31 @@ async fn get_my_records(data: web::Data<AppState>, req: HttpRequest) -> impl Res
                 code: "RECORD_NOT_FOUND".to_string(),
             }),
         }
+    } else if current_user.role.has_permission(Permission::ViewRecords) {
+        // Healthcare providers see each patient as that patient's directives allow
+        let profiles: Vec<PatientProfile> =
+            data.patients.read().unwrap().values().cloned().collect();
+        let mut visible = Vec::with_capacity(profiles.len());
+        for mut profile in profiles {
+            let disclosure =
+                match records_disclosure(&data, &current_user_id, &profile.patient_id).await {
+                    Ok(disclosure) => disclosure,
+                    Err(e) => return chain_unavailable(&e),
+                };
+            if !disclosure.emergency_subset {
+                continue;
+            }
+            profile.emergency_info = profile.emergency_info.redacted(&disclosure);
+            visible.push(profile);
+        }
+        HttpResponse::Ok().json(visible)
     } else {
-        // Healthcare providers can see all
-        let all: Vec<&PatientProfile> = patients.values().collect();
-        HttpResponse::Ok().json(all)
+        // Privacy officers review access, they do not read records
+        HttpResponse::Forbidden().json(ErrorResponse {
+            success: false,
+            error: "Your role cannot view patient records".to_string(),
+            code: "INSUFFICIENT_ROLE".to_string(),
+        })
     }
 }
 
@@ -1891,13 +2239,19 @@ async fn upload_medical_record(
         }
     };
 
-    // Only doctors, nurses, and admins can upload medical records
-    if !current_user.role.can_edit_medical_records() {
+    // Same rule as `MedicalRecords::add_document`: lab results need AddLabResult,
+    // every other record needs EditRecord
+    let required = if req.record_type == "lab_result" {
+        Permission::AddLabResult
+    } else {
+        Permission::EditRecord
+    };
+    if !current_user.role.has_permission(required) {
         return HttpResponse::Forbidden().json(ErrorResponse {
             success: false,
             error: format!(
-                "Role '{}' cannot upload medical records. Required: Doctor, Nurse, or Admin",
-                current_user.role
+                "Role '{}' cannot upload {} records",
+                current_user.role, req.record_type
             ),
             code: "INSUFFICIENT_ROLE".to_string(),
         });
@@ -1966,14 +2320,16 @@ async fn upload_medical_record(
         record_type: req.record_type.clone(),
         uploaded_at: Utc::now().timestamp(),
         content_checksum,
+        uploaded_by: current_user_id.clone(),
+        sensitive: req.sensitive,
     };
 
-    // Store reference locally (in production: on blockchain)
+    // Store reference in memory (not on-chain yet, see `AppState::medical_records`)
     {
         let mut records = data.medical_records.write().unwrap();
         records
             .entry(req.patient_id.clone())
-            .or_insert_with(Vec::new)
+            .or_default()
             .push(record_ref.clone());
     }
 
@@ -2034,14 +2390,13 @@ async fn download_medical_record(
 
     // Patients can only download their own records
     // Healthcare providers can download any records
-    if !current_user.role.is_healthcare_provider() {
+    if !current_user.role.has_permission(Permission::ViewRecords) {
         // Check if this record belongs to the patient
         let records = data.medical_records.read().unwrap();
         let patient_records = records.get(&current_user_id);
 
-        let owns_record = patient_records.map_or(false, |recs| {
-            recs.iter().any(|r| r.content_hash == req.content_hash)
-        });
+        let owns_record = patient_records
+            .is_some_and(|recs| recs.iter().any(|r| r.content_hash == req.content_hash));
 
         if !owns_record {
             return HttpResponse::Forbidden().json(ErrorResponse {
@@ -2052,6 +2407,29 @@ async fn download_medical_record(
         }
     }
 
+    // Apply the owning patient's consent directives to everyone but the patient
+    let reference = {
+        let records = data.medical_records.read().unwrap();
+        records.iter().find_map(|(patient_id, recs)| {
+            recs.iter()
+                .find(|r| r.content_hash == req.content_hash)
+                .map(|r| (patient_id.clone(), r.sensitive))
+        })
+    };
+    if let Some((patient_id, sensitive)) = reference {
+        let disclosure = match records_disclosure(&data, &current_user_id, &patient_id).await {
+            Ok(disclosure) => disclosure,
+            Err(e) => return chain_unavailable(&e),
+        };
+        if !disclosure.full_records || (sensitive && !disclosure.sensitive_conditions) {
+            return HttpResponse::Forbidden().json(ErrorResponse {
+                success: false,
+                error: "Patient's consent directives withhold this record from you".to_string(),
+                code: "CONSENT_WITHHELD".to_string(),
+            });
+        }
+    }
+
     // Download and decrypt from IPFS
     let download_result = match data
         .ipfs_client
@@ -2141,7 +2519,7 @@ async fn list_patient_records(
     };
 
     // Patients can only list their own records
-    if !current_user.role.is_healthcare_provider() && current_user_id != patient_id {
+    if !current_user.role.has_permission(Permission::ViewRecords) && current_user_id != patient_id {
         return HttpResponse::Forbidden().json(ErrorResponse {
             success: false,
             error: "Patients can only view their own medical records".to_string(),
@@ -2149,9 +2527,23 @@ async fn list_patient_records(
         });
     }
 
-    // Get patient records
+    // Get patient records, without those the patient's directives withhold
+    let disclosure = match records_disclosure(&data, &current_user_id, &patient_id).await {
+        Ok(disclosure) => disclosure,
+        Err(e) => return chain_unavailable(&e),
+    };
     let records = data.medical_records.read().unwrap();
-    let patient_records = records.get(&patient_id).cloned().unwrap_or_default();
+    let patient_records: Vec<MedicalRecordReference> = if disclosure.full_records {
+        records
+            .get(&patient_id)
+            .into_iter()
+            .flatten()
+            .filter(|r| !r.sensitive || disclosure.sensitive_conditions)
+            .cloned()
+            .collect()
+    } else {
+        Vec::new()
+    };
 
     // Log access
     {
@@ -2211,12 +2603,7 @@ async fn submit_lab_results(
     };
 
     // LabTechnician and healthcare providers can submit lab results
-    let can_submit = matches!(
-        current_user.role,
-        Role::LabTechnician | Role::Doctor | Role::Nurse | Role::Admin
-    );
-
-    if !can_submit {
+    if !current_user.role.has_permission(Permission::AddLabResult) {
         return HttpResponse::Forbidden().json(ErrorResponse {
             success: false,
             error: format!(
@@ -2345,7 +2732,7 @@ async fn get_pending_lab_results(
     };
 
     // Only Doctor, Nurse, or Admin can review
-    if !current_user.role.can_edit_medical_records() {
+    if !current_user.role.has_permission(Permission::EditRecord) {
         return HttpResponse::Forbidden().json(ErrorResponse {
             success: false,
             error: format!(
@@ -2404,7 +2791,7 @@ async fn get_all_lab_submissions(
     };
 
     // Only Doctor, Nurse, or Admin can view all submissions
-    if !current_user.role.can_edit_medical_records() {
+    if !current_user.role.has_permission(Permission::EditRecord) {
         return HttpResponse::Forbidden().json(ErrorResponse {
             success: false,
             error: format!(
@@ -2484,7 +2871,7 @@ async fn get_lab_submission(
     };
 
     // Allow access if: healthcare provider OR the lab tech who submitted it
-    let can_view = current_user.role.can_edit_medical_records()
+    let can_view = current_user.role.has_permission(Permission::EditRecord)
         || (current_user.role == Role::LabTechnician && submission.submitted_by == current_user_id);
 
     if !can_view {
@@ -2530,7 +2917,7 @@ async fn review_lab_results(
     };
 
     // Only Doctor, Nurse, or Admin can approve/reject
-    if !current_user.role.can_edit_medical_records() {
+    if !current_user.role.has_permission(Permission::EditRecord) {
         return HttpResponse::Forbidden().json(ErrorResponse {
             success: false,
             error: format!(
@@ -2603,6 +2990,8 @@ async fn review_lab_results(
             record_type: "lab_result".to_string(),
             uploaded_at: Utc::now().timestamp(),
             content_checksum,
+            uploaded_by: current_user_id.clone(),
+            sensitive: false,
         };
 
         // Store in patient's medical records (now visible to patient)
@@ -2611,7 +3000,7 @@ async fn review_lab_results(
             let mut records = data.medical_records.write().unwrap();
             records
                 .entry(patient_id.clone())
-                .or_insert_with(Vec::new)
+                .or_default()
                 .push(record_ref);
         }
 
@@ -2697,7 +3086,7 @@ async fn get_patient_lab_submissions(
         }
     };
 
-    let is_healthcare = current_user.role.is_healthcare_provider();
+    let is_healthcare = current_user.role.has_permission(Permission::ViewRecords);
     let is_own_records = current_user_id == patient_id;
 
     if !is_healthcare && !is_own_records {
@@ -2802,7 +3191,10 @@ async fn generate_nfc_card(
         }
     };
 
-    if !current_user.role.is_healthcare_provider() {
+    if !current_user
+        .role
+        .has_permission(Permission::RegisterPatient)
+    {
         return HttpResponse::Forbidden().json(ErrorResponse {
             success: false,
             error: "Only healthcare providers can generate NFC cards".to_string(),
@@ -2811,14 +3203,8 @@ async fn generate_nfc_card(
     }
 
     // Parse national ID type
-    let national_id_type = match body.national_id_type.to_lowercase().as_str() {
-        "fayda" | "faydaid" | "ethiopia" => NationalIdType::FaydaId,
-        "ghana" | "ghanacard" => NationalIdType::GhanaCard,
-        "nin" | "nigeria" => NationalIdType::NigeriaNIN,
-        "smartid" | "southafrica" => NationalIdType::SouthAfricaSmartId,
-        "huduma" | "kenya" => NationalIdType::KenyaHuduma,
-        _ => NationalIdType::Other,
-    };
+    let national_id_type =
+        NationalIdType::from_code(&body.national_id_type).unwrap_or(NationalIdType::Other);
 
     // Create NFC card
     let card = NFCCard::new(body.patient_id.clone(), national_id_type);
@@ -2883,7 +3269,10 @@ async fn nfc_tap(
         }
     };
 
-    if !current_user.role.is_healthcare_provider() {
+    if !current_user
+        .role
+        .has_permission(Permission::EmergencyAccess)
+    {
         return HttpResponse::Forbidden().json(ErrorResponse {
             success: false,
             error: "Only healthcare providers can use NFC tap".to_string(),
@@ -2983,7 +3372,10 @@ async fn verify_qr_code(
         }
     };
 
-    if !current_user.role.is_healthcare_provider() {
+    if !current_user
+        .role
+        .has_permission(Permission::EmergencyAccess)
+    {
         return HttpResponse::Forbidden().json(ErrorResponse {
             success: false,
             error: "Only healthcare providers can verify QR codes".to_string(),
@@ -3108,7 +3500,7 @@ async fn get_card_info(
     };
 
     // Patients can only view their own card
-    if !current_user.role.is_healthcare_provider() && current_user_id != patient_id {
+    if !current_user.role.has_permission(Permission::ViewRecords) && current_user_id != patient_id {
         return HttpResponse::Forbidden().json(ErrorResponse {
             success: false,
             error: "Access denied".to_string(),
@@ -3132,7 +3524,7 @@ async fn get_card_info(
         card_id: card.card_id,
         patient_id: card.patient_id,
         card_hash: card.card_hash,
-        national_id_type: card.national_id_type.to_string(),
+        national_id_type: card.national_id_type.display_name().to_string(),
         status: card.status.to_string(),
         created_at: card.created_at,
         last_used_at: card.last_used_at,
@@ -3248,7 +3640,7 @@ async fn list_nfc_cards(data: web::Data<AppState>, http_req: HttpRequest) -> imp
             card_id: c.card_id,
             patient_id: c.patient_id,
             card_hash: c.card_hash,
-            national_id_type: c.national_id_type.to_string(),
+            national_id_type: c.national_id_type.display_name().to_string(),
             status: c.status.to_string(),
             created_at: c.created_at,
             last_used_at: c.last_used_at,
@@ -3327,6 +3719,7 @@ async fn main() -> std::io::Result<()> {
             .service(health_check)
             .service(register_patient)
             .service(update_patient)
+            .service(get_consent)
             .service(emergency_access)
             .service(simulate_nfc_tap)
             .service(get_access_logs)
@@ -3361,3 +3754,118 @@ async fn main() -> std::io::Result<()> {
     .run()
     .await
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::test;
+    use pallet_access_control::AccessType;
+    use parity_scale_codec::Encode;
+
+    const PATIENT_ACCOUNT: [u8; 32] = [1u8; 32];
+    const DOCTOR_ACCOUNT: [u8; 32] = [2u8; 32];
+
+    /// Node stand-in: the patient restricts sensitive conditions to treating
+    /// providers, hides organ donor status and granted the doctor Full access
+    async fn mock_node(body: web::Json<serde_json::Value>) -> HttpResponse {
+        let patient = AccountId32::new(PATIENT_ACCOUNT);
+        let doctor = AccountId32::new(DOCTOR_ACCOUNT);
+        let consents_key = format!("0x{}", hex::encode(ChainClient::consents_key(&patient)));
+        let access_key = format!(
+            "0x{}",
+            hex::encode(ChainClient::active_access_key(&patient, &doctor))
+        );
+
+        let result = match body["method"].as_str() {
+            Some("chain_getHeader") => serde_json::json!({ "number": "0x10" }),
+            Some("state_getStorage") if body["params"][0] == consents_key.as_str() => {
+                let mut record = ConsentDirectives {
+                    share_emergency_subset: true,
+                    restrict_sensitive_to_treating: true,
+                    allow_research_use: false,
+                    disclose_organ_donor: false,
+                }
+                .encode();
+                record.extend_from_slice(&PATIENT_ACCOUNT);
+                record.extend_from_slice(&1u32.encode());
+                serde_json::json!(format!("0x{}", hex::encode(record)))
+            }
+            Some("state_getStorage") if body["params"][0] == access_key.as_str() => {
+                let mut grant = DOCTOR_ACCOUNT.to_vec();
+                grant.extend(AccessType::Full.encode());
+                grant.extend(1u32.encode());
+                grant.extend(Option::<u32>::None.encode());
+                grant.extend([0u8; 32]);
+                grant.extend(false.encode());
+                serde_json::json!(format!("0x{}", hex::encode(grant)))
+            }
+            _ => serde_json::Value::Null,
+        };
+        HttpResponse::Ok().json(serde_json::json!({ "jsonrpc": "2.0", "id": 1, "result": result }))
+    }
+
+    #[actix_web::test]
+    async fn test_my_records_applies_consent_directives() {
+        let node = HttpServer::new(|| App::new().route("/", web::post().to(mock_node)))
+            .bind(("127.0.0.1", 0))
+            .unwrap();
+        let node_url = format!("http://{}", node.addrs()[0]);
+        actix_web::rt::spawn(node.run());
+
+        let mut state = AppState::new();
+        state.chain_client = ChainClient::new(node_url);
+        {
+            let mut patients = state.patients.write().unwrap();
+            let patient = patients.get_mut("PAT-003-DEMO").unwrap();
+            patient.account = Some(AccountId32::new(PATIENT_ACCOUNT).to_ss58check());
+            patient.emergency_info.sensitive_conditions = vec!["HIV".to_string()];
+            patient.emergency_info.organ_donor = Some(true);
+        }
+        state
+            .users
+            .write()
+            .unwrap()
+            .get_mut("DOC-001")
+            .unwrap()
+            .account = Some(AccountId32::new(DOCTOR_ACCOUNT).to_ss58check());
+
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(state))
+                .service(get_my_records),
+        )
+        .await;
+
+        // No grant: the emergency view, without sensitive conditions or donor status
+        let req = test::TestRequest::get()
+            .uri("/api/my-records")
+            .insert_header(("X-User-Id", "NURSE-001"))
+            .to_request();
+        let profiles: Vec<PatientProfile> = test::call_and_read_body_json(&app, req).await;
+        let patient = profiles
+            .iter()
+            .find(|p| p.patient_id == "PAT-003-DEMO")
+            .unwrap();
+        assert!(patient.emergency_info.sensitive_conditions.is_empty());
+        assert_eq!(patient.emergency_info.organ_donor, None);
+        // Patients without an on-chain account keep the default directives
+        let other = profiles
+            .iter()
+            .find(|p| p.patient_id == "PAT-001-DEMO")
+            .unwrap();
+        assert!(other.emergency_info.organ_donor.is_some());
+
+        // Full grant: a treating provider sees sensitive conditions, donor status stays hidden
+        let req = test::TestRequest::get()
+            .uri("/api/my-records")
+            .insert_header(("X-User-Id", "DOC-001"))
+            .to_request();
+        let profiles: Vec<PatientProfile> = test::call_and_read_body_json(&app, req).await;
+        let patient = profiles
+            .iter()
+            .find(|p| p.patient_id == "PAT-003-DEMO")
+            .unwrap();
+        assert_eq!(patient.emergency_info.sensitive_conditions, vec!["HIV"]);
+        assert_eq!(patient.emergency_info.organ_donor, None);
+    }
+}
@@ -50,54 +50,8 @@ pub struct NFCCard {
     pub last_used_at: Option<u64>,
 }
 
-/// National ID types supported by MediChain
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
-pub enum NationalIdType {
-    /// Ethiopia Fayda ID
-    FaydaId,
-    /// Ghana Card
-    GhanaCard,
-    /// Nigeria National Identification Number
-    NigeriaNIN,
-    /// South Africa Smart ID
-    SouthAfricaSmartId,
-    /// Kenya Huduma Namba
-    KenyaHuduma,
-    /// Generic/Other
-    Other,
-}
-
-impl NationalIdType {
-    /// Returns the country code for this ID type
-    pub fn country_code(&self) -> &'static str {
-        match self {
-            NationalIdType::FaydaId => "ETH",
-            NationalIdType::GhanaCard => "GHA",
-            NationalIdType::NigeriaNIN => "NGA",
-            NationalIdType::SouthAfricaSmartId => "ZAF",
-            NationalIdType::KenyaHuduma => "KEN",
-            NationalIdType::Other => "XXX",
-        }
-    }
-
-    /// Returns a display name for this ID type
-    pub fn display_name(&self) -> &'static str {
-        match self {
-            NationalIdType::FaydaId => "Fayda ID (Ethiopia)",
-            NationalIdType::GhanaCard => "Ghana Card",
-            NationalIdType::NigeriaNIN => "NIN (Nigeria)",
-            NationalIdType::SouthAfricaSmartId => "Smart ID (South Africa)",
-            NationalIdType::KenyaHuduma => "Huduma Namba (Kenya)",
-            NationalIdType::Other => "Other ID",
-        }
-    }
-}
-
-impl std::fmt::Display for NationalIdType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.display_name())
-    }
-}
+/// National ID types supported by MediChain (shared with the patient-identity pallet)
+pub use pallet_patient_identity::NationalIdType;
 
 /// Card status
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -427,7 +381,7 @@ mod tests {
     #[test]
     fn test_nfc_card_creation() {
         let patient_id = "MCHI-1234-5678".to_string();
-        let card = NFCCard::new(patient_id.clone(), NationalIdType::FaydaId);
+        let card = NFCCard::new(patient_id.clone(), NationalIdType::FaydaID);
 
         assert_eq!(card.patient_id, patient_id);
         assert!(card.card_id.starts_with("MC-"));
@@ -449,7 +403,7 @@ mod tests {
 
     #[test]
     fn test_nfc_tap_suspended_card() {
-        let mut card = NFCCard::new("test-patient".to_string(), NationalIdType::NigeriaNIN);
+        let mut card = NFCCard::new("test-patient".to_string(), NationalIdType::NIN);
         card.suspend();
 
         let result = card.tap();
@@ -461,7 +415,7 @@ mod tests {
 
     #[test]
     fn test_card_hash_verification() {
-        let card = NFCCard::new("patient-123".to_string(), NationalIdType::FaydaId);
+        let card = NFCCard::new("patient-123".to_string(), NationalIdType::FaydaID);
         assert!(card.verify_hash());
 
         // Tampered card should fail verification
@@ -500,10 +454,7 @@ mod tests {
     #[test]
     fn test_card_registry() {
         let registry = CardRegistry::new();
-        let card = NFCCard::new(
-            "patient-789".to_string(),
-            NationalIdType::SouthAfricaSmartId,
-        );
+        let card = NFCCard::new("patient-789".to_string(), NationalIdType::SmartID);
         let card_hash = card.card_hash.clone();
 
         registry.register_card(card).unwrap();
@@ -518,8 +469,8 @@ mod tests {
     #[test]
     fn test_registry_duplicate_patient() {
         let registry = CardRegistry::new();
-        let card1 = NFCCard::new("same-patient".to_string(), NationalIdType::FaydaId);
-        let card2 = NFCCard::new("same-patient".to_string(), NationalIdType::FaydaId);
+        let card1 = NFCCard::new("same-patient".to_string(), NationalIdType::FaydaID);
+        let card2 = NFCCard::new("same-patient".to_string(), NationalIdType::FaydaID);
 
         registry.register_card(card1).unwrap();
         let result = registry.register_card(card2);
@@ -551,11 +502,21 @@ mod tests {
 
     #[test]
     fn test_national_id_types() {
-        assert_eq!(NationalIdType::FaydaId.country_code(), "ETH");
+        assert_eq!(NationalIdType::FaydaID.country_code(), "ETH");
         assert_eq!(NationalIdType::GhanaCard.country_code(), "GHA");
-        assert_eq!(NationalIdType::NigeriaNIN.country_code(), "NGA");
+        assert_eq!(NationalIdType::NIN.country_code(), "NGA");
+
+        assert!(NationalIdType::FaydaID.display_name().contains("Ethiopia"));
 
-        assert!(NationalIdType::FaydaId.display_name().contains("Ethiopia"));
+        // Request strings resolve through the pallet's codes and legacy aliases
+        assert_eq!(
+            NationalIdType::from_code("southafrica"),
+            Some(NationalIdType::SmartID)
+        );
+        assert_eq!(
+            NationalIdType::from_code("refugee_id"),
+            Some(NationalIdType::RefugeeID)
+        );
     }
 
     #[test]
@@ -7,6 +7,7 @@
 //! ## Features
 //! - ChaCha20-Poly1305 authenticated encryption
 //! - SHA-256 hashing for data integrity
+//! - Keyed (HMAC-SHA256) national ID hashing, matching `medichain-crypto`
 //! - QR code generation for emergency access
 //! - Secure random number generation
 //!
@@ -231,10 +232,82 @@ pub fn sha256_hash(data: &str) -> String {
     hex::encode(result)
 }
 
-/// Compute SHA-256 hash for national ID (privacy-preserving)
-/// Adds domain separation to prevent rainbow table attacks
+/// National ID type codes, mirroring `NationalIdType::code` in the
+/// patient-identity pallet: (canonical code, accepted aliases)
+const NATIONAL_ID_TYPES: [(&str, &[&str]); 9] = [
+    ("fayda", &["faydaid", "ethiopia"]),
+    ("ghana_card", &["ghana", "ghanacard"]),
+    ("nin", &["nigeria"]),
+    ("smart_id", &["smartid", "southafrica"]),
+    ("huduma", &["kenya"]),
+    ("passport", &[]),
+    ("nhis", &[]),
+    ("refugee_id", &[]),
+    ("other", &[]),
+];
+
+/// Resolve an ID type name or alias (case-insensitive) to its canonical code
 #[wasm_bindgen]
-pub fn hash_national_id(national_id: &str, id_type: &str) -> String {
+pub fn canonical_id_type(id_type: &str) -> Option<String> {
+    NATIONAL_ID_TYPES
+        .iter()
+        .find(|(code, aliases)| {
+            code.eq_ignore_ascii_case(id_type)
+                || aliases
+                    .iter()
+                    .any(|alias| alias.eq_ignore_ascii_case(id_type))
+        })
+        .map(|(code, _)| code.to_string())
+}
+
+/// Registry pepper size bounds, matching `medichain_crypto::{MIN,MAX}_PEPPER_SIZE`
+const MIN_PEPPER_SIZE: usize = 32;
+const MAX_PEPPER_SIZE: usize = 64;
+
+/// Compute the keyed national ID hash (scheme 2, privacy-preserving)
+///
+/// `HMAC-SHA256(pepper, "MEDICHAIN-NID-V2:" || code || ":" || id)`, identical to
+/// `medichain_crypto::hash_national_id`. The pepper is the registry secret and is
+/// only provisioned to registration terminals; without it the hash cannot be
+/// brute-forced from the low-entropy ID.
+///
+/// `id_type` is canonicalized first, so "nigeria" and "NIN" hash identically
+/// and match what the chain stores under `NationalIdType::NIN`.
+#[wasm_bindgen]
+pub fn hash_national_id(
+    national_id: &str,
+    id_type: &str,
+    pepper_hex: &str,
+) -> Result<String, JsValue> {
+    use hmac::{Hmac, Mac};
+
+    let code = canonical_id_type(id_type)
+        .ok_or_else(|| JsValue::from_str(&format!("Unknown national ID type: {}", id_type)))?;
+    let pepper = hex::decode(pepper_hex)
+        .map_err(|e| JsValue::from_str(&format!("Invalid pepper: {}", e)))?;
+    if pepper.len() < MIN_PEPPER_SIZE || pepper.len() > MAX_PEPPER_SIZE {
+        return Err(JsValue::from_str("Invalid pepper length"));
+    }
+
+    let mut mac = <Hmac<Sha256> as Mac>::new_from_slice(&pepper)
+        .map_err(|_| JsValue::from_str("Invalid pepper length"))?;
+    // Domain separation
+    mac.update(b"MEDICHAIN-NID-V2:");
+    mac.update(code.as_bytes());
+    mac.update(b":");
+    mac.update(national_id.as_bytes());
+    Ok(hex::encode(mac.finalize().into_bytes()))
+}
+
+/// Compute the legacy unkeyed national ID hash (scheme 1)
+///
+/// Only for finding identities registered before scheme 2 so they can be
+/// re-hashed on-chain with `migrate_id_hash`. Do not use for new registrations.
+///
+/// Scheme 1 never canonicalized `id_type`, so it is hashed exactly as given:
+/// pass the string the identity was registered with (e.g. "nigeria", not "nin").
+#[wasm_bindgen]
+pub fn hash_national_id_v1(national_id: &str, id_type: &str) -> String {
     let mut hasher = Sha256::new();
     // Domain separation
     hasher.update(b"MEDICHAIN-NID-V1:");
@@ -432,12 +505,55 @@ mod tests {
 
     #[test]
     fn test_national_id_hash() {
-        let hash1 = hash_national_id("NIN-12345678901", "nigeria");
-        let hash2 = hash_national_id("NIN-12345678901", "nigeria");
-        let hash3 = hash_national_id("NIN-12345678901", "ghana");
+        let pepper = "42".repeat(32);
+        let hash1 = hash_national_id("NIN-12345678901", "nigeria", &pepper).unwrap();
+        let hash2 = hash_national_id("NIN-12345678901", "NIN", &pepper).unwrap();
+        let hash3 = hash_national_id("NIN-12345678901", "ghana", &pepper).unwrap();
+        let hash4 = hash_national_id("NIN-12345678901", "nin", &"43".repeat(32)).unwrap();
 
-        assert_eq!(hash1, hash2); // Same input = same output
+        assert_eq!(hash1, hash2); // Alias and code share a domain
         assert_ne!(hash1, hash3); // Different domain = different output
+        assert_ne!(hash1, hash4); // Different pepper = different output
+    }
+
+    #[test]
+    fn test_national_id_hash_vectors() {
+        // Same vectors as medichain-crypto, so browser and server agree
+        assert_eq!(
+            hash_national_id("NIN-12345678901", "nin", &"42".repeat(32)).unwrap(),
+            "0729ede3e80b79aa7dcab83ebfaf16166c1c88bc6534ba97208635e54d9e37ea"
+        );
+        assert_eq!(
+            hash_national_id_v1("NIN-12345678901", "nin"),
+            "9ac6da962457173171cc3767cb621e7772308c24ce14a94ecfa6f3caa6e092b7"
+        );
+    }
+
+    #[test]
+    fn test_national_id_hash_v1_uses_raw_id_type() {
+        // The old README registered with `hash_national_id(id, "nigeria")`,
+        // i.e. SHA-256("MEDICHAIN-NID-V1:nigeria:NIN-12345678901")
+        assert_eq!(
+            hash_national_id_v1("NIN-12345678901", "nigeria"),
+            "d93e8559ae436913fbe698493984aaf0480bbb0d89bc6c3183c9bf1ffe5815af"
+        );
+        assert_ne!(
+            hash_national_id_v1("NIN-12345678901", "nigeria"),
+            hash_national_id_v1("NIN-12345678901", "nin")
+        );
+    }
+
+    #[test]
+    fn test_canonical_id_type() {
+        assert_eq!(
+            canonical_id_type("SouthAfrica").as_deref(),
+            Some("smart_id")
+        );
+        assert_eq!(
+            canonical_id_type("refugee_id").as_deref(),
+            Some("refugee_id")
+        );
+        assert_eq!(canonical_id_type("drivers_licence"), None);
     }
 
     #[test]
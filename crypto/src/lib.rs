@@ -7,6 +7,7 @@
 //! - Argon2id for key derivation
 //! - Patient-controlled keys
 //! - Forward secrecy
+//! - Keyed (HMAC-SHA256) national ID hashes, versioned by scheme
 //!
 //! ## Safety
 //! - Constant-time operations (prevents timing attacks)
@@ -58,6 +59,21 @@ const ARGON2_T_COST: u32 = 3;
 /// Argon2 parallelism (4 threads)
 const ARGON2_P_COST: u32 = 4;
 
+/// Minimum registry pepper size for keyed national ID hashing (256 bits)
+pub const MIN_PEPPER_SIZE: usize = 32;
+
+/// Maximum registry pepper size (Rule 2: bounded)
+pub const MAX_PEPPER_SIZE: usize = 64;
+
+/// National ID hash scheme 1: unkeyed SHA-256 (legacy, enumerable)
+pub const NATIONAL_ID_HASH_V1: u8 = 1;
+
+/// National ID hash scheme 2: HMAC-SHA256 keyed with the registry pepper
+pub const NATIONAL_ID_HASH_V2: u8 = 2;
+
+/// Scheme used for newly registered national IDs
+pub const NATIONAL_ID_HASH_VERSION: u8 = NATIONAL_ID_HASH_V2;
+
 // =============================================================================
 // ERROR TYPES
 // =============================================================================
@@ -81,6 +97,8 @@ pub enum CryptoError {
     CiphertextTooShort,
     /// Random generation failed
     RandomGenerationFailed,
+    /// Pepper shorter than MIN_PEPPER_SIZE or longer than MAX_PEPPER_SIZE
+    InvalidPepperLength,
 }
 
 impl std::fmt::Display for CryptoError {
@@ -94,6 +112,7 @@ impl std::fmt::Display for CryptoError {
             CryptoError::PlaintextTooLarge => write!(f, "Plaintext too large"),
             CryptoError::CiphertextTooShort => write!(f, "Ciphertext too short"),
             CryptoError::RandomGenerationFailed => write!(f, "Random generation failed"),
+            CryptoError::InvalidPepperLength => write!(f, "Invalid pepper length"),
         }
     }
 }
@@ -276,7 +295,6 @@ pub fn decrypt(key: &EncryptionKey, encrypted: &EncryptedData) -> Result<Vec<u8>
 /// Compute SHA-256 hash of data
 ///
 /// Used for:
-/// - National ID hashing (privacy)
 /// - IPFS content addressing verification
 /// - Reason hash for access logs
 pub fn sha256(data: &[u8]) -> [u8; 32] {
@@ -289,6 +307,69 @@ pub fn sha256(data: &[u8]) -> [u8; 32] {
     hash
 }
 
+/// Registry secret mixed into national ID hashes, zeroized on drop
+///
+/// Held only by registration services; never stored on-chain or shipped to
+/// public clients. Without it, `IdToAccount` hashes cannot be brute-forced.
+#[derive(Clone, Zeroize)]
+#[zeroize(drop)]
+pub struct IdPepper {
+    bytes: Vec<u8>,
+}
+
+impl IdPepper {
+    /// Wrap a registry secret of `MIN_PEPPER_SIZE..=MAX_PEPPER_SIZE` bytes
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, CryptoError> {
+        if bytes.len() < MIN_PEPPER_SIZE || bytes.len() > MAX_PEPPER_SIZE {
+            return Err(CryptoError::InvalidPepperLength);
+        }
+        Ok(Self {
+            bytes: bytes.to_vec(),
+        })
+    }
+
+    /// Generate a new random pepper (for provisioning a registry)
+    pub fn generate() -> Result<Self, CryptoError> {
+        let mut bytes = vec![0u8; MIN_PEPPER_SIZE];
+        getrandom(&mut bytes)?;
+        Ok(Self { bytes })
+    }
+}
+
+/// Legacy (scheme 1) national ID hash: `SHA-256("MEDICHAIN-NID-V1:" || type || ":" || id)`
+///
+/// Kept only to find identities registered before scheme 2 so they can be
+/// re-hashed with `hash_national_id`. `id_type` is hashed as given, since
+/// scheme 1 registrations did not canonicalize it.
+pub fn hash_national_id_v1(national_id: &str, id_type: &str) -> [u8; 32] {
+    use sha2::{Digest, Sha256};
+    let mut hasher = Sha256::new();
+    hasher.update(b"MEDICHAIN-NID-V1:");
+    hasher.update(id_type.as_bytes());
+    hasher.update(b":");
+    hasher.update(national_id.as_bytes());
+    hasher.finalize().into()
+}
+
+/// National ID hash (scheme 2): `HMAC-SHA256(pepper, "MEDICHAIN-NID-V2:" || type || ":" || id)`
+///
+/// `id_type` is `NationalIdType::code()`, so the same ID under different
+/// types never collides.
+pub fn hash_national_id(
+    pepper: &IdPepper,
+    national_id: &str,
+    id_type: &str,
+) -> Result<[u8; 32], CryptoError> {
+    use hmac::{Hmac, Mac};
+    let mut mac = <Hmac<sha2::Sha256> as Mac>::new_from_slice(&pepper.bytes)
+        .map_err(|_| CryptoError::InvalidPepperLength)?;
+    mac.update(b"MEDICHAIN-NID-V2:");
+    mac.update(id_type.as_bytes());
+    mac.update(b":");
+    mac.update(national_id.as_bytes());
+    Ok(mac.finalize().into_bytes().into())
+}
+
 /// Generate a random salt for Argon2
 pub fn generate_salt() -> Result<[u8; SALT_SIZE], CryptoError> {
     let mut salt = [0u8; SALT_SIZE];
@@ -315,7 +396,7 @@ pub fn to_hex(bytes: &[u8]) -> String {
 
 /// Convert hex string to bytes
 pub fn from_hex(hex: &str) -> Result<Vec<u8>, CryptoError> {
-    if hex.len() % 2 != 0 {
+    if !hex.len().is_multiple_of(2) {
         return Err(CryptoError::DecryptionFailed);
     }
 
@@ -428,6 +509,50 @@ mod tests {
         assert_eq!(hash, hash2);
     }
 
+    #[test]
+    fn test_national_id_hash_is_keyed() {
+        let pepper1 = IdPepper::generate().unwrap();
+        let pepper2 = IdPepper::generate().unwrap();
+
+        let hash1 = hash_national_id(&pepper1, "NIN-12345678901", "nin").unwrap();
+        let hash2 = hash_national_id(&pepper1, "NIN-12345678901", "nin").unwrap();
+        let other_pepper = hash_national_id(&pepper2, "NIN-12345678901", "nin").unwrap();
+        let other_type = hash_national_id(&pepper1, "NIN-12345678901", "passport").unwrap();
+
+        assert_eq!(hash1, hash2);
+        assert_ne!(hash1, other_pepper);
+        assert_ne!(hash1, other_type);
+        // Keyed hash never equals the enumerable legacy hash
+        assert_ne!(hash1, hash_national_id_v1("NIN-12345678901", "nin"));
+    }
+
+    #[test]
+    fn test_national_id_hash_vectors() {
+        // Shared with client/wasm-crypto so both derivations stay identical
+        let pepper = IdPepper::from_bytes(&[0x42; 32]).unwrap();
+        assert_eq!(
+            to_hex(&hash_national_id(&pepper, "NIN-12345678901", "nin").unwrap()),
+            "0729ede3e80b79aa7dcab83ebfaf16166c1c88bc6534ba97208635e54d9e37ea"
+        );
+        assert_eq!(
+            to_hex(&hash_national_id_v1("NIN-12345678901", "nin")),
+            "9ac6da962457173171cc3767cb621e7772308c24ce14a94ecfa6f3caa6e092b7"
+        );
+    }
+
+    #[test]
+    fn test_pepper_length_bounds() {
+        assert_eq!(
+            IdPepper::from_bytes(&[0u8; MIN_PEPPER_SIZE - 1]).err(),
+            Some(CryptoError::InvalidPepperLength)
+        );
+        assert_eq!(
+            IdPepper::from_bytes(&[0u8; MAX_PEPPER_SIZE + 1]).err(),
+            Some(CryptoError::InvalidPepperLength)
+        );
+        assert!(IdPepper::from_bytes(&[0u8; MAX_PEPPER_SIZE]).is_ok());
+    }
+
     #[test]
     fn test_hex_conversion() {
         let original = vec![0xde, 0xad, 0xbe, 0xef];
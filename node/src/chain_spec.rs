@@ -0,0 +1,45 @@
+//! Chain specifications for the MediChain node
+//!
+//! Genesis state comes from the presets shipped inside `medichain-runtime`,
+//! so the node and the runtime never disagree about initial accounts.
+
+use medichain_runtime::{SS58_PREFIX, WASM_BINARY};
+use sc_service::{ChainType, Properties};
+
+/// Chain specification type used by the node
+pub type ChainSpec = sc_service::GenericChainSpec;
+
+/// Chain properties read by wallets and explorers (address format)
+fn chain_properties() -> Properties {
+    let mut properties = Properties::new();
+    properties.insert("ss58Format".into(), SS58_PREFIX.into());
+    properties
+}
+
+/// Single-authority development chain (`--dev`)
+pub fn development_chain_spec() -> Result<ChainSpec, String> {
+    Ok(ChainSpec::builder(
+        WASM_BINARY.ok_or_else(|| "Development wasm not available".to_string())?,
+        None,
+    )
+    .with_name("MediChain Development")
+    .with_id("medichain_dev")
+    .with_chain_type(ChainType::Development)
+    .with_genesis_config_preset_name(sp_genesis_builder::DEV_RUNTIME_PRESET)
+    .with_properties(chain_properties())
+    .build())
+}
+
+/// Two-authority local testnet (`--chain local`)
+pub fn local_chain_spec() -> Result<ChainSpec, String> {
+    Ok(ChainSpec::builder(
+        WASM_BINARY.ok_or_else(|| "Development wasm not available".to_string())?,
+        None,
+    )
+    .with_name("MediChain Local Testnet")
+    .with_id("medichain_local_testnet")
+    .with_chain_type(ChainType::Local)
+    .with_genesis_config_preset_name(sp_genesis_builder::LOCAL_TESTNET_RUNTIME_PRESET)
+    .with_properties(chain_properties())
+    .build())
+}
@@ -1,28 +1,27 @@
 //! # MediChain Node
 //!
 //! Entry point for the MediChain blockchain node.
-//! This is a placeholder that demonstrates the runtime integration.
+//! Runs `medichain-runtime` with Aura block authoring, GRANDPA finality
+//! and a JSON-RPC server (HTTP + WebSocket, default port 9944).
 //!
-//! For the hackathon demo, we use an API server to simulate blockchain interactions.
+//! ## Usage
+//! - `medichain-node --dev` - single-authority development chain (Alice)
+//! - `medichain-node build-spec --chain local` - export a chain specification
+//! - `medichain-node purge-chain --dev` - wipe the local database
 
 // © 2025 Trustware. All rights reserved.
 // Proprietary and confidential.
 // Unauthorized use is strictly prohibited.
 
-fn main() {
-    println!("╔═══════════════════════════════════════════════════════════╗");
-    println!("║                    MEDICHAIN NODE                          ║");
-    println!("║     Emergency Medical Records via National ID + NFC        ║");
-    println!("╚═══════════════════════════════════════════════════════════╝");
-    println!();
-    println!("📦 Runtime: medichain-runtime v0.1.0");
-    println!("🔧 Pallets:");
-    println!("   • pallet-patient-identity - National ID registration");
-    println!("   • pallet-medical-records  - Health record storage");
-    println!("   • pallet-access-control   - Emergency access management");
-    println!();
-    println!("⚠️  Full node implementation pending.");
-    println!("   For demo, use the API server: cargo run --bin medichain-api");
-    println!();
-    println!("🏥 MediChain - Saving lives with blockchain technology.");
+// `sc_cli::Error` / `sc_service::Error` are upstream types we cannot shrink.
+#![allow(clippy::result_large_err)]
+
+mod chain_spec;
+mod cli;
+mod command;
+mod rpc;
+mod service;
+
+fn main() -> sc_cli::Result<()> {
+    command::run()
 }
@@ -0,0 +1,580 @@
+//! Benchmarks for access-control pallet
+//!
+//! Each benchmark exercises the worst-case storage path of its extrinsic.
+//! Run with `cargo test -p pallet-access-control --features runtime-benchmarks`.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet as AccessControl;
+use frame_benchmarking::v2::*;
+use frame_support::{
+    sp_runtime::Saturating,
+    traits::{EnsureOrigin, Get, Hooks},
+};
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
+use sp_std::vec;
+
+/// Register a full-length hospital run by a separate facility Admin, with
+/// `admin` made a ministry Admin
+fn register_hospital<T: Config>(admin: &T::AccountId) -> Result<FacilityId, BenchmarkError> {
+    UserRoles::<T>::insert(admin, Role::Admin);
+    let origin = T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+    let facility_admin: T::AccountId = account("facility_admin", 0, 0);
+    let name = vec![b'h'; T::MaxFacilityNameLength::get() as usize];
+    AccessControl::<T>::register_facility(origin, FacilityKind::Hospital, name, facility_admin)
+        .expect("AdminOrigin can register a facility");
+    Ok(NextFacilityId::<T>::get().saturating_sub(1))
+}
+
+/// Record an unexpired credential for `provider` at facility 0
+fn insert_credential<T: Config>(provider: &T::AccountId) {
+    let credential = Credential {
+        licence_id_hash: [7u8; 32],
+        issuing_body: IssuingBody::MedicalCouncil,
+        valid_until: u32::MAX.into(),
+        facility_id: 0,
+        registered_at: frame_system::Pallet::<T>::block_number(),
+    };
+    Credentials::<T>::insert(provider, credential);
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn assign_role() -> Result<(), BenchmarkError> {
+        let origin =
+            T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let doctor: T::AccountId = account("doctor", 0, 0);
+        insert_credential::<T>(&doctor);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, doctor.clone(), Role::Doctor);
+
+        assert_eq!(UserRoles::<T>::get(&doctor), Some(Role::Doctor));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn revoke_role() -> Result<(), BenchmarkError> {
+        let origin =
+            T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let doctor: T::AccountId = account("doctor", 0, 0);
+        AccessControl::<T>::set_role(&doctor, Role::Doctor);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, doctor.clone());
+
+        assert!(UserRoles::<T>::get(&doctor).is_none());
+        Ok(())
+    }
+
+    #[benchmark]
+    fn grant_emergency_access() {
+        let doctor: T::AccountId = whitelisted_caller();
+        AccessControl::<T>::set_role(&doctor, Role::Doctor);
+        let patient: T::AccountId = account("patient", 0, 0);
+        // Worst case: audit log is full, so the oldest entry is pruned
+        AuditLogLength::<T>::insert(&patient, T::MaxAuditEntries::get());
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(doctor.clone()),
+            patient.clone(),
+            [1u8; 32],
+        );
+
+        assert!(ActiveAccess::<T>::contains_key(&patient, &doctor));
+    }
+
+    #[benchmark]
+    fn revoke_access() {
+        let doctor: T::AccountId = account("doctor", 0, 0);
+        AccessControl::<T>::set_role(&doctor, Role::Doctor);
+        let patient: T::AccountId = account("patient", 0, 0);
+        UserRoles::<T>::insert(&patient, Role::Patient);
+        AccessControl::<T>::grant_emergency_access(
+            RawOrigin::Signed(doctor.clone()).into(),
+            patient.clone(),
+            [1u8; 32],
+        )
+        .expect("doctor can grant emergency access");
+        // Worst case: a guardian revokes, so the appointment is read as well
+        let guardian: T::AccountId = whitelisted_caller();
+        AccessControl::<T>::appoint_guardian(
+            RawOrigin::Signed(patient.clone()).into(),
+            patient.clone(),
+            guardian.clone(),
+            GuardianRelationship::HealthcareProxy,
+            GuardianScopes::ALL,
+            None,
+        )
+        .expect("patient can appoint a guardian");
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(guardian), patient.clone(), doctor.clone());
+
+        assert!(!AccessControl::<T>::has_valid_access(&patient, &doctor));
+    }
+
+    #[benchmark]
+    fn cleanup_expired_access() {
+        let doctor: T::AccountId = account("doctor", 0, 0);
+        AccessControl::<T>::set_role(&doctor, Role::Doctor);
+        let patient: T::AccountId = account("patient", 0, 0);
+        AccessControl::<T>::grant_emergency_access(
+            RawOrigin::Signed(doctor.clone()).into(),
+            patient.clone(),
+            [1u8; 32],
+        )
+        .expect("doctor can grant emergency access");
+        let now = frame_system::Pallet::<T>::block_number()
+            .saturating_add(T::DoctorAccessDuration::get())
+            .saturating_add(1u32.into());
+        frame_system::Pallet::<T>::set_block_number(now);
+        let caller: T::AccountId = whitelisted_caller();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), patient.clone(), doctor.clone());
+
+        assert!(!ActiveAccess::<T>::contains_key(&patient, &doctor));
+    }
+
+    #[benchmark]
+    fn on_initialize_expired(n: Linear<0, { T::MaxExpiriesPerBlock::get() }>) {
+        let doctor: T::AccountId = account("doctor", 0, 0);
+        AccessControl::<T>::set_role(&doctor, Role::Doctor);
+        // Rule 2: bounded by MaxExpiriesPerBlock
+        for i in 0..n {
+            let patient: T::AccountId = account("patient", i, 0);
+            AccessControl::<T>::grant_emergency_access(
+                RawOrigin::Signed(doctor.clone()).into(),
+                patient,
+                [1u8; 32],
+            )
+            .expect("doctor can grant emergency access");
+        }
+        let expires_at = frame_system::Pallet::<T>::block_number()
+            .saturating_add(T::DoctorAccessDuration::get());
+        let now = expires_at.saturating_add(1u32.into());
+        frame_system::Pallet::<T>::set_block_number(now);
+
+        #[block]
+        {
+            AccessControl::<T>::on_initialize(now);
+        }
+
+        assert!(ExpiryQueue::<T>::get(expires_at).is_empty());
+        assert_eq!(
+            AccessCount::<T>::get(account::<T::AccountId>("patient", 0, 0)),
+            0
+        );
+    }
+
+    #[benchmark]
+    fn grant_access() {
+        let patient: T::AccountId = whitelisted_caller();
+        UserRoles::<T>::insert(&patient, Role::Patient);
+        let doctor: T::AccountId = account("doctor", 0, 0);
+        AccessControl::<T>::set_role(&doctor, Role::Doctor);
+        let duration: BlockNumberFor<T> = T::DefaultAccessDuration::get();
+        AuditLogLength::<T>::insert(&patient, T::MaxAuditEntries::get());
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(patient.clone()),
+            doctor.clone(),
+            AccessType::Full,
+            Some(duration),
+        );
+
+        assert!(AccessControl::<T>::has_valid_access(&patient, &doctor));
+    }
+
+    #[benchmark]
+    fn appoint_guardian() {
+        let patient: T::AccountId = whitelisted_caller();
+        UserRoles::<T>::insert(&patient, Role::Patient);
+        let guardian: T::AccountId = account("guardian", 0, 0);
+        let duration: BlockNumberFor<T> = T::DefaultAccessDuration::get();
+        AuditLogLength::<T>::insert(&patient, T::MaxAuditEntries::get());
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(patient.clone()),
+            patient.clone(),
+            guardian.clone(),
+            GuardianRelationship::Parent,
+            GuardianScopes::ALL,
+            Some(duration),
+        );
+
+        assert!(Guardians::<T>::contains_key(&patient, &guardian));
+    }
+
+    #[benchmark]
+    fn remove_guardian() {
+        let patient: T::AccountId = whitelisted_caller();
+        UserRoles::<T>::insert(&patient, Role::Patient);
+        let guardian: T::AccountId = account("guardian", 0, 0);
+        AccessControl::<T>::appoint_guardian(
+            RawOrigin::Signed(patient.clone()).into(),
+            patient.clone(),
+            guardian.clone(),
+            GuardianRelationship::NextOfKin,
+            GuardianScopes::ALL,
+            None,
+        )
+        .expect("patient can appoint a guardian");
+        AuditLogLength::<T>::insert(&patient, T::MaxAuditEntries::get());
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(patient.clone()),
+            patient.clone(),
+            guardian.clone(),
+        );
+
+        assert!(!Guardians::<T>::contains_key(&patient, &guardian));
+    }
+
+    #[benchmark]
+    fn grant_access_as_guardian() {
+        let patient: T::AccountId = account("patient", 0, 0);
+        UserRoles::<T>::insert(&patient, Role::Patient);
+        let doctor: T::AccountId = account("doctor", 0, 0);
+        AccessControl::<T>::set_role(&doctor, Role::Doctor);
+        let guardian: T::AccountId = whitelisted_caller();
+        AccessControl::<T>::appoint_guardian(
+            RawOrigin::Signed(patient.clone()).into(),
+            patient.clone(),
+            guardian.clone(),
+            GuardianRelationship::LegalGuardian,
+            GuardianScopes::ALL,
+            None,
+        )
+        .expect("patient can appoint a guardian");
+        let duration: BlockNumberFor<T> = T::DefaultAccessDuration::get();
+        AuditLogLength::<T>::insert(&patient, T::MaxAuditEntries::get());
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(guardian),
+            patient.clone(),
+            doctor.clone(),
+            AccessType::Full,
+            Some(duration),
+        );
+
+        assert!(AccessControl::<T>::has_valid_access(&patient, &doctor));
+    }
+
+    #[benchmark]
+    fn set_consent_directives() {
+        let patient: T::AccountId = account("patient", 0, 0);
+        UserRoles::<T>::insert(&patient, Role::Patient);
+        // Worst case: a guardian sets them, so the appointment is read as well
+        let guardian: T::AccountId = whitelisted_caller();
+        AccessControl::<T>::appoint_guardian(
+            RawOrigin::Signed(patient.clone()).into(),
+            patient.clone(),
+            guardian.clone(),
+            GuardianRelationship::LegalGuardian,
+            GuardianScopes::ALL,
+            None,
+        )
+        .expect("patient can appoint a guardian");
+        AuditLogLength::<T>::insert(&patient, T::MaxAuditEntries::get());
+        let directives = ConsentDirectives {
+            restrict_sensitive_to_treating: true,
+            ..Default::default()
+        };
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(guardian), patient.clone(), directives);
+
+        assert_eq!(AccessControl::<T>::consent_directives(&patient), directives);
+    }
+
+    #[benchmark]
+    fn submit_justification() {
+        let doctor: T::AccountId = whitelisted_caller();
+        AccessControl::<T>::set_role(&doctor, Role::Doctor);
+        let patient: T::AccountId = account("patient", 0, 0);
+        AccessControl::<T>::grant_emergency_access(
+            RawOrigin::Signed(doctor.clone()).into(),
+            patient.clone(),
+            [1u8; 32],
+        )
+        .expect("doctor can grant emergency access");
+        AuditLogLength::<T>::insert(&patient, T::MaxAuditEntries::get());
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(doctor), 0, [2u8; 32]);
+
+        assert_eq!(
+            BreakGlassReviews::<T>::get(0).map(|review| review.status),
+            Some(ReviewStatus::Justified)
+        );
+    }
+
+    #[benchmark]
+    fn review_emergency_access() {
+        let doctor: T::AccountId = account("doctor", 0, 0);
+        AccessControl::<T>::set_role(&doctor, Role::Doctor);
+        let patient: T::AccountId = account("patient", 0, 0);
+        AccessControl::<T>::grant_emergency_access(
+            RawOrigin::Signed(doctor.clone()).into(),
+            patient.clone(),
+            [1u8; 32],
+        )
+        .expect("doctor can grant emergency access");
+        AccessControl::<T>::submit_justification(
+            RawOrigin::Signed(doctor.clone()).into(),
+            0,
+            [2u8; 32],
+        )
+        .expect("accessor can justify their grant");
+        let officer: T::AccountId = whitelisted_caller();
+        UserRoles::<T>::insert(&officer, Role::PrivacyOfficer);
+        AuditLogLength::<T>::insert(&patient, T::MaxAuditEntries::get());
+
+        // Worst case: flagging a justified grant updates the misuse metric
+        #[extrinsic_call]
+        _(RawOrigin::Signed(officer), 0, ReviewVerdict::Flag);
+
+        assert_eq!(MisuseCount::<T>::get(&doctor), 1);
+    }
+
+    #[benchmark]
+    fn on_initialize_unjustified(n: Linear<0, { T::MaxExpiriesPerBlock::get() }>) {
+        let doctor: T::AccountId = account("doctor", 0, 0);
+        AccessControl::<T>::set_role(&doctor, Role::Doctor);
+        // Rule 2: bounded by MaxExpiriesPerBlock
+        for i in 0..n {
+            let patient: T::AccountId = account("patient", i, 0);
+            AccessControl::<T>::grant_emergency_access(
+                RawOrigin::Signed(doctor.clone()).into(),
+                patient,
+                [1u8; 32],
+            )
+            .expect("doctor can grant emergency access");
+        }
+        let justify_by =
+            frame_system::Pallet::<T>::block_number().saturating_add(T::JustificationPeriod::get());
+        let now = justify_by.saturating_add(1u32.into());
+        frame_system::Pallet::<T>::set_block_number(now);
+        // Measure the deadline queue only, even if grants expire at the same block
+        ExpiryQueue::<T>::remove(now.saturating_sub(1u32.into()));
+
+        #[block]
+        {
+            AccessControl::<T>::on_initialize(now);
+        }
+
+        assert!(ReviewDeadlines::<T>::get(justify_by).is_empty());
+        assert_eq!(MisuseCount::<T>::get(&doctor), n);
+    }
+
+    #[benchmark]
+    fn register_credential() -> Result<(), BenchmarkError> {
+        let admin: T::AccountId = account("admin", 0, 0);
+        let facility_id = register_hospital::<T>(&admin)?;
+        let origin =
+            T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let doctor: T::AccountId = account("doctor", 0, 0);
+        AccessControl::<T>::set_role(&doctor, Role::Doctor);
+        // Worst case: a renewal dropping the old warning from a full queue,
+        // far enough out that the new warning is queued too
+        let period = T::CredentialWarningPeriod::get();
+        let old_valid_until = frame_system::Pallet::<T>::block_number()
+            .saturating_add(period)
+            .saturating_add(1u32.into());
+        let old_warn_at = old_valid_until.saturating_sub(period);
+        CredentialWarnings::<T>::mutate(old_warn_at, |queue| {
+            // Rule 2: bounded by MaxExpiriesPerBlock
+            for i in 1..T::MaxExpiriesPerBlock::get() {
+                let _ = queue.try_push(account("doctor", i, 0));
+            }
+            let _ = queue.try_push(doctor.clone());
+        });
+        Credentials::<T>::mutate(&doctor, |credential| {
+            if let Some(credential) = credential {
+                credential.valid_until = old_valid_until;
+            }
+        });
+        let valid_until = old_valid_until.saturating_add(period);
+
+        #[extrinsic_call]
+        _(
+            origin as T::RuntimeOrigin,
+            doctor.clone(),
+            [7u8; 32],
+            IssuingBody::MedicalCouncil,
+            valid_until,
+            facility_id,
+        );
+
+        assert_eq!(
+            Credentials::<T>::get(&doctor).map(|c| c.valid_until),
+            Some(valid_until)
+        );
+        assert!(!CredentialWarnings::<T>::get(old_warn_at).contains(&doctor));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn on_initialize_credential_warnings(
+        n: Linear<0, { T::MaxExpiriesPerBlock::get() }>,
+    ) -> Result<(), BenchmarkError> {
+        let admin: T::AccountId = account("admin", 0, 0);
+        let facility_id = register_hospital::<T>(&admin)?;
+        let valid_until = frame_system::Pallet::<T>::block_number()
+            .saturating_add(T::CredentialWarningPeriod::get())
+            .saturating_add(1u32.into());
+        // Rule 2: bounded by MaxExpiriesPerBlock
+        for i in 0..n {
+            let origin =
+                T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+            let doctor: T::AccountId = account("doctor", i, 0);
+            AccessControl::<T>::register_credential(
+                origin,
+                doctor,
+                [7u8; 32],
+                IssuingBody::MedicalCouncil,
+                valid_until,
+                facility_id,
+            )
+            .expect("AdminOrigin can record a credential");
+        }
+        let now = valid_until.saturating_sub(T::CredentialWarningPeriod::get());
+        frame_system::Pallet::<T>::set_block_number(now);
+
+        #[block]
+        {
+            AccessControl::<T>::on_initialize(now);
+        }
+
+        assert!(CredentialWarnings::<T>::get(now).is_empty());
+        Ok(())
+    }
+
+    #[benchmark]
+    fn register_facility() -> Result<(), BenchmarkError> {
+        let origin =
+            T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let facility_admin: T::AccountId = account("facility_admin", 0, 0);
+        let name = vec![b'h'; T::MaxFacilityNameLength::get() as usize];
+
+        #[extrinsic_call]
+        _(
+            origin as T::RuntimeOrigin,
+            FacilityKind::Hospital,
+            name,
+            facility_admin,
+        );
+
+        assert!(Facilities::<T>::contains_key(0));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn set_facility_admin() -> Result<(), BenchmarkError> {
+        let admin: T::AccountId = account("admin", 0, 0);
+        let facility_id = register_hospital::<T>(&admin)?;
+        let origin =
+            T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let new_admin: T::AccountId = account("facility_admin", 1, 0);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, facility_id, new_admin.clone());
+
+        assert_eq!(
+            Facilities::<T>::get(facility_id).map(|f| f.admin),
+            Some(new_admin)
+        );
+        Ok(())
+    }
+
+    #[benchmark]
+    fn add_facility_staff() -> Result<(), BenchmarkError> {
+        // Worst case: a licensed role, so the credential is checked
+        let admin: T::AccountId = account("admin", 0, 0);
+        let facility_id = register_hospital::<T>(&admin)?;
+        let origin =
+            T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let doctor: T::AccountId = account("doctor", 0, 0);
+        insert_credential::<T>(&doctor);
+
+        #[extrinsic_call]
+        _(
+            origin as T::RuntimeOrigin,
+            facility_id,
+            doctor.clone(),
+            Role::Doctor,
+        );
+
+        assert_eq!(ActiveFacility::<T>::get(&doctor), Some(facility_id));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn remove_facility_staff() -> Result<(), BenchmarkError> {
+        let admin: T::AccountId = whitelisted_caller();
+        let facility_id = register_hospital::<T>(&admin)?;
+        let doctor: T::AccountId = account("doctor", 0, 0);
+        FacilityStaff::<T>::insert(facility_id, &doctor, Role::Doctor);
+        ActiveFacility::<T>::insert(&doctor, facility_id);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(admin), facility_id, doctor.clone());
+
+        assert!(!ActiveFacility::<T>::contains_key(&doctor));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn set_active_facility() -> Result<(), BenchmarkError> {
+        let admin: T::AccountId = account("admin", 0, 0);
+        let facility_id = register_hospital::<T>(&admin)?;
+        let doctor: T::AccountId = whitelisted_caller();
+        FacilityStaff::<T>::insert(facility_id, &doctor, Role::Doctor);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(doctor.clone()), facility_id);
+
+        assert_eq!(ActiveFacility::<T>::get(&doctor), Some(facility_id));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn mark_review_unjustified() {
+        let doctor: T::AccountId = account("doctor", 0, 0);
+        AccessControl::<T>::set_role(&doctor, Role::Doctor);
+        let patient: T::AccountId = account("patient", 0, 0);
+        AccessControl::<T>::grant_emergency_access(
+            RawOrigin::Signed(doctor.clone()).into(),
+            patient.clone(),
+            [1u8; 32],
+        )
+        .expect("doctor can grant emergency access");
+        let justify_by =
+            frame_system::Pallet::<T>::block_number().saturating_add(T::JustificationPeriod::get());
+        frame_system::Pallet::<T>::set_block_number(justify_by.saturating_add(1u32.into()));
+        AuditLogLength::<T>::insert(&patient, T::MaxAuditEntries::get());
+        let caller: T::AccountId = whitelisted_caller();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), 0);
+
+        assert_eq!(MisuseCount::<T>::get(&doctor), 1);
+    }
+
+    impl_benchmark_test_suite!(
+        AccessControl,
+        crate::mock::new_test_ext(),
+        crate::mock::Test
+    );
+}
@@ -2,12 +2,26 @@
 //!
 //! MediChain access management with role-based permissions.
 //! Healthcare providers can manage patient records based on their roles.
-//! Patients can only read their own records (enforced at API layer).
+//! Other pallets consult roles and grants through the [`AccessPermissions`] trait.
 //!
 //! ## Key Principle
 //! - Patients CANNOT self-register; must be registered by healthcare provider
 //! - Doctors/Nurses can create and edit medical records
 //! - Patients can only READ their own records (no write access)
+//! - Admin accounts (Ministry of Health) are seeded in the genesis config
+//! - Role changes after genesis require `AdminOrigin` (M-of-N ministry council)
+//! - Guardians (parents, legal guardians, next of kin, healthcare proxies) act
+//!   for minors and incapacitated patients within their delegated scopes
+//! - Patients record consent directives; [`ConsentDirectives::disclosure`] decides
+//!   what each kind of viewer may see, on-chain and in the API
+//! - Every emergency (break-glass) grant opens a review: the accessor justifies
+//!   it within `JustificationPeriod` blocks, then an Admin or Privacy Officer
+//!   approves or flags it; unjustified and flagged grants count as misuse
+//! - Licensed providers carry a credential (licence, issuing body, expiry,
+//!   facility); once it lapses their role grants no permissions
+//! - Facilities (hospitals, clinics, labs, pharmacies, ambulance services) hold
+//!   their own staff roles, managed by each facility's Admin; staff act in the
+//!   role of the facility they are currently working for, for its patients only
 //!
 //! ## NASA Power of 10 Compliance
 //! - Rule 1: No recursion
@@ -17,23 +31,110 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+pub mod migrations;
 pub mod mock;
 pub mod tests;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+
 pub use pallet::*;
+pub use weights::WeightInfo;
+
+/// Role and access checks other pallets depend on through their `Config`
+///
+/// Implemented by this pallet's `Pallet<T>`; the medical-records and
+/// patient-identity pallets take it as `type AccessPermissions`.
+pub trait AccessPermissions<AccountId> {
+    /// Role an account may act in for `patient`, if any (`None` once its
+    /// credential has lapsed; facility roles only for that facility's patients)
+    fn role(who: &AccountId, patient: &AccountId) -> Option<Role>;
+
+    /// Whether the account's role for `patient` holds `permission` in the permission matrix
+    fn has_permission(who: &AccountId, patient: &AccountId, permission: Permission) -> bool;
+
+    /// Whether `accessor` holds valid (non-expired, non-revoked) access to `patient`
+    fn has_valid_access(patient: &AccountId, accessor: &AccountId) -> bool;
+
+    /// Whether `who` is an unexpired guardian of `patient` delegated `scope`
+    fn is_guardian(patient: &AccountId, who: &AccountId, scope: GuardianScope) -> bool;
+
+    /// What `who` may see of `patient`'s data under the patient's consent directives
+    fn disclosure(patient: &AccountId, who: &AccountId) -> Disclosure;
+
+    /// Give the account the Patient role unless it already holds a global role
+    ///
+    /// Facility staff get it too: their facility role still takes precedence
+    /// (see `active_role`), so registering as a patient never hides it.
+    fn ensure_patient_role(who: &AccountId);
+
+    /// Append an entry to the patient's audit log
+    fn log_audit(patient: &AccountId, who: &AccountId, action: AuditAction, reason_hash: [u8; 32]);
+
+    /// Facility the account is currently acting for, if it is facility staff
+    fn active_facility(who: &AccountId) -> Option<FacilityId>;
+
+    /// Whether `patient` may be treated (see [`PatientStatus::is_active`])
+    fn is_active_patient(patient: &AccountId) -> bool;
+
+    /// Assign a role directly, bypassing `AdminOrigin`, with an unexpired
+    /// credential for licensed roles (benchmark setup only)
+    #[cfg(feature = "runtime-benchmarks")]
+    fn set_role(who: &AccountId, role: Role);
+
+    /// Give `accessor` indefinite Regular access to `patient` (benchmark setup only)
+    #[cfg(feature = "runtime-benchmarks")]
+    fn set_valid_access(patient: &AccountId, accessor: &AccountId);
+}
+
+/// Lifecycle status of patients, as kept by the identity registry
+///
+/// Implemented by the patient-identity pallet and taken by this pallet as
+/// `type PatientStatus`; `()` treats every patient as active.
+pub trait PatientStatus<AccountId> {
+    /// False once the patient's identity is inactive, deceased or merged;
+    /// true for active identities and accounts without one
+    fn is_active(patient: &AccountId) -> bool;
+}
+
+impl<AccountId> PatientStatus<AccountId> for () {
+    fn is_active(_patient: &AccountId) -> bool {
+        true
+    }
+}
+
+/// Facilities patients are treated at, as kept with their health records
+///
+/// Implemented by the medical-records pallet and taken by this pallet as
+/// `type PatientFacilities`; `()` ties no patient to any facility, so facility
+/// roles reach only patients who granted the staff member access.
+pub trait PatientFacilities<AccountId> {
+    /// Whether `patient`'s record was created or last modified at `facility_id`,
+    /// or the patient has no record yet (any facility may take them in)
+    fn is_treated_at(patient: &AccountId, facility_id: FacilityId) -> bool;
+}
+
+impl<AccountId> PatientFacilities<AccountId> for () {
+    fn is_treated_at(_patient: &AccountId, _facility_id: FacilityId) -> bool {
+        false
+    }
+}
 
 #[frame_support::pallet]
 pub mod pallet {
+    use crate::WeightInfo;
     use frame_support::pallet_prelude::*;
-    use frame_support::sp_runtime::Saturating;
+    use frame_support::sp_runtime::{
+        traits::{One, Zero},
+        Saturating,
+    };
     use frame_system::pallet_prelude::*;
+    use sp_std::vec::Vec;
 
-    /// Default emergency access duration in blocks (~15 minutes at 6s/block)
-    pub const DEFAULT_ACCESS_DURATION: u32 = 150;
-    /// Maximum reason length
-    pub const MAX_REASON_LENGTH: u32 = 256;
-    /// Maximum active accesses per patient (Rule 2: bounded)
-    pub const MAX_ACTIVE_ACCESSES: u32 = 10;
+    /// Current storage version
+    /// (1: access grants may be indefinite, `expires_at` is optional)
+    pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
 
     // ========================================================================
     // ROLE-BASED ACCESS CONTROL
@@ -42,13 +143,27 @@ pub mod pallet {
     /// User roles in the MediChain system
     ///
     /// Access hierarchy:
-    /// - Admin: Can assign/revoke roles, full system access
+    /// - Admin: Ministry staff, full system access (roles change via `AdminOrigin`)
     /// - Doctor: Can register patients, create/edit medical records
     /// - Nurse: Can register patients, create/edit medical records (limited)
-    /// - LabTechnician: Can add lab results only
-    /// - Pharmacist: Can view prescriptions, mark as dispensed
-    /// - Patient: Read-only access to own records (enforced at API layer)
-    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Copy)]
+    /// - LabTechnician: Can add lab results and verify blood types
+    /// - Pharmacist: Can view records (prescriptions) under an access grant
+    /// - PrivacyOfficer: Reviews emergency (break-glass) access, no clinical access
+    /// - Patient: Read-only access to own records (reads recorded via `record_access`)
+    #[derive(
+        Clone,
+        Encode,
+        Decode,
+        Eq,
+        PartialEq,
+        RuntimeDebug,
+        TypeInfo,
+        MaxEncodedLen,
+        Copy,
+        Default,
+        serde::Serialize,
+        serde::Deserialize,
+    )]
     pub enum Role {
         /// System administrator (Ministry of Health)
         Admin,
@@ -61,19 +176,112 @@ pub mod pallet {
         /// Licensed pharmacist
         Pharmacist,
         /// Patient (read-only, cannot self-register)
+        #[default]
         Patient,
+        /// Privacy officer (reviews emergency access, no record access)
+        PrivacyOfficer,
     }
 
-    impl Default for Role {
-        fn default() -> Self {
-            Role::Patient
+    /// Actions gated by role (see `Role::permissions` for the matrix)
+    #[derive(
+        Clone,
+        Encode,
+        Decode,
+        Eq,
+        PartialEq,
+        RuntimeDebug,
+        TypeInfo,
+        MaxEncodedLen,
+        Copy,
+        serde::Serialize,
+        serde::Deserialize,
+    )]
+    pub enum Permission {
+        /// Register patient identities and issue cards
+        RegisterPatient,
+        /// Verify a patient's identity
+        VerifyIdentity,
+        /// Create and edit medical records, alerts and documents, approve lab results
+        EditRecord,
+        /// View patient records (subject to access grants on-chain)
+        ViewRecords,
+        /// Submit lab results and add lab-result documents
+        AddLabResult,
+        /// Set a patient's blood type from a lab result or test
+        VerifyBloodType,
+        /// Take time-limited emergency access to a patient
+        EmergencyAccess,
+        /// Approve or flag emergency access after the fact
+        ReviewEmergencyAccess,
+    }
+
+    impl Role {
+        /// Permission matrix: the permissions each role holds
+        ///
+        /// Single source of truth for role checks, shared with the API server.
+        pub fn permissions(&self) -> &'static [Permission] {
+            use Permission::*;
+            match self {
+                Role::Admin => &[
+                    RegisterPatient,
+                    VerifyIdentity,
+                    EditRecord,
+                    ViewRecords,
+                    AddLabResult,
+                    EmergencyAccess,
+                    ReviewEmergencyAccess,
+                ],
+                Role::Doctor => &[
+                    RegisterPatient,
+                    VerifyIdentity,
+                    EditRecord,
+                    ViewRecords,
+                    AddLabResult,
+                    VerifyBloodType,
+                    EmergencyAccess,
+                ],
+                Role::Nurse => &[
+                    RegisterPatient,
+                    VerifyIdentity,
+                    EditRecord,
+                    ViewRecords,
+                    AddLabResult,
+                    EmergencyAccess,
+                ],
+                Role::LabTechnician => &[ViewRecords, AddLabResult, VerifyBloodType],
+                Role::Pharmacist => &[ViewRecords],
+                Role::Patient => &[],
+                Role::PrivacyOfficer => &[ReviewEmergencyAccess],
+            }
+        }
+
+        /// Whether this role holds `permission` (Rule 2: bounded by the matrix row)
+        pub fn has_permission(&self, permission: Permission) -> bool {
+            self.permissions().contains(&permission)
+        }
+
+        /// Whether this role is a licensed profession whose credential can lapse
+        pub fn requires_licence(&self) -> bool {
+            matches!(
+                self,
+                Role::Doctor | Role::Nurse | Role::LabTechnician | Role::Pharmacist
+            )
+        }
+
+        /// Whether only `AdminOrigin` may give this role at a facility
+        /// (Privacy Officers; licensed roles are vetted through the credential)
+        pub fn requires_admin_origin(&self) -> bool {
+            matches!(self, Role::PrivacyOfficer)
         }
     }
 
     /// Type of access granted
-    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[derive(
+        Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default,
+    )]
     pub enum AccessType {
         /// Emergency access (time-limited, auto-expires)
+        #[default]
         Emergency,
         /// Regular access (granted by patient)
         Regular,
@@ -81,12 +289,6 @@ pub mod pallet {
         Full,
     }
 
-    impl Default for AccessType {
-        fn default() -> Self {
-            AccessType::Emergency
-        }
-    }
-
     /// Access log entry stored on-chain
     #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
     #[scale_info(skip_type_params(T))]
@@ -97,21 +299,538 @@ pub mod pallet {
         pub access_type: AccessType,
         /// Block when access was granted
         pub granted_at: BlockNumberFor<T>,
-        /// Block when access expires
-        pub expires_at: BlockNumberFor<T>,
+        /// Block when access expires (`None` = until revoked by the patient)
+        pub expires_at: Option<BlockNumberFor<T>>,
         /// Reason hash (stored encrypted)
         pub reason_hash: [u8; 32],
         /// Whether access has been revoked early
         pub revoked: bool,
     }
 
+    impl<T: Config> AccessLog<T> {
+        /// Whether the grant is past its expiry block (indefinite grants never expire)
+        pub fn is_expired(&self, now: BlockNumberFor<T>) -> bool {
+            self.expires_at.is_some_and(|expires_at| now > expires_at)
+        }
+    }
+
+    /// Action recorded in a patient's audit log
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub enum AuditAction {
+        /// Access granted (emergency or by the patient)
+        AccessGranted(AccessType),
+        /// Access revoked by the patient or the accessor
+        AccessRevoked,
+        /// Access expired and was removed
+        AccessExpired,
+        /// Provider read the patient's records
+        RecordRead,
+        /// Provider created or changed the patient's records
+        RecordWritten,
+        /// Guardian appointed for the patient
+        GuardianAppointed,
+        /// Guardian removed (by the patient, a provider, themselves, or after expiry)
+        GuardianRemoved,
+        /// Consent directives changed by the patient or a guardian
+        ConsentUpdated,
+        /// Emergency accessor justified their grant (reason hash is the justification)
+        AccessJustified,
+        /// Emergency grant was not justified in time
+        AccessUnjustified,
+        /// Reviewer approved or flagged an emergency grant
+        AccessReviewed(ReviewVerdict),
+    }
+
+    /// Audit log entry stored on-chain (append-only)
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct AuditEntry<T: Config> {
+        /// Provider whose access or action the entry concerns
+        pub who: T::AccountId,
+        /// What happened
+        pub action: AuditAction,
+        /// Block when it happened
+        pub block: BlockNumberFor<T>,
+        /// Reason hash of the grant or action (`[0; 32]` if none given)
+        pub reason_hash: [u8; 32],
+    }
+
+    /// How a guardian is related to the patient they act for
+    #[derive(
+        Clone,
+        Encode,
+        Decode,
+        Eq,
+        PartialEq,
+        RuntimeDebug,
+        TypeInfo,
+        MaxEncodedLen,
+        Copy,
+        serde::Serialize,
+        serde::Deserialize,
+    )]
+    pub enum GuardianRelationship {
+        /// Parent of a minor
+        Parent,
+        /// Court-appointed legal guardian
+        LegalGuardian,
+        /// Next of kin of an incapacitated patient
+        NextOfKin,
+        /// Healthcare proxy named by the patient (power of attorney)
+        HealthcareProxy,
+    }
+
+    /// Action a guardian can take on the patient's behalf
+    #[derive(
+        Clone,
+        Encode,
+        Decode,
+        Eq,
+        PartialEq,
+        RuntimeDebug,
+        TypeInfo,
+        MaxEncodedLen,
+        Copy,
+        serde::Serialize,
+        serde::Deserialize,
+    )]
+    pub enum GuardianScope {
+        /// Grant Regular or Full access to providers (`grant_access_as_guardian`)
+        GrantAccess,
+        /// Revoke providers' access (`revoke_access`)
+        RevokeAccess,
+        /// Read the patient's records (`record_access` in medical-records)
+        ViewRecords,
+    }
+
+    /// Scopes delegated to a guardian
+    #[derive(
+        Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Copy, Default,
+    )]
+    pub struct GuardianScopes {
+        /// May grant access to providers
+        pub grant_access: bool,
+        /// May revoke providers' access
+        pub revoke_access: bool,
+        /// May read the patient's records
+        pub view_records: bool,
+    }
+
+    impl GuardianScopes {
+        /// Every scope (parents of minors, legal guardians)
+        pub const ALL: Self = Self {
+            grant_access: true,
+            revoke_access: true,
+            view_records: true,
+        };
+
+        /// Whether `scope` is delegated
+        pub fn contains(&self, scope: GuardianScope) -> bool {
+            match scope {
+                GuardianScope::GrantAccess => self.grant_access,
+                GuardianScope::RevokeAccess => self.revoke_access,
+                GuardianScope::ViewRecords => self.view_records,
+            }
+        }
+
+        /// Whether no scope is delegated
+        pub fn is_empty(&self) -> bool {
+            !(self.grant_access || self.revoke_access || self.view_records)
+        }
+    }
+
+    /// Guardian appointment stored on-chain
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct Guardianship<T: Config> {
+        /// How the guardian is related to the patient
+        pub relationship: GuardianRelationship,
+        /// What the guardian may do on the patient's behalf
+        pub scopes: GuardianScopes,
+        /// Patient who made the appointment (`None` = appointed through `AdminOrigin`)
+        pub appointed_by: Option<T::AccountId>,
+        /// Block when the guardian was appointed
+        pub appointed_at: BlockNumberFor<T>,
+        /// Last block the appointment is valid (`None` = until removed)
+        pub expires_at: Option<BlockNumberFor<T>>,
+    }
+
+    impl<T: Config> Guardianship<T> {
+        /// Whether the appointment is past its expiry block
+        pub fn is_expired(&self, now: BlockNumberFor<T>) -> bool {
+            self.expires_at.is_some_and(|expires_at| now > expires_at)
+        }
+    }
+
+    /// Patient consent directives
+    ///
+    /// Unset directives read as `Default`, which keeps the emergency subset
+    /// visible to every provider (the behaviour before directives existed).
+    #[derive(
+        Clone,
+        Encode,
+        Decode,
+        Eq,
+        PartialEq,
+        RuntimeDebug,
+        TypeInfo,
+        MaxEncodedLen,
+        Copy,
+        serde::Serialize,
+        serde::Deserialize,
+    )]
+    pub struct ConsentDirectives {
+        /// Emergency subset (blood type, allergies, medications, alerts) and
+        /// emergency access open to any provider, not only treating ones
+        pub share_emergency_subset: bool,
+        /// Sensitive conditions (e.g. HIV status) hidden from providers without
+        /// a Regular or Full grant
+        pub restrict_sensitive_to_treating: bool,
+        /// Records may be used for de-identified research
+        pub allow_research_use: bool,
+        /// Organ donor status shown to providers
+        pub disclose_organ_donor: bool,
+    }
+
+    impl Default for ConsentDirectives {
+        fn default() -> Self {
+            Self {
+                share_emergency_subset: true,
+                restrict_sensitive_to_treating: false,
+                allow_research_use: false,
+                disclose_organ_donor: true,
+            }
+        }
+    }
+
+    /// Whose view of the patient's data consent is evaluated for
+    #[derive(
+        Clone,
+        Encode,
+        Decode,
+        Eq,
+        PartialEq,
+        RuntimeDebug,
+        TypeInfo,
+        MaxEncodedLen,
+        Copy,
+        serde::Serialize,
+        serde::Deserialize,
+    )]
+    pub enum ConsentViewer {
+        /// The patient, or a guardian with the `ViewRecords` scope
+        PatientOrGuardian,
+        /// Provider holding a Regular or Full grant from the patient
+        TreatingProvider,
+        /// Any other provider: emergency access holders and card taps
+        EmergencyProvider,
+        /// De-identified research export
+        Researcher,
+    }
+
+    /// What a viewer may see, as decided by `ConsentDirectives::disclosure`
+    #[derive(
+        Clone,
+        Encode,
+        Decode,
+        Eq,
+        PartialEq,
+        RuntimeDebug,
+        TypeInfo,
+        MaxEncodedLen,
+        Copy,
+        Default,
+        serde::Serialize,
+        serde::Deserialize,
+    )]
+    pub struct Disclosure {
+        /// Blood type, allergies, medications, active alerts, DNR status
+        pub emergency_subset: bool,
+        /// Conditions the patient marked sensitive (e.g. HIV status)
+        pub sensitive_conditions: bool,
+        /// Organ donor status
+        pub organ_donor: bool,
+        /// Full health record and documents (`record_access`, downloads)
+        pub full_records: bool,
+    }
+
+    impl ConsentDirectives {
+        /// Evaluate the directives for a viewer
+        ///
+        /// Single source of truth for consent decisions, shared with the API server.
+        pub fn disclosure(&self, viewer: ConsentViewer) -> Disclosure {
+            match viewer {
+                ConsentViewer::PatientOrGuardian => Disclosure {
+                    emergency_subset: true,
+                    sensitive_conditions: true,
+                    organ_donor: true,
+                    full_records: true,
+                },
+                ConsentViewer::TreatingProvider => Disclosure {
+                    emergency_subset: true,
+                    sensitive_conditions: true,
+                    organ_donor: self.disclose_organ_donor,
+                    full_records: true,
+                },
+                ConsentViewer::EmergencyProvider => Disclosure {
+                    emergency_subset: self.share_emergency_subset,
+                    sensitive_conditions: self.share_emergency_subset
+                        && !self.restrict_sensitive_to_treating,
+                    organ_donor: self.share_emergency_subset && self.disclose_organ_donor,
+                    full_records: self.share_emergency_subset,
+                },
+                ConsentViewer::Researcher => Disclosure {
+                    emergency_subset: self.allow_research_use,
+                    sensitive_conditions: self.allow_research_use
+                        && !self.restrict_sensitive_to_treating,
+                    organ_donor: self.allow_research_use && self.disclose_organ_donor,
+                    full_records: self.allow_research_use,
+                },
+            }
+        }
+    }
+
+    /// Consent directives as stored on-chain, with who last changed them
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct ConsentRecord<T: Config> {
+        /// The directives in force
+        pub directives: ConsentDirectives,
+        /// Patient or guardian who set them
+        pub updated_by: T::AccountId,
+        /// Block when they were set
+        pub updated_at: BlockNumberFor<T>,
+    }
+
+    /// Where an open break-glass review stands
+    ///
+    /// Approved and flagged reviews are closed: they leave `BreakGlassReviews`
+    /// and only their verdict remains, in the patient's audit log.
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Copy)]
+    pub enum ReviewStatus {
+        /// Waiting for the accessor's justification
+        AwaitingJustification,
+        /// Justified by the accessor, waiting for a reviewer
+        Justified,
+        /// Deadline passed without a justification (counted as misuse), waiting for a reviewer
+        Unjustified,
+    }
+
+    /// Reviewer's decision on an emergency grant
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Copy)]
+    pub enum ReviewVerdict {
+        /// The emergency was genuine
+        Approve,
+        /// The access was not warranted
+        Flag,
+    }
+
+    /// Review opened for every emergency (break-glass) grant
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct BreakGlassReview<T: Config> {
+        /// Patient whose records were accessed
+        pub patient: T::AccountId,
+        /// Provider who took emergency access
+        pub accessor: T::AccountId,
+        /// Reason hash given with the grant
+        pub reason_hash: [u8; 32],
+        /// Block when access was granted
+        pub granted_at: BlockNumberFor<T>,
+        /// Last block the accessor may submit a justification
+        pub justify_by: BlockNumberFor<T>,
+        /// Hash of the accessor's justification, once submitted
+        pub justification_hash: Option<[u8; 32]>,
+        /// Where the review stands
+        pub status: ReviewStatus,
+    }
+
+    /// Id of a facility in the facility registry
+    pub type FacilityId = u32;
+
+    /// Kind of healthcare facility
+    #[derive(
+        Clone,
+        Encode,
+        Decode,
+        Eq,
+        PartialEq,
+        RuntimeDebug,
+        TypeInfo,
+        MaxEncodedLen,
+        Copy,
+        serde::Serialize,
+        serde::Deserialize,
+    )]
+    pub enum FacilityKind {
+        /// Hospital (inpatient and outpatient care)
+        Hospital,
+        /// Clinic or health centre
+        Clinic,
+        /// Diagnostic laboratory
+        Laboratory,
+        /// Pharmacy
+        Pharmacy,
+        /// Ambulance service
+        AmbulanceService,
+    }
+
+    impl FacilityKind {
+        /// Staff roles this kind of facility may hand out
+        ///
+        /// Admins (ministry) and Patients are never facility roles.
+        pub fn allows(&self, role: Role) -> bool {
+            use Role::*;
+            match self {
+                FacilityKind::Hospital => matches!(
+                    role,
+                    Doctor | Nurse | LabTechnician | Pharmacist | PrivacyOfficer
+                ),
+                FacilityKind::Clinic => {
+                    matches!(role, Doctor | Nurse | LabTechnician | Pharmacist)
+                }
+                FacilityKind::Laboratory => matches!(role, LabTechnician),
+                FacilityKind::Pharmacy => matches!(role, Pharmacist),
+                FacilityKind::AmbulanceService => matches!(role, Doctor | Nurse),
+            }
+        }
+    }
+
+    /// Facility in the registry
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct Facility<T: Config> {
+        /// Kind of facility
+        pub kind: FacilityKind,
+        /// Registered name
+        pub name: BoundedVec<u8, T::MaxFacilityNameLength>,
+        /// Facility Admin, who manages its staff
+        pub admin: T::AccountId,
+        /// Block when it was registered
+        pub registered_at: BlockNumberFor<T>,
+    }
+
+    /// Body that issued a provider's licence
+    #[derive(
+        Clone,
+        Encode,
+        Decode,
+        Eq,
+        PartialEq,
+        RuntimeDebug,
+        TypeInfo,
+        MaxEncodedLen,
+        Copy,
+        serde::Serialize,
+        serde::Deserialize,
+    )]
+    pub enum IssuingBody {
+        /// Medical council (doctors)
+        MedicalCouncil,
+        /// Nursing and midwifery council
+        NursingCouncil,
+        /// Pharmacy council
+        PharmacyCouncil,
+        /// Health professions council (laboratory and allied professions)
+        HealthProfessionsCouncil,
+    }
+
+    /// Professional credential of a licensed provider
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct Credential<T: Config> {
+        /// Hash of the licence number (the number itself stays off-chain)
+        pub licence_id_hash: [u8; 32],
+        /// Council that issued the licence
+        pub issuing_body: IssuingBody,
+        /// Last block the licence is valid
+        pub valid_until: BlockNumberFor<T>,
+        /// Facility the provider practises at
+        pub facility_id: FacilityId,
+        /// Block when it was recorded
+        pub registered_at: BlockNumberFor<T>,
+    }
+
+    impl<T: Config> Credential<T> {
+        /// Whether the licence has lapsed by `now`
+        pub fn is_expired(&self, now: BlockNumberFor<T>) -> bool {
+            now > self.valid_until
+        }
+    }
+
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     #[pallet::config]
     pub trait Config: frame_system::Config {
         /// The overarching event type
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// Weight information for extrinsics in this pallet
+        type WeightInfo: WeightInfo;
+
+        /// Origin allowed to assign and revoke roles, including Admin, and to
+        /// register facilities and credentials
+        ///
+        /// Wire this to an M-of-N ministry council or multisig in the runtime.
+        type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Lifecycle status of patients (the patient-identity pallet)
+        type PatientStatus: crate::PatientStatus<Self::AccountId>;
+
+        /// Facilities patients are treated at (the medical-records pallet)
+        type PatientFacilities: crate::PatientFacilities<Self::AccountId>;
+
+        /// Emergency access duration in blocks for Admins and other providers
+        #[pallet::constant]
+        type DefaultAccessDuration: Get<BlockNumberFor<Self>>;
+
+        /// Emergency access duration in blocks for Doctors
+        #[pallet::constant]
+        type DoctorAccessDuration: Get<BlockNumberFor<Self>>;
+
+        /// Emergency access duration in blocks for Nurses
+        #[pallet::constant]
+        type NurseAccessDuration: Get<BlockNumberFor<Self>>;
+
+        /// Maximum length of the plaintext reason behind `reason_hash`
+        #[pallet::constant]
+        type MaxReasonLength: Get<u32>;
+
+        /// Maximum active accesses per patient (Rule 2: bounded)
+        #[pallet::constant]
+        type MaxActiveAccesses: Get<u32>;
+
+        /// Maximum grants expiring, reviews or credential warnings falling due at
+        /// the same block (Rule 2: bounds `on_initialize`)
+        #[pallet::constant]
+        type MaxExpiriesPerBlock: Get<u32>;
+
+        /// Later blocks a grant expiry or review deadline spills into when its
+        /// block is already full (Rule 2: bounds the search)
+        #[pallet::constant]
+        type MaxQueueSpill: Get<u32>;
+
+        /// Audit entries retained per patient; older entries are pruned (Rule 2: bounded)
+        #[pallet::constant]
+        type MaxAuditEntries: Get<u32>;
+
+        /// Maximum guardians per patient, expired ones included (Rule 2: bounded)
+        #[pallet::constant]
+        type MaxGuardians: Get<u32>;
+
+        /// Blocks an emergency accessor has to justify their grant
+        #[pallet::constant]
+        type JustificationPeriod: Get<BlockNumberFor<Self>>;
+
+        /// Blocks before a credential lapses that `CredentialExpiring` is raised
+        #[pallet::constant]
+        type CredentialWarningPeriod: Get<BlockNumberFor<Self>>;
+
+        /// Maximum length of a facility name
+        #[pallet::constant]
+        type MaxFacilityNameLength: Get<u32>;
     }
 
     // ========================================================================
@@ -144,27 +863,261 @@ pub mod pallet {
     pub type AccessCount<T: Config> =
         StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
 
+    /// Storage: Expiry queue, block number to (patient, accessor) grants expiring at it
+    /// Drained in `on_initialize` of the following block; grants that find
+    /// their block full are queued at the next block with room
+    #[pallet::storage]
+    #[pallet::getter(fn expiry_queue)]
+    pub type ExpiryQueue<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        BoundedVec<(T::AccountId, T::AccountId), T::MaxExpiriesPerBlock>,
+        ValueQuery,
+    >;
+
+    /// Storage: Audit log, (patient, index) to entry
+    /// Append-only; only the latest `MaxAuditEntries` entries per patient are kept
+    #[pallet::storage]
+    #[pallet::getter(fn audit_entry)]
+    pub type AuditLog<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId, // patient
+        Twox64Concat,
+        u32, // index
+        AuditEntry<T>,
+        OptionQuery,
+    >;
+
+    /// Storage: Number of audit entries ever appended per patient (next index)
+    #[pallet::storage]
+    #[pallet::getter(fn audit_log_length)]
+    pub type AuditLogLength<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+    /// Storage: Map (patient, guardian) to guardian appointment
+    #[pallet::storage]
+    #[pallet::getter(fn guardianship)]
+    pub type Guardians<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId, // patient
+        Blake2_128Concat,
+        T::AccountId, // guardian
+        Guardianship<T>,
+        OptionQuery,
+    >;
+
+    /// Storage: Count of appointed guardians per patient
+    #[pallet::storage]
+    #[pallet::getter(fn guardian_count)]
+    pub type GuardianCount<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+    /// Storage: Map patient to consent directives (unset = `ConsentDirectives::default()`)
+    #[pallet::storage]
+    #[pallet::getter(fn consent_record)]
+    pub type Consents<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, ConsentRecord<T>, OptionQuery>;
+
+    /// Storage: Id of the next break-glass review
+    #[pallet::storage]
+    #[pallet::getter(fn next_review_id)]
+    pub type NextReviewId<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    /// Storage: Map review id to open break-glass review
+    /// Removed once approved or flagged; the verdict stays in the audit log
+    #[pallet::storage]
+    #[pallet::getter(fn break_glass_review)]
+    pub type BreakGlassReviews<T: Config> =
+        StorageMap<_, Twox64Concat, u32, BreakGlassReview<T>, OptionQuery>;
+
+    /// Storage: Justification deadlines, block number to reviews due at it
+    /// Drained in `on_initialize` of the following block; reviews may sit
+    /// at a later block than their `justify_by` when the queue was full
+    #[pallet::storage]
+    #[pallet::getter(fn review_deadlines)]
+    pub type ReviewDeadlines<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        BoundedVec<u32, T::MaxExpiriesPerBlock>,
+        ValueQuery,
+    >;
+
+    /// Storage: Misuse metric, provider to unjustified or flagged emergency grants
+    #[pallet::storage]
+    #[pallet::getter(fn misuse_count)]
+    pub type MisuseCount<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+    /// Storage: Map provider to professional credential
+    /// Licensed roles grant no permissions without an unexpired credential,
+    /// including providers seeded before credentials were recorded
+    #[pallet::storage]
+    #[pallet::getter(fn credential)]
+    pub type Credentials<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, Credential<T>, OptionQuery>;
+
+    /// Storage: Credential warnings, block number to providers warned at it
+    /// Drained in `on_initialize` of the same block; a full block spills into
+    /// the next one with room (see `MaxQueueSpill`)
+    #[pallet::storage]
+    #[pallet::getter(fn credential_warnings)]
+    pub type CredentialWarnings<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        BoundedVec<T::AccountId, T::MaxExpiriesPerBlock>,
+        ValueQuery,
+    >;
+
+    /// Storage: Id of the next registered facility
+    #[pallet::storage]
+    #[pallet::getter(fn next_facility_id)]
+    pub type NextFacilityId<T: Config> = StorageValue<_, FacilityId, ValueQuery>;
+
+    /// Storage: Map facility id to facility
+    #[pallet::storage]
+    #[pallet::getter(fn facility)]
+    pub type Facilities<T: Config> =
+        StorageMap<_, Twox64Concat, FacilityId, Facility<T>, OptionQuery>;
+
+    /// Storage: Map (facility, account) to the account's role at that facility
+    #[pallet::storage]
+    #[pallet::getter(fn facility_role)]
+    pub type FacilityStaff<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        FacilityId,
+        Blake2_128Concat,
+        T::AccountId,
+        Role,
+        OptionQuery,
+    >;
+
+    /// Storage: Map staff account to the facility it is currently acting for
+    #[pallet::storage]
+    #[pallet::getter(fn active_facility)]
+    pub type ActiveFacility<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, FacilityId, OptionQuery>;
+
+    // ========================================================================
+    // GENESIS
+    // ========================================================================
+
+    /// Genesis configuration: initial facilities, credentials and role assignments
+    ///
+    /// Seeds the first Admins and providers; later ones are added through
+    /// `assign_role` with `AdminOrigin` approval. Licensed roles need a
+    /// credential listed here, at one of the genesis facilities.
+    #[pallet::genesis_config]
+    #[derive(frame_support::DefaultNoBound)]
+    pub struct GenesisConfig<T: Config> {
+        /// Accounts and their initial roles (Admins, providers)
+        pub roles: Vec<(T::AccountId, Role)>,
+        /// Facilities (kind, name, facility Admin), numbered from 0
+        pub facilities: Vec<(FacilityKind, Vec<u8>, T::AccountId)>,
+        /// Provider credentials
+        /// (provider, licence_id_hash, issuing_body, valid_until, facility_id)
+        pub credentials: Vec<(
+            T::AccountId,
+            [u8; 32],
+            IssuingBody,
+            BlockNumberFor<T>,
+            FacilityId,
+        )>,
+    }
+
+    #[pallet::genesis_build]
+    impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+        fn build(&self) {
+            let now = <frame_system::Pallet<T>>::block_number();
+
+            // Rule 2: bounded by the genesis list length
+            for (kind, name, admin) in &self.facilities {
+                let facility_id = NextFacilityId::<T>::get();
+                let facility = Facility::<T> {
+                    kind: *kind,
+                    name: name
+                        .clone()
+                        .try_into()
+                        .expect("Genesis facility name exceeds MaxFacilityNameLength"),
+                    admin: admin.clone(),
+                    registered_at: now,
+                };
+                Facilities::<T>::insert(facility_id, facility);
+                NextFacilityId::<T>::put(
+                    facility_id
+                        .checked_add(1)
+                        .expect("Genesis facilities exhaust FacilityId"),
+                );
+            }
+
+            // Rule 2: bounded by the genesis list length
+            for (provider, licence_id_hash, issuing_body, valid_until, facility_id) in
+                &self.credentials
+            {
+                assert!(*valid_until > now, "Genesis credential already expired");
+                assert!(
+                    Facilities::<T>::contains_key(facility_id),
+                    "Genesis credential names an unknown facility"
+                );
+                assert!(
+                    !Credentials::<T>::contains_key(provider),
+                    "Provider listed twice in access-control genesis credentials"
+                );
+                // Past the spill the credential still lapses, only unwarned
+                let warn_at = valid_until.saturating_sub(T::CredentialWarningPeriod::get());
+                if warn_at > now {
+                    Pallet::<T>::schedule_credential_warning(warn_at, provider);
+                }
+                let credential = Credential {
+                    licence_id_hash: *licence_id_hash,
+                    issuing_body: *issuing_body,
+                    valid_until: *valid_until,
+                    facility_id: *facility_id,
+                    registered_at: now,
+                };
+                Credentials::<T>::insert(provider, credential);
+            }
+
+            // Rule 2: bounded by the genesis list length
+            for (account, role) in &self.roles {
+                assert!(
+                    !UserRoles::<T>::contains_key(account),
+                    "Account listed twice in access-control genesis roles"
+                );
+                assert!(
+                    !role.requires_licence() || Credentials::<T>::contains_key(account),
+                    "Licensed genesis role without a genesis credential"
+                );
+                UserRoles::<T>::insert(account, role);
+            }
+        }
+    }
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
-        /// Role assigned to account [account, role, assigned_by]
-        RoleAssigned {
-            account: T::AccountId,
-            role: Role,
-            assigned_by: T::AccountId,
-        },
-        /// Role revoked from account [account, role, revoked_by]
-        RoleRevoked {
-            account: T::AccountId,
-            role: Role,
-            revoked_by: T::AccountId,
-        },
+        /// Role assigned to account by `AdminOrigin` [account, role]
+        RoleAssigned { account: T::AccountId, role: Role },
+        /// Role revoked from account by `AdminOrigin` [account, role]
+        RoleRevoked { account: T::AccountId, role: Role },
         /// Emergency access granted [patient, accessor, expires_at]
         EmergencyAccessGranted {
             patient: T::AccountId,
             accessor: T::AccountId,
             expires_at: BlockNumberFor<T>,
         },
+        /// Access granted by patient [patient, accessor, access_type, expires_at]
+        AccessGranted {
+            patient: T::AccountId,
+            accessor: T::AccountId,
+            access_type: AccessType,
+            expires_at: Option<BlockNumberFor<T>>,
+        },
         /// Access revoked [patient, accessor]
         AccessRevoked {
             patient: T::AccountId,
@@ -175,125 +1128,306 @@ pub mod pallet {
             patient: T::AccountId,
             accessor: T::AccountId,
         },
-    }
-
-    #[pallet::error]
-    pub enum Error<T> {
-        /// Caller does not have required role
-        InsufficientRole,
-        /// Cannot assign Admin role (only genesis)
-        CannotAssignAdmin,
-        /// Account already has this role
-        RoleAlreadyAssigned,
-        /// Account does not have a role to revoke
-        NoRoleToRevoke,
-        /// Cannot revoke own role
-        CannotRevokeOwnRole,
-        /// Only healthcare providers can perform this action
-        NotHealthcareProvider,
-        /// Access already granted to this accessor
-        AccessAlreadyGranted,
-        /// Access not found
-        AccessNotFound,
-        /// Access already expired
-        AccessExpired,
-        /// Too many active accesses
-        TooManyAccesses,
-        /// Not authorized to grant access
-        NotAuthorized,
-        /// Cannot revoke own access
-        CannotRevokeSelf,
-        /// Access already revoked
-        AlreadyRevoked,
-    }
-
-    #[pallet::call]
-    impl<T: Config> Pallet<T> {
+        /// Guardian appointed [patient, guardian, relationship, scopes, appointed_by, expires_at]
+        GuardianAppointed {
+            patient: T::AccountId,
+            guardian: T::AccountId,
+            relationship: GuardianRelationship,
+            scopes: GuardianScopes,
+            appointed_by: Option<T::AccountId>,
+            expires_at: Option<BlockNumberFor<T>>,
+        },
+        /// Guardian removed [patient, guardian, removed_by]
+        /// (`removed_by` is `None` when removed through `AdminOrigin`)
+        GuardianRemoved {
+            patient: T::AccountId,
+            guardian: T::AccountId,
+            removed_by: Option<T::AccountId>,
+        },
+        /// Access granted by a guardian on the patient's behalf [patient, guardian, accessor]
+        AccessGrantedByGuardian {
+            patient: T::AccountId,
+            guardian: T::AccountId,
+            accessor: T::AccountId,
+        },
+        /// Consent directives set [patient, directives, updated_by]
+        ConsentDirectivesUpdated {
+            patient: T::AccountId,
+            directives: ConsentDirectives,
+            updated_by: T::AccountId,
+        },
+        /// Review opened for an emergency grant [review_id, patient, accessor, justify_by]
+        BreakGlassReviewOpened {
+            review_id: u32,
+            patient: T::AccountId,
+            accessor: T::AccountId,
+            justify_by: BlockNumberFor<T>,
+        },
+        /// Accessor justified their emergency grant [review_id, accessor]
+        EmergencyAccessJustified {
+            review_id: u32,
+            accessor: T::AccountId,
+        },
+        /// Reviewer approved an emergency grant [review_id, reviewer]
+        EmergencyAccessApproved {
+            review_id: u32,
+            reviewer: T::AccountId,
+        },
+        /// Alert: reviewer flagged an emergency grant as misuse
+        /// [review_id, patient, accessor, reviewer, misuse_count]
+        EmergencyAccessFlagged {
+            review_id: u32,
+            patient: T::AccountId,
+            accessor: T::AccountId,
+            reviewer: T::AccountId,
+            misuse_count: u32,
+        },
+        /// Alert: emergency grant not justified in time [review_id, patient, accessor, misuse_count]
+        EmergencyAccessUnjustified {
+            review_id: u32,
+            patient: T::AccountId,
+            accessor: T::AccountId,
+            misuse_count: u32,
+        },
+        /// Credential recorded or renewed by `AdminOrigin`
+        /// [provider, issuing_body, valid_until, facility_id]
+        CredentialRegistered {
+            provider: T::AccountId,
+            issuing_body: IssuingBody,
+            valid_until: BlockNumberFor<T>,
+            facility_id: FacilityId,
+        },
+        /// Credential lapses within `CredentialWarningPeriod` blocks [provider, valid_until]
+        CredentialExpiring {
+            provider: T::AccountId,
+            valid_until: BlockNumberFor<T>,
+        },
+        /// Facility registered [facility_id, kind, admin]
+        FacilityRegistered {
+            facility_id: FacilityId,
+            kind: FacilityKind,
+            admin: T::AccountId,
+        },
+        /// Facility Admin replaced [facility_id, admin]
+        FacilityAdminChanged {
+            facility_id: FacilityId,
+            admin: T::AccountId,
+        },
+        /// Staff role given at a facility [facility_id, account, role, added_by]
+        /// (`added_by` is `None` for `AdminOrigin`)
+        FacilityStaffAdded {
+            facility_id: FacilityId,
+            account: T::AccountId,
+            role: Role,
+            added_by: Option<T::AccountId>,
+        },
+        /// Staff removed from a facility [facility_id, account, removed_by]
+        FacilityStaffRemoved {
+            facility_id: FacilityId,
+            account: T::AccountId,
+            removed_by: T::AccountId,
+        },
+        /// Staff switched the facility they act for [account, facility_id]
+        ActiveFacilitySet {
+            account: T::AccountId,
+            facility_id: FacilityId,
+        },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Caller does not have required role
+        InsufficientRole,
+        /// Account already has this role
+        RoleAlreadyAssigned,
+        /// Account does not have a role to revoke
+        NoRoleToRevoke,
+        /// Only healthcare providers can perform this action
+        NotHealthcareProvider,
+        /// Access already granted to this accessor
+        AccessAlreadyGranted,
+        /// Access not found
+        AccessNotFound,
+        /// Access already expired
+        AccessExpired,
+        /// Too many active accesses
+        TooManyAccesses,
+        /// Not authorized to grant access
+        NotAuthorized,
+        /// Cannot revoke own access
+        CannotRevokeSelf,
+        /// Access already revoked
+        AlreadyRevoked,
+        /// Only Regular or Full access can be granted by a patient
+        InvalidAccessType,
+        /// Access duration must be at least one block
+        InvalidDuration,
+        /// Account is already a guardian of this patient
+        GuardianAlreadyAppointed,
+        /// A patient cannot be their own guardian
+        CannotAppointSelf,
+        /// Accounts holding a provider role cannot be guardians
+        GuardianIsProvider,
+        /// Account is not a guardian of this patient
+        GuardianNotFound,
+        /// Patient has the maximum number of guardians
+        TooManyGuardians,
+        /// A guardian must be delegated at least one scope
+        NoGuardianScopes,
+        /// Guardian appointment has expired
+        GuardianshipExpired,
+        /// Guardian was not delegated the scope this action needs
+        GuardianScopeMissing,
+        /// Patient's consent directives do not allow this access
+        ConsentWithheld,
+        /// Break-glass review not found (never opened, or already closed)
+        ReviewNotFound,
+        /// Break-glass review ids are exhausted
+        ReviewIdOverflow,
+        /// Review is not waiting for a justification
+        NotAwaitingJustification,
+        /// Justification deadline has passed
+        JustificationDeadlinePassed,
+        /// Review is still waiting for the accessor's justification
+        ReviewNotReady,
+        /// Reviewers cannot review their own emergency access
+        CannotReviewOwnAccess,
+        /// Review's justification deadline has not passed yet
+        JustificationNotOverdue,
+        /// Credential must be valid for at least one more block
+        CredentialAlreadyExpired,
+        /// Licensed role needs an unexpired credential first
+        CredentialRequired,
+        /// Facility not found
+        FacilityNotFound,
+        /// Facility name exceeds `MaxFacilityNameLength`
+        FacilityNameTooLong,
+        /// Facility ids are exhausted
+        FacilityIdOverflow,
+        /// Caller is neither the facility's Admin nor a ministry Admin
+        NotFacilityAdmin,
+        /// Role cannot be given at this kind of facility
+        RoleNotAllowedAtFacility,
+        /// Privacy Officer roles are given at facilities only by `AdminOrigin`
+        RoleRequiresAdminOrigin,
+        /// Facility Admins cannot add themselves as staff
+        CannotAssignSelf,
+        /// Account is already staff at this facility
+        StaffAlreadyAssigned,
+        /// Account is not staff at this facility
+        StaffNotFound,
+        /// Patient's identity is inactive, deceased or merged
+        PatientNotActive,
+    }
+
+    // ========================================================================
+    // HOOKS
+    // ========================================================================
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        /// Remove grants that expired at the previous block, mark reviews
+        /// whose justification deadline passed at it as unjustified, and warn
+        /// providers whose credential lapses within `CredentialWarningPeriod`
+        ///
+        /// Weight is bounded by `MaxExpiriesPerBlock` per queue (Rule 2).
+        fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+            let expired_at = now.saturating_sub(One::one());
+            let expired = ExpiryQueue::<T>::take(expired_at);
+            let count = expired.len() as u32;
+
+            for (patient, accessor) in expired {
+                Self::expire_access(&patient, &accessor, now);
+            }
+
+            let overdue = ReviewDeadlines::<T>::take(expired_at);
+            let overdue_count = overdue.len() as u32;
+
+            for review_id in overdue {
+                Self::mark_unjustified(review_id);
+            }
+
+            let warned = CredentialWarnings::<T>::take(now);
+            let warned_count = warned.len() as u32;
+
+            for provider in warned {
+                Self::warn_credential_expiry(&provider, now);
+            }
+
+            T::WeightInfo::on_initialize_expired(count)
+                .saturating_add(T::WeightInfo::on_initialize_unjustified(overdue_count))
+                .saturating_add(T::WeightInfo::on_initialize_credential_warnings(
+                    warned_count,
+                ))
+        }
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
         // ====================================================================
         // ROLE MANAGEMENT EXTRINSICS
         // ====================================================================
 
         /// Assign a role to an account
         ///
-        /// Only Admin can assign Doctor, Nurse, LabTechnician, Pharmacist roles.
-        /// Patient role is assigned when registering a patient.
+        /// Requires `AdminOrigin` (e.g. M-of-N ministry council approval).
+        /// This is how providers are promoted and new Admins are added.
+        /// Patient role is normally assigned when registering a patient.
+        /// Licensed roles need the provider's credential registered first.
         ///
         /// # Arguments
         /// * `account` - Account to assign role to
         /// * `role` - Role to assign
         ///
         /// # Errors
-        /// * `InsufficientRole` - Caller is not Admin
-        /// * `CannotAssignAdmin` - Admin role can only be set at genesis
+        /// * `BadOrigin` - Origin is not `AdminOrigin`
         /// * `RoleAlreadyAssigned` - Account already has a role
+        /// * `CredentialRequired` - Licensed role without an unexpired credential
         #[pallet::call_index(0)]
-        #[pallet::weight(Weight::from_parts(10_000, 0))]
+        #[pallet::weight(T::WeightInfo::assign_role())]
         pub fn assign_role(
             origin: OriginFor<T>,
             account: T::AccountId,
             role: Role,
         ) -> DispatchResult {
-            let who = ensure_signed(origin)?;
-
-            // Only Admin can assign roles
-            ensure!(Self::is_admin(&who), Error::<T>::InsufficientRole);
-
-            // Cannot assign Admin role via extrinsic (security)
-            ensure!(!matches!(role, Role::Admin), Error::<T>::CannotAssignAdmin);
+            T::AdminOrigin::ensure_origin(origin)?;
 
             // Check if account already has a role
             ensure!(
                 !UserRoles::<T>::contains_key(&account),
                 Error::<T>::RoleAlreadyAssigned
             );
+            ensure!(
+                !role.requires_licence() || Self::has_valid_credential(&account),
+                Error::<T>::CredentialRequired
+            );
 
             UserRoles::<T>::insert(&account, role);
 
-            Self::deposit_event(Event::RoleAssigned {
-                account,
-                role,
-                assigned_by: who,
-            });
+            Self::deposit_event(Event::RoleAssigned { account, role });
 
             Ok(())
         }
 
         /// Revoke a role from an account
         ///
-        /// Only Admin can revoke roles. Cannot revoke own role.
+        /// Requires `AdminOrigin` (e.g. M-of-N ministry council approval),
+        /// so a single Admin can neither remove colleagues nor themselves.
         ///
         /// # Arguments
         /// * `account` - Account to revoke role from
         ///
         /// # Errors
-        /// * `InsufficientRole` - Caller is not Admin
+        /// * `BadOrigin` - Origin is not `AdminOrigin`
         /// * `NoRoleToRevoke` - Account has no role
-        /// * `CannotRevokeOwnRole` - Cannot revoke your own role
         #[pallet::call_index(1)]
-        #[pallet::weight(Weight::from_parts(10_000, 0))]
+        #[pallet::weight(T::WeightInfo::revoke_role())]
         pub fn revoke_role(origin: OriginFor<T>, account: T::AccountId) -> DispatchResult {
-            let who = ensure_signed(origin)?;
-
-            // Only Admin can revoke roles
-            ensure!(Self::is_admin(&who), Error::<T>::InsufficientRole);
-
-            // Cannot revoke own role
-            ensure!(who != account, Error::<T>::CannotRevokeOwnRole);
+            T::AdminOrigin::ensure_origin(origin)?;
 
-            // Check account has a role
-            ensure!(
-                UserRoles::<T>::contains_key(&account),
-                Error::<T>::NoRoleToRevoke
-            );
+            let role = UserRoles::<T>::take(&account).ok_or(Error::<T>::NoRoleToRevoke)?;
 
-            let role = UserRoles::<T>::get(&account).unwrap_or_default();
-            UserRoles::<T>::remove(&account);
-
-            Self::deposit_event(Event::RoleRevoked {
-                account,
-                role,
-                revoked_by: who,
-            });
+            Self::deposit_event(Event::RoleRevoked { account, role });
 
             Ok(())
         }
@@ -305,7 +1439,9 @@ pub mod pallet {
         /// Grant emergency access to a patient's records
         ///
         /// In emergencies, healthcare providers can self-grant access.
-        /// Access is time-limited and logged immutably.
+        /// Access is time-limited (duration depends on the caller's role)
+        /// and logged immutably. Each grant opens a break-glass review the
+        /// accessor must justify within `JustificationPeriod` blocks.
         ///
         /// # Arguments
         /// * `patient` - Patient whose records to access
@@ -313,10 +1449,16 @@ pub mod pallet {
         ///
         /// # Errors
         /// * `NotHealthcareProvider` - Caller is not a healthcare provider
+        /// * `PatientNotActive` - Patient's identity is inactive, deceased or merged
+        /// * `ConsentWithheld` - Patient does not share the emergency subset with any provider
         /// * `AccessAlreadyGranted` - Accessor already has active access
         /// * `TooManyAccesses` - Patient has maximum active accesses
+        /// * `ReviewIdOverflow` - No break-glass review id is left for the grant
         #[pallet::call_index(2)]
-        #[pallet::weight(Weight::from_parts(10_000, 0))]
+        #[pallet::weight(
+            T::WeightInfo::grant_emergency_access()
+                .saturating_add(Pallet::<T>::queue_spill_weight().saturating_mul(2))
+        )]
         pub fn grant_emergency_access(
             origin: OriginFor<T>,
             patient: T::AccountId,
@@ -326,9 +1468,21 @@ pub mod pallet {
 
             // Must be a healthcare provider to use emergency access
             ensure!(
-                Self::is_healthcare_provider(&accessor),
+                Self::has_permission(&accessor, &patient, Permission::EmergencyAccess),
                 Error::<T>::NotHealthcareProvider
             );
+            ensure!(
+                Self::is_active_patient(&patient),
+                Error::<T>::PatientNotActive
+            );
+
+            // Emergency accessors are not treating providers
+            ensure!(
+                Self::consent_directives(&patient)
+                    .disclosure(ConsentViewer::EmergencyProvider)
+                    .full_records,
+                Error::<T>::ConsentWithheld
+            );
 
             // Check if access already exists
             ensure!(
@@ -339,24 +1493,35 @@ pub mod pallet {
             // Check access count limit (Rule 2: bounded)
             let current_count = AccessCount::<T>::get(&patient);
             ensure!(
-                current_count < MAX_ACTIVE_ACCESSES,
+                current_count < T::MaxActiveAccesses::get(),
                 Error::<T>::TooManyAccesses
             );
 
             let current_block = <frame_system::Pallet<T>>::block_number();
-            let expires_at = current_block.saturating_add(DEFAULT_ACCESS_DURATION.into());
+            let expires_at =
+                current_block.saturating_add(Self::emergency_access_duration(&accessor));
+
+            // Schedule automatic cleanup once the grant expires
+            Self::schedule_expiry(expires_at, &patient, &accessor);
 
             let access_log = AccessLog {
                 accessor: accessor.clone(),
                 access_type: AccessType::Emergency,
                 granted_at: current_block,
-                expires_at,
+                expires_at: Some(expires_at),
                 reason_hash,
                 revoked: false,
             };
 
             ActiveAccess::<T>::insert(&patient, &accessor, access_log);
             AccessCount::<T>::mutate(&patient, |count| *count = count.saturating_add(1));
+            Self::log_audit(
+                &patient,
+                &accessor,
+                AuditAction::AccessGranted(AccessType::Emergency),
+                reason_hash,
+            );
+            Self::open_review(&patient, &accessor, reason_hash, current_block)?;
 
             Self::deposit_event(Event::EmergencyAccessGranted {
                 patient,
@@ -367,17 +1532,18 @@ pub mod pallet {
             Ok(())
         }
 
-        /// Revoke access (patient or accessor can revoke)
+        /// Revoke access (patient, accessor, or a guardian with `RevokeAccess` can revoke)
         ///
         /// # Arguments
         /// * `patient` - Patient account
         /// * `accessor` - Accessor to revoke
         ///
         /// # Errors
+        /// * `NotAuthorized` - Caller is neither party nor a guardian allowed to revoke
         /// * `AccessNotFound` - No active access found
         /// * `AlreadyRevoked` - Access already revoked
         #[pallet::call_index(3)]
-        #[pallet::weight(Weight::from_parts(10_000, 0))]
+        #[pallet::weight(T::WeightInfo::revoke_access())]
         pub fn revoke_access(
             origin: OriginFor<T>,
             patient: T::AccountId,
@@ -385,8 +1551,13 @@ pub mod pallet {
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
 
-            // Only patient or accessor can revoke
-            ensure!(who == patient || who == accessor, Error::<T>::NotAuthorized);
+            // Only patient, accessor or a guardian acting for the patient can revoke
+            ensure!(
+                who == patient
+                    || who == accessor
+                    || Self::is_guardian(&patient, &who, GuardianScope::RevokeAccess),
+                Error::<T>::NotAuthorized
+            );
 
             ActiveAccess::<T>::try_mutate(&patient, &accessor, |maybe_access| -> DispatchResult {
                 let access = maybe_access.as_mut().ok_or(Error::<T>::AccessNotFound)?;
@@ -394,6 +1565,12 @@ pub mod pallet {
                 ensure!(!access.revoked, Error::<T>::AlreadyRevoked);
 
                 access.revoked = true;
+                Self::log_audit(
+                    &patient,
+                    &accessor,
+                    AuditAction::AccessRevoked,
+                    access.reason_hash,
+                );
 
                 Self::deposit_event(Event::AccessRevoked {
                     patient: patient.clone(),
@@ -411,8 +1588,9 @@ pub mod pallet {
 
         /// Clean up expired access entries
         ///
-        /// Can be called by anyone to remove expired access records.
-        /// Helps keep storage clean and reduces costs.
+        /// Can be called by anyone to remove expired or revoked access records.
+        /// Expired grants are also removed automatically in `on_initialize`,
+        /// so this is mainly useful for revoked grants that have not expired yet.
         ///
         /// # Arguments
         /// * `patient` - Patient account
@@ -422,7 +1600,7 @@ pub mod pallet {
         /// * `AccessNotFound` - No access record found
         /// * `AccessExpired` - Access hasn't expired yet (cannot clean)
         #[pallet::call_index(4)]
-        #[pallet::weight(Weight::from_parts(10_000, 0))]
+        #[pallet::weight(T::WeightInfo::cleanup_expired_access())]
         pub fn cleanup_expired_access(
             origin: OriginFor<T>,
             patient: T::AccountId,
@@ -437,76 +1615,1456 @@ pub mod pallet {
 
             // Only clean if expired or revoked
             ensure!(
-                access.revoked || current_block > access.expires_at,
+                access.revoked || access.is_expired(current_block),
                 Error::<T>::AccessNotFound
             );
 
             ActiveAccess::<T>::remove(&patient, &accessor);
-            AccessCount::<T>::mutate(&patient, |count| *count = count.saturating_sub(1));
+            // Revoked grants were already uncounted and logged by `revoke_access`
+            if !access.revoked {
+                AccessCount::<T>::mutate(&patient, |count| *count = count.saturating_sub(1));
+                Self::log_audit(
+                    &patient,
+                    &accessor,
+                    AuditAction::AccessExpired,
+                    access.reason_hash,
+                );
+            }
 
             Self::deposit_event(Event::ExpiredAccessCleaned { patient, accessor });
 
             Ok(())
         }
-    }
 
-    // ========================================================================
-    // HELPER FUNCTIONS
-    // ========================================================================
+        /// Grant Regular or Full access to a healthcare provider (patient only)
+        ///
+        /// Lets a patient onboard their primary care provider without going
+        /// through emergency access. The grant can be revoked at any time
+        /// with `revoke_access`.
+        ///
+        /// # Arguments
+        /// * `accessor` - Healthcare provider to grant access to
+        /// * `access_type` - `Regular` or `Full`
+        /// * `duration` - Number of blocks the grant lasts (`None` = until revoked)
+        ///
+        /// # Errors
+        /// * `NotAuthorized` - Caller is not a patient, or grants to themselves
+        /// * `InvalidAccessType` - `Emergency` cannot be granted by a patient
+        /// * `InvalidDuration` - Duration of zero blocks
+        /// * `NotHealthcareProvider` - Accessor is not a healthcare provider
+        /// * `PatientNotActive` - Patient's identity is inactive, deceased or merged
+        /// * `AccessAlreadyGranted` - Accessor already has an access entry
+        /// * `TooManyAccesses` - Patient has maximum active accesses
+        #[pallet::call_index(5)]
+        #[pallet::weight(
+            T::WeightInfo::grant_access().saturating_add(Pallet::<T>::queue_spill_weight())
+        )]
+        pub fn grant_access(
+            origin: OriginFor<T>,
+            accessor: T::AccountId,
+            access_type: AccessType,
+            duration: Option<BlockNumberFor<T>>,
+        ) -> DispatchResult {
+            let patient = ensure_signed(origin)?;
 
-    impl<T: Config> Pallet<T> {
-        /// Check if account is an Admin
-        pub fn is_admin(account: &T::AccountId) -> bool {
-            matches!(UserRoles::<T>::get(account), Some(Role::Admin))
-        }
+            // Rule 6: Check preconditions early
+            ensure!(Self::is_patient(&patient), Error::<T>::NotAuthorized);
 
-        /// Check if account is a Doctor
-        pub fn is_doctor(account: &T::AccountId) -> bool {
-            matches!(UserRoles::<T>::get(account), Some(Role::Doctor))
+            Self::do_grant_access(patient, accessor, access_type, duration)
         }
 
-        /// Check if account is a Nurse
-        pub fn is_nurse(account: &T::AccountId) -> bool {
-            matches!(UserRoles::<T>::get(account), Some(Role::Nurse))
-        }
+        // ====================================================================
+        // GUARDIAN EXTRINSICS
+        // ====================================================================
 
-        /// Check if account is a healthcare provider (Doctor, Nurse, Admin)
-        /// These are the only roles that can register patients and edit medical records
-        pub fn is_healthcare_provider(account: &T::AccountId) -> bool {
-            matches!(
-                UserRoles::<T>::get(account),
-                Some(Role::Admin) | Some(Role::Doctor) | Some(Role::Nurse)
-            )
+        /// Appoint a guardian to act on a patient's behalf
+        ///
+        /// Patients appoint their own proxies and next of kin. Parents of
+        /// minors and guardians of patients who cannot sign for themselves
+        /// are appointed by providers through `AdminOrigin` (e.g. M-of-N
+        /// ministry council approval), never by a single signed provider.
+        ///
+        /// # Arguments
+        /// * `patient` - Patient the guardian acts for
+        /// * `guardian` - Guardian account (must not hold a provider role)
+        /// * `relationship` - How the guardian is related to the patient
+        /// * `scopes` - What the guardian may do on the patient's behalf
+        /// * `duration` - Number of blocks the appointment lasts (`None` = until removed)
+        ///
+        /// # Errors
+        /// * `BadOrigin` - Origin is neither `AdminOrigin` nor signed
+        /// * `NotAuthorized` - Signed caller is not the patient, or the account is not a patient
+        /// * `CannotAppointSelf` - Guardian is the patient
+        /// * `GuardianIsProvider` - Guardian holds a provider role
+        /// * `NoGuardianScopes` - No scope delegated
+        /// * `InvalidDuration` - Duration of zero blocks
+        /// * `GuardianAlreadyAppointed` - Guardian already appointed for this patient
+        /// * `TooManyGuardians` - Patient has the maximum number of guardians
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::appoint_guardian())]
+        pub fn appoint_guardian(
+            origin: OriginFor<T>,
+            patient: T::AccountId,
+            guardian: T::AccountId,
+            relationship: GuardianRelationship,
+            scopes: GuardianScopes,
+            duration: Option<BlockNumberFor<T>>,
+        ) -> DispatchResult {
+            let appointed_by = Self::ensure_admin_or_patient(origin, &patient)?;
+
+            // Rule 6: Check preconditions early
+            ensure!(Self::is_patient(&patient), Error::<T>::NotAuthorized);
+            ensure!(patient != guardian, Error::<T>::CannotAppointSelf);
+            // Providers named as guardians would read past consent directives
+            ensure!(
+                !Self::holds_provider_role(&guardian),
+                Error::<T>::GuardianIsProvider
+            );
+            ensure!(!scopes.is_empty(), Error::<T>::NoGuardianScopes);
+            ensure!(
+                duration.is_none_or(|blocks| !blocks.is_zero()),
+                Error::<T>::InvalidDuration
+            );
+            ensure!(
+                !Guardians::<T>::contains_key(&patient, &guardian),
+                Error::<T>::GuardianAlreadyAppointed
+            );
+            ensure!(
+                GuardianCount::<T>::get(&patient) < T::MaxGuardians::get(),
+                Error::<T>::TooManyGuardians
+            );
+
+            let current_block = <frame_system::Pallet<T>>::block_number();
+            let expires_at = duration.map(|blocks| current_block.saturating_add(blocks));
+
+            let guardianship = Guardianship {
+                relationship,
+                scopes,
+                appointed_by: appointed_by.clone(),
+                appointed_at: current_block,
+                expires_at,
+            };
+
+            Guardians::<T>::insert(&patient, &guardian, guardianship);
+            GuardianCount::<T>::mutate(&patient, |count| *count = count.saturating_add(1));
+            Self::log_audit(
+                &patient,
+                &guardian,
+                AuditAction::GuardianAppointed,
+                [0u8; 32],
+            );
+
+            Self::deposit_event(Event::GuardianAppointed {
+                patient,
+                guardian,
+                relationship,
+                scopes,
+                appointed_by,
+                expires_at,
+            });
+
+            Ok(())
         }
 
-        /// Check if account can register patients (Doctor, Nurse, Admin)
-        pub fn can_register_patients(account: &T::AccountId) -> bool {
-            Self::is_healthcare_provider(account)
+        /// Remove a guardian
+        ///
+        /// Only the patient, or providers through `AdminOrigin` (the path
+        /// that appoints guardians for patients who cannot sign), can remove
+        /// an appointment. The removal is recorded in the patient's audit log.
+        ///
+        /// # Arguments
+        /// * `patient` - Patient the guardian acts for
+        /// * `guardian` - Guardian to remove
+        ///
+        /// # Errors
+        /// * `BadOrigin` - Origin is neither `AdminOrigin` nor signed
+        /// * `NotAuthorized` - Signed caller is not the patient
+        /// * `GuardianNotFound` - Guardian not appointed for this patient
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::remove_guardian())]
+        pub fn remove_guardian(
+            origin: OriginFor<T>,
+            patient: T::AccountId,
+            guardian: T::AccountId,
+        ) -> DispatchResult {
+            let removed_by = Self::ensure_admin_or_patient(origin, &patient)?;
+
+            ensure!(
+                Guardians::<T>::contains_key(&patient, &guardian),
+                Error::<T>::GuardianNotFound
+            );
+
+            Guardians::<T>::remove(&patient, &guardian);
+            GuardianCount::<T>::mutate(&patient, |count| *count = count.saturating_sub(1));
+            Self::log_audit(&patient, &guardian, AuditAction::GuardianRemoved, [0u8; 32]);
+
+            Self::deposit_event(Event::GuardianRemoved {
+                patient,
+                guardian,
+                removed_by,
+            });
+
+            Ok(())
         }
 
-        /// Check if account can edit medical records (Doctor, Nurse, Admin)
-        pub fn can_edit_medical_records(account: &T::AccountId) -> bool {
-            Self::is_healthcare_provider(account)
+        /// Grant Regular or Full access to a provider on the patient's behalf
+        ///
+        /// Same as `grant_access`, signed by a guardian holding the
+        /// `GrantAccess` scope instead of the patient.
+        ///
+        /// # Arguments
+        /// * `patient` - Patient the guardian acts for
+        /// * `accessor` - Healthcare provider to grant access to
+        /// * `access_type` - `Regular` or `Full`
+        /// * `duration` - Number of blocks the grant lasts (`None` = until revoked)
+        ///
+        /// # Errors
+        /// * `GuardianNotFound` - Caller is not a guardian of the patient
+        /// * `GuardianshipExpired` - Appointment has expired
+        /// * `GuardianScopeMissing` - Appointment lacks the `GrantAccess` scope
+        /// * `NotAuthorized` - Guardian grants to themselves or to the patient
+        /// * Any `grant_access` error for the grant itself
+        #[pallet::call_index(8)]
+        #[pallet::weight(
+            T::WeightInfo::grant_access_as_guardian().saturating_add(Pallet::<T>::queue_spill_weight())
+        )]
+        pub fn grant_access_as_guardian(
+            origin: OriginFor<T>,
+            patient: T::AccountId,
+            accessor: T::AccountId,
+            access_type: AccessType,
+            duration: Option<BlockNumberFor<T>>,
+        ) -> DispatchResult {
+            let guardian = ensure_signed(origin)?;
+
+            Self::ensure_guardian(&patient, &guardian, GuardianScope::GrantAccess)?;
+            ensure!(accessor != guardian, Error::<T>::NotAuthorized);
+
+            Self::do_grant_access(patient.clone(), accessor.clone(), access_type, duration)?;
+
+            Self::deposit_event(Event::AccessGrantedByGuardian {
+                patient,
+                guardian,
+                accessor,
+            });
+
+            Ok(())
         }
 
-        /// Check if account is a Patient
-        pub fn is_patient(account: &T::AccountId) -> bool {
-            matches!(UserRoles::<T>::get(account), Some(Role::Patient))
+        // ====================================================================
+        // CONSENT EXTRINSICS
+        // ====================================================================
+
+        /// Set a patient's consent directives
+        ///
+        /// Replaces the directives in force; they are evaluated on emergency
+        /// access, record reads and in the API's emergency views and downloads.
+        ///
+        /// # Arguments
+        /// * `patient` - Patient the directives apply to
+        /// * `directives` - New directives
+        ///
+        /// # Errors
+        /// * `NotAuthorized` - Caller is neither the patient nor a guardian
+        ///   with the `GrantAccess` scope, or the account is not a patient
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::WeightInfo::set_consent_directives())]
+        pub fn set_consent_directives(
+            origin: OriginFor<T>,
+            patient: T::AccountId,
+            directives: ConsentDirectives,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(
+                who == patient || Self::is_guardian(&patient, &who, GuardianScope::GrantAccess),
+                Error::<T>::NotAuthorized
+            );
+            ensure!(Self::is_patient(&patient), Error::<T>::NotAuthorized);
+
+            let record = ConsentRecord {
+                directives,
+                updated_by: who.clone(),
+                updated_at: <frame_system::Pallet<T>>::block_number(),
+            };
+
+            Consents::<T>::insert(&patient, record);
+            Self::log_audit(&patient, &who, AuditAction::ConsentUpdated, [0u8; 32]);
+
+            Self::deposit_event(Event::ConsentDirectivesUpdated {
+                patient,
+                directives,
+                updated_by: who,
+            });
+
+            Ok(())
         }
 
-        /// Get the role of an account
-        pub fn get_role(account: &T::AccountId) -> Option<Role> {
-            UserRoles::<T>::get(account)
+        // ====================================================================
+        // BREAK-GLASS REVIEW EXTRINSICS
+        // ====================================================================
+
+        /// Justify an emergency grant after the fact
+        ///
+        /// Only the accessor can justify, and only until the review's
+        /// `justify_by` block; the review then waits for an Admin or
+        /// Privacy Officer.
+        ///
+        /// # Arguments
+        /// * `review_id` - Review opened by the emergency grant
+        /// * `justification_hash` - Hash of the encrypted justification
+        ///
+        /// # Errors
+        /// * `ReviewNotFound` - No review with this id
+        /// * `NotAuthorized` - Caller is not the emergency accessor
+        /// * `NotAwaitingJustification` - Review was already justified or closed
+        /// * `JustificationDeadlinePassed` - `justify_by` has passed
+        #[pallet::call_index(10)]
+        #[pallet::weight(T::WeightInfo::submit_justification())]
+        pub fn submit_justification(
+            origin: OriginFor<T>,
+            review_id: u32,
+            justification_hash: [u8; 32],
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let patient = BreakGlassReviews::<T>::try_mutate(
+                review_id,
+                |maybe_review| -> Result<T::AccountId, DispatchError> {
+                    let review = maybe_review.as_mut().ok_or(Error::<T>::ReviewNotFound)?;
+
+                    ensure!(review.accessor == who, Error::<T>::NotAuthorized);
+                    ensure!(
+                        review.status == ReviewStatus::AwaitingJustification,
+                        Error::<T>::NotAwaitingJustification
+                    );
+                    let current_block = <frame_system::Pallet<T>>::block_number();
+                    ensure!(
+                        current_block <= review.justify_by,
+                        Error::<T>::JustificationDeadlinePassed
+                    );
+
+                    review.justification_hash = Some(justification_hash);
+                    review.status = ReviewStatus::Justified;
+                    Ok(review.patient.clone())
+                },
+            )?;
+
+            Self::log_audit(
+                &patient,
+                &who,
+                AuditAction::AccessJustified,
+                justification_hash,
+            );
+
+            Self::deposit_event(Event::EmergencyAccessJustified {
+                review_id,
+                accessor: who,
+            });
+
+            Ok(())
         }
 
-        /// Check if accessor has valid (non-expired, non-revoked) access
-        pub fn has_valid_access(patient: &T::AccountId, accessor: &T::AccountId) -> bool {
-            if let Some(access) = ActiveAccess::<T>::get(patient, accessor) {
-                let current_block = <frame_system::Pallet<T>>::block_number();
-                !access.revoked && current_block <= access.expires_at
-            } else {
-                false
-            }
+        /// Approve or flag an emergency grant
+        ///
+        /// Admins and Privacy Officers review justified grants, and may also
+        /// revisit unjustified ones. Flagging a justified grant counts against
+        /// the accessor's misuse metric; approving an unjustified one (e.g. the
+        /// accessor justified it off-chain) takes the count back. Either verdict
+        /// closes the review: it is removed from `BreakGlassReviews`, and the
+        /// patient's audit log keeps the verdict (`AccessReviewed`).
+        ///
+        /// # Arguments
+        /// * `review_id` - Review opened by the emergency grant
+        /// * `verdict` - `Approve` or `Flag`
+        ///
+        /// # Errors
+        /// * `ReviewNotFound` - No open review with this id
+        /// * `InsufficientRole` - Caller lacks `ReviewEmergencyAccess` for the patient
+        /// * `CannotReviewOwnAccess` - Caller is the emergency accessor
+        /// * `ReviewNotReady` - Accessor can still justify the grant
+        #[pallet::call_index(11)]
+        #[pallet::weight(T::WeightInfo::review_emergency_access())]
+        pub fn review_emergency_access(
+            origin: OriginFor<T>,
+            review_id: u32,
+            verdict: ReviewVerdict,
+        ) -> DispatchResult {
+            let reviewer = ensure_signed(origin)?;
+
+            let review =
+                BreakGlassReviews::<T>::get(review_id).ok_or(Error::<T>::ReviewNotFound)?;
+            ensure!(
+                Self::has_permission(
+                    &reviewer,
+                    &review.patient,
+                    Permission::ReviewEmergencyAccess
+                ),
+                Error::<T>::InsufficientRole
+            );
+            ensure!(
+                review.accessor != reviewer,
+                Error::<T>::CannotReviewOwnAccess
+            );
+            ensure!(
+                review.status != ReviewStatus::AwaitingJustification,
+                Error::<T>::ReviewNotReady
+            );
+
+            BreakGlassReviews::<T>::remove(review_id);
+
+            // Unjustified grants were already counted when their deadline passed
+            let misuse_count = match (verdict, review.status) {
+                (ReviewVerdict::Flag, ReviewStatus::Justified) => {
+                    MisuseCount::<T>::mutate(&review.accessor, |count| {
+                        *count = count.saturating_add(1);
+                        *count
+                    })
+                }
+                (ReviewVerdict::Approve, ReviewStatus::Unjustified) => {
+                    MisuseCount::<T>::mutate(&review.accessor, |count| {
+                        *count = count.saturating_sub(1);
+                        *count
+                    })
+                }
+                _ => MisuseCount::<T>::get(&review.accessor),
+            };
+
+            Self::log_audit(
+                &review.patient,
+                &reviewer,
+                AuditAction::AccessReviewed(verdict),
+                review.reason_hash,
+            );
+
+            match verdict {
+                ReviewVerdict::Approve => Self::deposit_event(Event::EmergencyAccessApproved {
+                    review_id,
+                    reviewer,
+                }),
+                ReviewVerdict::Flag => Self::deposit_event(Event::EmergencyAccessFlagged {
+                    review_id,
+                    patient: review.patient,
+                    accessor: review.accessor,
+                    reviewer,
+                    misuse_count,
+                }),
+            }
+
+            Ok(())
+        }
+
+        // ====================================================================
+        // CREDENTIAL EXTRINSICS
+        // ====================================================================
+
+        /// Record or renew a provider's professional credential
+        ///
+        /// Requires `AdminOrigin` (e.g. M-of-N ministry council approval).
+        /// Records the licence behind a Doctor, Nurse, LabTechnician or
+        /// Pharmacist role; without one (or once `valid_until` passes) the
+        /// role grants no permissions until the credential is renewed.
+        /// `CredentialExpiring` is raised `CredentialWarningPeriod` blocks before,
+        /// or straight away if the warning cannot be queued.
+        ///
+        /// # Arguments
+        /// * `provider` - Account the credential belongs to
+        /// * `licence_id_hash` - Hash of the licence number
+        /// * `issuing_body` - Council that issued the licence
+        /// * `valid_until` - Last block the licence is valid
+        /// * `facility_id` - Facility the provider practises at
+        ///
+        /// # Errors
+        /// * `BadOrigin` - Origin is not `AdminOrigin`
+        /// * `CredentialAlreadyExpired` - `valid_until` is not in the future
+        /// * `FacilityNotFound` - No facility with this id
+        #[pallet::call_index(12)]
+        #[pallet::weight(
+            T::WeightInfo::register_credential()
+                .saturating_add(Pallet::<T>::queue_spill_weight().saturating_mul(2))
+        )]
+        pub fn register_credential(
+            origin: OriginFor<T>,
+            provider: T::AccountId,
+            licence_id_hash: [u8; 32],
+            issuing_body: IssuingBody,
+            valid_until: BlockNumberFor<T>,
+            facility_id: FacilityId,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            let current_block = <frame_system::Pallet<T>>::block_number();
+            ensure!(
+                valid_until > current_block,
+                Error::<T>::CredentialAlreadyExpired
+            );
+            ensure!(
+                Facilities::<T>::contains_key(facility_id),
+                Error::<T>::FacilityNotFound
+            );
+
+            // A renewal replaces the old credential's pending warning
+            if let Some(old) = Credentials::<T>::get(&provider) {
+                let old_warn_at = old
+                    .valid_until
+                    .saturating_sub(T::CredentialWarningPeriod::get());
+                Self::unschedule_credential_warning(old_warn_at, &provider);
+            }
+
+            // Queue the expiry warning, or warn straight away if already within the period
+            let warn_at = valid_until.saturating_sub(T::CredentialWarningPeriod::get());
+            let queued =
+                warn_at > current_block && Self::schedule_credential_warning(warn_at, &provider);
+
+            let credential = Credential {
+                licence_id_hash,
+                issuing_body,
+                valid_until,
+                facility_id,
+                registered_at: current_block,
+            };
+            Credentials::<T>::insert(&provider, credential);
+
+            Self::deposit_event(Event::CredentialRegistered {
+                provider: provider.clone(),
+                issuing_body,
+                valid_until,
+                facility_id,
+            });
+            if !queued {
+                Self::deposit_event(Event::CredentialExpiring {
+                    provider,
+                    valid_until,
+                });
+            }
+
+            Ok(())
+        }
+
+        // ====================================================================
+        // FACILITY EXTRINSICS
+        // ====================================================================
+
+        /// Register a facility and name its Admin
+        ///
+        /// Requires `AdminOrigin` (e.g. M-of-N ministry council approval).
+        ///
+        /// # Arguments
+        /// * `kind` - Hospital, clinic, laboratory, pharmacy or ambulance service
+        /// * `name` - Facility name
+        /// * `admin` - Facility Admin, who manages its staff
+        ///
+        /// # Errors
+        /// * `BadOrigin` - Origin is not `AdminOrigin`
+        /// * `FacilityNameTooLong` - Name exceeds `MaxFacilityNameLength`
+        /// * `FacilityIdOverflow` - No facility id is left
+        #[pallet::call_index(13)]
+        #[pallet::weight(T::WeightInfo::register_facility())]
+        pub fn register_facility(
+            origin: OriginFor<T>,
+            kind: FacilityKind,
+            name: Vec<u8>,
+            admin: T::AccountId,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            let name: BoundedVec<u8, T::MaxFacilityNameLength> = name
+                .try_into()
+                .map_err(|_| Error::<T>::FacilityNameTooLong)?;
+
+            let facility_id = NextFacilityId::<T>::get();
+            let next_facility_id = facility_id
+                .checked_add(1)
+                .ok_or(Error::<T>::FacilityIdOverflow)?;
+            let facility = Facility {
+                kind,
+                name,
+                admin: admin.clone(),
+                registered_at: <frame_system::Pallet<T>>::block_number(),
+            };
+
+            Facilities::<T>::insert(facility_id, facility);
+            NextFacilityId::<T>::put(next_facility_id);
+
+            Self::deposit_event(Event::FacilityRegistered {
+                facility_id,
+                kind,
+                admin,
+            });
+
+            Ok(())
+        }
+
+        /// Replace a facility's Admin
+        ///
+        /// Requires `AdminOrigin` (e.g. M-of-N ministry council approval).
+        ///
+        /// # Arguments
+        /// * `facility_id` - Facility to change
+        /// * `admin` - New facility Admin
+        ///
+        /// # Errors
+        /// * `BadOrigin` - Origin is not `AdminOrigin`
+        /// * `FacilityNotFound` - No facility with this id
+        #[pallet::call_index(14)]
+        #[pallet::weight(T::WeightInfo::set_facility_admin())]
+        pub fn set_facility_admin(
+            origin: OriginFor<T>,
+            facility_id: FacilityId,
+            admin: T::AccountId,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            Facilities::<T>::try_mutate(facility_id, |maybe_facility| -> DispatchResult {
+                let facility = maybe_facility
+                    .as_mut()
+                    .ok_or(Error::<T>::FacilityNotFound)?;
+                facility.admin = admin.clone();
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::FacilityAdminChanged { facility_id, admin });
+
+            Ok(())
+        }
+
+        /// Give an account a staff role at a facility
+        ///
+        /// The account acts in this role while the facility is its active
+        /// facility, for the facility's patients; the first facility it joins
+        /// becomes active. Licensed roles need an unexpired credential, which
+        /// only `AdminOrigin` records; the facility's Admin (or a ministry
+        /// Admin) may then add the account, but never themselves. Privacy
+        /// Officers need `AdminOrigin` itself.
+        ///
+        /// # Arguments
+        /// * `facility_id` - Facility hiring the account
+        /// * `account` - Staff member
+        /// * `role` - Role at this facility (must suit the facility kind)
+        ///
+        /// # Errors
+        /// * `FacilityNotFound` - No facility with this id
+        /// * `NotFacilityAdmin` - Caller is neither its Admin nor a ministry Admin
+        /// * `RoleRequiresAdminOrigin` - Privacy Officer role without `AdminOrigin`
+        /// * `CannotAssignSelf` - Caller is adding themselves
+        /// * `RoleNotAllowedAtFacility` - Facility kind does not hand out this role
+        /// * `CredentialRequired` - Licensed role without an unexpired credential
+        /// * `StaffAlreadyAssigned` - Account is already staff at this facility
+        #[pallet::call_index(15)]
+        #[pallet::weight(T::WeightInfo::add_facility_staff())]
+        pub fn add_facility_staff(
+            origin: OriginFor<T>,
+            facility_id: FacilityId,
+            account: T::AccountId,
+            role: Role,
+        ) -> DispatchResult {
+            let (facility, added_by) = match T::AdminOrigin::try_origin(origin) {
+                Ok(_) => {
+                    let facility =
+                        Facilities::<T>::get(facility_id).ok_or(Error::<T>::FacilityNotFound)?;
+                    (facility, None)
+                }
+                Err(origin) => {
+                    let who = ensure_signed(origin)?;
+                    let facility = Self::ensure_facility_admin(facility_id, &who)?;
+                    ensure!(
+                        !role.requires_admin_origin(),
+                        Error::<T>::RoleRequiresAdminOrigin
+                    );
+                    ensure!(account != who, Error::<T>::CannotAssignSelf);
+                    (facility, Some(who))
+                }
+            };
+            ensure!(
+                facility.kind.allows(role),
+                Error::<T>::RoleNotAllowedAtFacility
+            );
+            ensure!(
+                !role.requires_licence() || Self::has_valid_credential(&account),
+                Error::<T>::CredentialRequired
+            );
+            ensure!(
+                !FacilityStaff::<T>::contains_key(facility_id, &account),
+                Error::<T>::StaffAlreadyAssigned
+            );
+
+            FacilityStaff::<T>::insert(facility_id, &account, role);
+            if !ActiveFacility::<T>::contains_key(&account) {
+                ActiveFacility::<T>::insert(&account, facility_id);
+            }
+
+            Self::deposit_event(Event::FacilityStaffAdded {
+                facility_id,
+                account,
+                role,
+                added_by,
+            });
+
+            Ok(())
+        }
+
+        /// Remove an account from a facility's staff
+        ///
+        /// # Arguments
+        /// * `facility_id` - Facility the account works at
+        /// * `account` - Staff member to remove
+        ///
+        /// # Errors
+        /// * `FacilityNotFound` - No facility with this id
+        /// * `NotFacilityAdmin` - Caller is neither its Admin nor a ministry Admin
+        /// * `StaffNotFound` - Account is not staff at this facility
+        #[pallet::call_index(16)]
+        #[pallet::weight(T::WeightInfo::remove_facility_staff())]
+        pub fn remove_facility_staff(
+            origin: OriginFor<T>,
+            facility_id: FacilityId,
+            account: T::AccountId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            Self::ensure_facility_admin(facility_id, &who)?;
+            FacilityStaff::<T>::take(facility_id, &account).ok_or(Error::<T>::StaffNotFound)?;
+            if ActiveFacility::<T>::get(&account) == Some(facility_id) {
+                ActiveFacility::<T>::remove(&account);
+            }
+
+            Self::deposit_event(Event::FacilityStaffRemoved {
+                facility_id,
+                account,
+                removed_by: who,
+            });
+
+            Ok(())
+        }
+
+        /// Switch the facility the caller acts for
+        ///
+        /// # Arguments
+        /// * `facility_id` - Facility the caller is staff at
+        ///
+        /// # Errors
+        /// * `StaffNotFound` - Caller is not staff at this facility
+        #[pallet::call_index(17)]
+        #[pallet::weight(T::WeightInfo::set_active_facility())]
+        pub fn set_active_facility(
+            origin: OriginFor<T>,
+            facility_id: FacilityId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(
+                FacilityStaff::<T>::contains_key(facility_id, &who),
+                Error::<T>::StaffNotFound
+            );
+            ActiveFacility::<T>::insert(&who, facility_id);
+
+            Self::deposit_event(Event::ActiveFacilitySet {
+                account: who,
+                facility_id,
+            });
+
+            Ok(())
+        }
+
+        /// Mark a review unjustified once its justification deadline has passed
+        ///
+        /// Can be called by anyone. Deadlines are normally processed in
+        /// `on_initialize`, so this is mainly useful for reviews whose
+        /// deadline could not be queued (see `MaxQueueSpill`) or is queued
+        /// at a later block.
+        ///
+        /// # Arguments
+        /// * `review_id` - Review opened by the emergency grant
+        ///
+        /// # Errors
+        /// * `ReviewNotFound` - No review with this id
+        /// * `NotAwaitingJustification` - Review was already justified or closed
+        /// * `JustificationNotOverdue` - `justify_by` has not passed yet
+        #[pallet::call_index(18)]
+        #[pallet::weight(T::WeightInfo::mark_review_unjustified())]
+        pub fn mark_review_unjustified(origin: OriginFor<T>, review_id: u32) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            let review =
+                BreakGlassReviews::<T>::get(review_id).ok_or(Error::<T>::ReviewNotFound)?;
+            ensure!(
+                review.status == ReviewStatus::AwaitingJustification,
+                Error::<T>::NotAwaitingJustification
+            );
+            let current_block = <frame_system::Pallet<T>>::block_number();
+            ensure!(
+                current_block > review.justify_by,
+                Error::<T>::JustificationNotOverdue
+            );
+
+            Self::mark_unjustified(review_id);
+
+            Ok(())
+        }
+    }
+
+    // ========================================================================
+    // HELPER FUNCTIONS
+    // ========================================================================
+
+    impl<T: Config> Pallet<T> {
+        /// Check if account is an Admin
+        pub fn is_admin(account: &T::AccountId) -> bool {
+            matches!(UserRoles::<T>::get(account), Some(Role::Admin))
+        }
+
+        /// Check if account is a Doctor with a valid credential
+        pub fn is_doctor(account: &T::AccountId) -> bool {
+            matches!(Self::current_role(account), Some(Role::Doctor))
+        }
+
+        /// Check if account is a Nurse with a valid credential
+        pub fn is_nurse(account: &T::AccountId) -> bool {
+            matches!(Self::current_role(account), Some(Role::Nurse))
+        }
+
+        /// Check if account is a healthcare provider (role holds `ViewRecords`)
+        /// Only these roles can receive access grants from patients
+        pub fn is_healthcare_provider(account: &T::AccountId) -> bool {
+            Self::holds_permission(account, Permission::ViewRecords)
+        }
+
+        /// Check if account's role for `patient` holds `permission` in the permission matrix
+        pub fn has_permission(
+            account: &T::AccountId,
+            patient: &T::AccountId,
+            permission: Permission,
+        ) -> bool {
+            Self::active_role(account, patient).is_some_and(|role| role.has_permission(permission))
+        }
+
+        /// Check if account's current role holds `permission`, for some patient
+        fn holds_permission(account: &T::AccountId, permission: Permission) -> bool {
+            Self::current_role(account).is_some_and(|role| role.has_permission(permission))
+        }
+
+        /// Check if account can register patients (Doctor, Nurse, Admin)
+        pub fn can_register_patients(account: &T::AccountId) -> bool {
+            Self::holds_permission(account, Permission::RegisterPatient)
+        }
+
+        /// Check if account can edit medical records (Doctor, Nurse, Admin)
+        pub fn can_edit_medical_records(account: &T::AccountId) -> bool {
+            Self::holds_permission(account, Permission::EditRecord)
+        }
+
+        /// Check if account can verify a patient's blood type (Doctor, LabTechnician)
+        /// Blood type drives transfusion decisions, so Nurses and Admins cannot change it
+        pub fn can_verify_blood_type(account: &T::AccountId) -> bool {
+            Self::holds_permission(account, Permission::VerifyBloodType)
+        }
+
+        /// Check if account is a Patient
+        pub fn is_patient(account: &T::AccountId) -> bool {
+            matches!(UserRoles::<T>::get(account), Some(Role::Patient))
+        }
+
+        /// Whether the account holds, or is licensed for, any role other than Patient
+        ///
+        /// Covers global roles, an active facility role and recorded credentials,
+        /// even lapsed ones.
+        pub fn holds_provider_role(account: &T::AccountId) -> bool {
+            UserRoles::<T>::get(account).is_some_and(|role| role != Role::Patient)
+                || ActiveFacility::<T>::contains_key(account)
+                || Credentials::<T>::contains_key(account)
+        }
+
+        /// Account acting for `patient`: `None` through `AdminOrigin`, or the
+        /// patient themselves when signed
+        fn ensure_admin_or_patient(
+            origin: OriginFor<T>,
+            patient: &T::AccountId,
+        ) -> Result<Option<T::AccountId>, DispatchError> {
+            match T::AdminOrigin::try_origin(origin) {
+                Ok(_) => Ok(None),
+                Err(origin) => {
+                    let who = ensure_signed(origin)?;
+                    ensure!(&who == patient, Error::<T>::NotAuthorized);
+                    Ok(Some(who))
+                }
+            }
+        }
+
+        /// Get the role assigned to an account, even if its credential has lapsed
+        pub fn get_role(account: &T::AccountId) -> Option<Role> {
+            UserRoles::<T>::get(account)
+        }
+
+        /// Role an account currently holds, whichever patient it acts for:
+        /// a global role other than Patient, else its role at its active
+        /// facility, else a global Patient role
+        ///
+        /// `None` if the role is licensed and the account's credential has
+        /// lapsed. Checks on a patient go through `active_role`.
+        pub fn current_role(account: &T::AccountId) -> Option<Role> {
+            Self::resolve_role(account, |_| true)
+        }
+
+        /// Role an account may act in for `patient`
+        ///
+        /// As `current_role`, but a facility role applies only to the
+        /// facility's patients (see `Config::PatientFacilities`) and to
+        /// patients who granted the account access.
+        pub fn active_role(account: &T::AccountId, patient: &T::AccountId) -> Option<Role> {
+            Self::resolve_role(account, |facility_id| {
+                <T::PatientFacilities as crate::PatientFacilities<T::AccountId>>::is_treated_at(
+                    patient,
+                    facility_id,
+                ) || Self::has_valid_access(patient, account)
+            })
+        }
+
+        /// Resolve the account's role, taking its active facility role only
+        /// if `in_context` accepts the facility
+        fn resolve_role(
+            account: &T::AccountId,
+            in_context: impl FnOnce(FacilityId) -> bool,
+        ) -> Option<Role> {
+            let global = UserRoles::<T>::get(account);
+            let role = match global {
+                Some(role) if role != Role::Patient => Some(role),
+                _ => ActiveFacility::<T>::get(account)
+                    .filter(|facility_id| in_context(*facility_id))
+                    .and_then(|facility_id| FacilityStaff::<T>::get(facility_id, account))
+                    .or(global),
+            }?;
+            if role.requires_licence() && !Self::has_valid_credential(account) {
+                return None;
+            }
+            Some(role)
+        }
+
+        /// Whether the account holds a recorded, unexpired credential
+        pub fn has_valid_credential(account: &T::AccountId) -> bool {
+            Credentials::<T>::get(account).is_some_and(|credential| {
+                !credential.is_expired(<frame_system::Pallet<T>>::block_number())
+            })
+        }
+
+        /// Emergency access duration for the accessor's role
+        ///
+        /// Doctors and Nurses have their own configured durations; Admins
+        /// fall back to `DefaultAccessDuration`.
+        pub fn emergency_access_duration(accessor: &T::AccountId) -> BlockNumberFor<T> {
+            match Self::current_role(accessor) {
+                Some(Role::Doctor) => T::DoctorAccessDuration::get(),
+                Some(Role::Nurse) => T::NurseAccessDuration::get(),
+                _ => T::DefaultAccessDuration::get(),
+            }
+        }
+
+        /// Grant Regular or Full access to `accessor`, on behalf of `patient`
+        ///
+        /// Shared by `grant_access` and `grant_access_as_guardian`; the caller
+        /// has already been authorized.
+        fn do_grant_access(
+            patient: T::AccountId,
+            accessor: T::AccountId,
+            access_type: AccessType,
+            duration: Option<BlockNumberFor<T>>,
+        ) -> DispatchResult {
+            ensure!(patient != accessor, Error::<T>::NotAuthorized);
+            ensure!(
+                matches!(access_type, AccessType::Regular | AccessType::Full),
+                Error::<T>::InvalidAccessType
+            );
+            ensure!(
+                duration.is_none_or(|blocks| !blocks.is_zero()),
+                Error::<T>::InvalidDuration
+            );
+            ensure!(
+                Self::is_healthcare_provider(&accessor),
+                Error::<T>::NotHealthcareProvider
+            );
+            ensure!(
+                Self::is_active_patient(&patient),
+                Error::<T>::PatientNotActive
+            );
+            ensure!(
+                !ActiveAccess::<T>::contains_key(&patient, &accessor),
+                Error::<T>::AccessAlreadyGranted
+            );
+            ensure!(
+                AccessCount::<T>::get(&patient) < T::MaxActiveAccesses::get(),
+                Error::<T>::TooManyAccesses
+            );
+
+            let current_block = <frame_system::Pallet<T>>::block_number();
+            let expires_at = duration.map(|blocks| current_block.saturating_add(blocks));
+
+            if let Some(expires_at) = expires_at {
+                Self::schedule_expiry(expires_at, &patient, &accessor);
+            }
+
+            let access_log = AccessLog {
+                accessor: accessor.clone(),
+                access_type: access_type.clone(),
+                granted_at: current_block,
+                expires_at,
+                reason_hash: [0u8; 32],
+                revoked: false,
+            };
+
+            ActiveAccess::<T>::insert(&patient, &accessor, access_log);
+            AccessCount::<T>::mutate(&patient, |count| *count = count.saturating_add(1));
+            Self::log_audit(
+                &patient,
+                &accessor,
+                AuditAction::AccessGranted(access_type.clone()),
+                [0u8; 32],
+            );
+
+            Self::deposit_event(Event::AccessGranted {
+                patient,
+                accessor,
+                access_type,
+                expires_at,
+            });
+
+            Ok(())
+        }
+
+        /// Whether `who` is an unexpired guardian of `patient` delegated `scope`
+        pub fn is_guardian(
+            patient: &T::AccountId,
+            who: &T::AccountId,
+            scope: GuardianScope,
+        ) -> bool {
+            Self::ensure_guardian(patient, who, scope).is_ok()
+        }
+
+        /// Consent directives in force for a patient (defaults if never set)
+        pub fn consent_directives(patient: &T::AccountId) -> ConsentDirectives {
+            Consents::<T>::get(patient)
+                .map(|record| record.directives)
+                .unwrap_or_default()
+        }
+
+        /// How consent sees `who` when they look at `patient`'s data
+        ///
+        /// Only valid Regular or Full grants make a treating provider; emergency
+        /// grants and card taps without a grant count as emergency providers.
+        pub fn consent_viewer(patient: &T::AccountId, who: &T::AccountId) -> ConsentViewer {
+            if who == patient || Self::is_guardian(patient, who, GuardianScope::ViewRecords) {
+                return ConsentViewer::PatientOrGuardian;
+            }
+            let treating = ActiveAccess::<T>::get(patient, who).is_some_and(|access| {
+                let current_block = <frame_system::Pallet<T>>::block_number();
+                matches!(access.access_type, AccessType::Regular | AccessType::Full)
+                    && !access.revoked
+                    && !access.is_expired(current_block)
+            });
+            if treating {
+                ConsentViewer::TreatingProvider
+            } else {
+                ConsentViewer::EmergencyProvider
+            }
+        }
+
+        /// What `who` may see of `patient`'s data under the patient's directives
+        pub fn disclosure(patient: &T::AccountId, who: &T::AccountId) -> Disclosure {
+            Self::consent_directives(patient).disclosure(Self::consent_viewer(patient, who))
+        }
+
+        /// Check that `who` may act for `patient` within `scope`
+        fn ensure_guardian(
+            patient: &T::AccountId,
+            who: &T::AccountId,
+            scope: GuardianScope,
+        ) -> DispatchResult {
+            let guardianship =
+                Guardians::<T>::get(patient, who).ok_or(Error::<T>::GuardianNotFound)?;
+            let current_block = <frame_system::Pallet<T>>::block_number();
+            ensure!(
+                !guardianship.is_expired(current_block),
+                Error::<T>::GuardianshipExpired
+            );
+            ensure!(
+                guardianship.scopes.contains(scope),
+                Error::<T>::GuardianScopeMissing
+            );
+            Ok(())
+        }
+
+        /// Open the break-glass review for an emergency grant
+        ///
+        /// The review falls due `JustificationPeriod` blocks after the grant.
+        fn open_review(
+            patient: &T::AccountId,
+            accessor: &T::AccountId,
+            reason_hash: [u8; 32],
+            granted_at: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            let review_id = NextReviewId::<T>::get();
+            let next_review_id = review_id
+                .checked_add(1)
+                .ok_or(Error::<T>::ReviewIdOverflow)?;
+            let justify_by = granted_at.saturating_add(T::JustificationPeriod::get());
+
+            Self::schedule_review_deadline(justify_by, review_id);
+
+            let review = BreakGlassReview {
+                patient: patient.clone(),
+                accessor: accessor.clone(),
+                reason_hash,
+                granted_at,
+                justify_by,
+                justification_hash: None,
+                status: ReviewStatus::AwaitingJustification,
+            };
+
+            BreakGlassReviews::<T>::insert(review_id, review);
+            NextReviewId::<T>::put(next_review_id);
+
+            Self::deposit_event(Event::BreakGlassReviewOpened {
+                review_id,
+                patient: patient.clone(),
+                accessor: accessor.clone(),
+                justify_by,
+            });
+
+            Ok(())
+        }
+
+        /// Mark a review unjustified if its deadline passed without a justification
+        ///
+        /// Counts against the accessor's misuse metric and raises an alert.
+        fn mark_unjustified(review_id: u32) {
+            let Some(mut review) = BreakGlassReviews::<T>::get(review_id) else {
+                return;
+            };
+            if review.status != ReviewStatus::AwaitingJustification {
+                return;
+            }
+
+            review.status = ReviewStatus::Unjustified;
+            let misuse_count = MisuseCount::<T>::mutate(&review.accessor, |count| {
+                *count = count.saturating_add(1);
+                *count
+            });
+            Self::log_audit(
+                &review.patient,
+                &review.accessor,
+                AuditAction::AccessUnjustified,
+                review.reason_hash,
+            );
+            BreakGlassReviews::<T>::insert(review_id, &review);
+
+            Self::deposit_event(Event::EmergencyAccessUnjustified {
+                review_id,
+                patient: review.patient,
+                accessor: review.accessor,
+                misuse_count,
+            });
+        }
+
+        /// Check that `who` may manage `facility_id`'s staff
+        ///
+        /// The facility's own Admin or any ministry Admin qualifies.
+        fn ensure_facility_admin(
+            facility_id: FacilityId,
+            who: &T::AccountId,
+        ) -> Result<Facility<T>, DispatchError> {
+            let facility = Facilities::<T>::get(facility_id).ok_or(Error::<T>::FacilityNotFound)?;
+            ensure!(
+                facility.admin == *who || Self::is_admin(who),
+                Error::<T>::NotFacilityAdmin
+            );
+            Ok(facility)
+        }
+
+        /// Raise `CredentialExpiring` unless the credential was renewed since
+        /// the warning was queued
+        ///
+        /// A spilled warning falls due after its `warn_at`, so only a warning
+        /// that is not due yet is stale.
+        fn warn_credential_expiry(provider: &T::AccountId, now: BlockNumberFor<T>) {
+            let Some(credential) = Credentials::<T>::get(provider) else {
+                return;
+            };
+            let warn_at = credential
+                .valid_until
+                .saturating_sub(T::CredentialWarningPeriod::get());
+            if warn_at > now {
+                return;
+            }
+
+            Self::deposit_event(Event::CredentialExpiring {
+                provider: provider.clone(),
+                valid_until: credential.valid_until,
+            });
+        }
+
+        /// Queue a grant for automatic removal after `expires_at`
+        ///
+        /// Never refuses the grant: if the block is full the entry spills into
+        /// the next block with room, up to `MaxQueueSpill` blocks later. Past
+        /// that it is left for `cleanup_expired_access`. Either way the grant
+        /// stops being valid at `expires_at`; only its removal is delayed.
+        fn schedule_expiry(
+            expires_at: BlockNumberFor<T>,
+            patient: &T::AccountId,
+            accessor: &T::AccountId,
+        ) {
+            let mut block = expires_at;
+
+            // Rule 2: bounded by MaxQueueSpill
+            for _ in 0..=T::MaxQueueSpill::get() {
+                let queued = ExpiryQueue::<T>::try_mutate(block, |queue| {
+                    queue.try_push((patient.clone(), accessor.clone()))
+                })
+                .is_ok();
+                if queued {
+                    return;
+                }
+                block = block.saturating_add(One::one());
+            }
+        }
+
+        /// Queue a review to be marked unjustified after `justify_by`
+        ///
+        /// Never refuses the emergency grant: if the block is full the review
+        /// spills into the next block with room, up to `MaxQueueSpill` blocks
+        /// later. Past that it is left for `mark_review_unjustified`. Either
+        /// way justifications are refused after `justify_by`; only marking
+        /// the review unjustified is delayed.
+        fn schedule_review_deadline(justify_by: BlockNumberFor<T>, review_id: u32) {
+            let mut block = justify_by;
+
+            // Rule 2: bounded by MaxQueueSpill
+            for _ in 0..=T::MaxQueueSpill::get() {
+                if ReviewDeadlines::<T>::try_mutate(block, |queue| queue.try_push(review_id))
+                    .is_ok()
+                {
+                    return;
+                }
+                block = block.saturating_add(One::one());
+            }
+        }
+
+        /// Queue a credential expiry warning for `warn_at`
+        ///
+        /// Never refuses the credential: if the block is full the warning
+        /// spills into the next block with room, up to `MaxQueueSpill` blocks
+        /// later. Returns `false` if every block was full, leaving the caller
+        /// to warn straight away.
+        fn schedule_credential_warning(
+            warn_at: BlockNumberFor<T>,
+            provider: &T::AccountId,
+        ) -> bool {
+            let mut block = warn_at;
+
+            // Rule 2: bounded by MaxQueueSpill
+            for _ in 0..=T::MaxQueueSpill::get() {
+                let queued = CredentialWarnings::<T>::try_mutate(block, |queue| {
+                    queue.try_push(provider.clone())
+                })
+                .is_ok();
+                if queued {
+                    return true;
+                }
+                block = block.saturating_add(One::one());
+            }
+            false
+        }
+
+        /// Drop a pending credential warning queued for `warn_at`, or spilled
+        /// past it
+        fn unschedule_credential_warning(warn_at: BlockNumberFor<T>, provider: &T::AccountId) {
+            let mut block = warn_at;
+
+            // Rule 2: bounded by MaxQueueSpill
+            for _ in 0..=T::MaxQueueSpill::get() {
+                if CredentialWarnings::<T>::get(block).contains(provider) {
+                    CredentialWarnings::<T>::mutate(block, |queue| {
+                        queue.retain(|pending| pending != provider)
+                    });
+                    return;
+                }
+                block = block.saturating_add(One::one());
+            }
+        }
+
+        /// Worst-case extra weight of a queue push that spills past full blocks
+        pub fn queue_spill_weight() -> Weight {
+            T::DbWeight::get().reads(u64::from(T::MaxQueueSpill::get()))
+        }
+
+        /// Remove a queued grant if it has expired by `now`
+        ///
+        /// The grant may have been cleaned up and re-granted with a later
+        /// expiry since it was queued, in which case it is left alone.
+        fn expire_access(patient: &T::AccountId, accessor: &T::AccountId, now: BlockNumberFor<T>) {
+            let Some(access) = ActiveAccess::<T>::get(patient, accessor) else {
+                return;
+            };
+            if !access.is_expired(now) {
+                return;
+            }
+
+            ActiveAccess::<T>::remove(patient, accessor);
+            if !access.revoked {
+                AccessCount::<T>::mutate(patient, |count| *count = count.saturating_sub(1));
+                Self::log_audit(
+                    patient,
+                    accessor,
+                    AuditAction::AccessExpired,
+                    access.reason_hash,
+                );
+            }
+
+            Self::deposit_event(Event::ExpiredAccessCleaned {
+                patient: patient.clone(),
+                accessor: accessor.clone(),
+            });
+        }
+
+        /// Append an entry to the patient's audit log
+        ///
+        /// Entries are never modified. Once more than `MaxAuditEntries` exist,
+        /// the oldest retained entry is pruned (Rule 2: bounded storage).
+        /// Other pallets call this for record reads and writes.
+        pub fn log_audit(
+            patient: &T::AccountId,
+            who: &T::AccountId,
+            action: AuditAction,
+            reason_hash: [u8; 32],
+        ) {
+            let index = AuditLogLength::<T>::get(patient);
+            let entry = AuditEntry {
+                who: who.clone(),
+                action,
+                block: <frame_system::Pallet<T>>::block_number(),
+                reason_hash,
+            };
+
+            AuditLog::<T>::insert(patient, index, entry);
+            AuditLogLength::<T>::insert(patient, index.saturating_add(1));
+
+            if let Some(pruned) = index.checked_sub(T::MaxAuditEntries::get()) {
+                AuditLog::<T>::remove(patient, pruned);
+            }
+        }
+
+        /// Page through a patient's audit log, oldest first
+        ///
+        /// Returns up to `limit` retained entries with index `>= start`,
+        /// each paired with its index. `limit` is capped at `MaxAuditEntries`.
+        pub fn audit_log(
+            patient: &T::AccountId,
+            start: u32,
+            limit: u32,
+        ) -> Vec<(u32, AuditEntry<T>)> {
+            let length = AuditLogLength::<T>::get(patient);
+            let first_retained = length.saturating_sub(T::MaxAuditEntries::get());
+            let start = start.max(first_retained);
+            let end = start
+                .saturating_add(limit.min(T::MaxAuditEntries::get()))
+                .min(length);
+
+            // Rule 2: bounded by MaxAuditEntries
+            (start..end)
+                .filter_map(|index| AuditLog::<T>::get(patient, index).map(|entry| (index, entry)))
+                .collect()
+        }
+
+        /// Whether the patient's identity is still active (see `Config::PatientStatus`)
+        pub fn is_active_patient(patient: &T::AccountId) -> bool {
+            <T::PatientStatus as crate::PatientStatus<T::AccountId>>::is_active(patient)
+        }
+
+        /// Check if accessor has valid (non-expired, non-revoked) access
+        pub fn has_valid_access(patient: &T::AccountId, accessor: &T::AccountId) -> bool {
+            if let Some(access) = ActiveAccess::<T>::get(patient, accessor) {
+                let current_block = <frame_system::Pallet<T>>::block_number();
+                !access.revoked && !access.is_expired(current_block)
+            } else {
+                false
+            }
+        }
+    }
+
+    impl<T: Config> crate::AccessPermissions<T::AccountId> for Pallet<T> {
+        fn role(who: &T::AccountId, patient: &T::AccountId) -> Option<Role> {
+            Self::active_role(who, patient)
+        }
+
+        fn has_permission(
+            who: &T::AccountId,
+            patient: &T::AccountId,
+            permission: Permission,
+        ) -> bool {
+            Self::has_permission(who, patient, permission)
+        }
+
+        fn has_valid_access(patient: &T::AccountId, accessor: &T::AccountId) -> bool {
+            Self::has_valid_access(patient, accessor)
+        }
+
+        fn is_guardian(patient: &T::AccountId, who: &T::AccountId, scope: GuardianScope) -> bool {
+            Self::is_guardian(patient, who, scope)
+        }
+
+        fn disclosure(patient: &T::AccountId, who: &T::AccountId) -> Disclosure {
+            Self::disclosure(patient, who)
+        }
+
+        fn ensure_patient_role(who: &T::AccountId) {
+            UserRoles::<T>::mutate(who, |maybe_role| {
+                if maybe_role.is_none() {
+                    *maybe_role = Some(Role::Patient);
+                }
+            });
+        }
+
+        fn log_audit(
+            patient: &T::AccountId,
+            who: &T::AccountId,
+            action: AuditAction,
+            reason_hash: [u8; 32],
+        ) {
+            Self::log_audit(patient, who, action, reason_hash)
+        }
+
+        fn active_facility(who: &T::AccountId) -> Option<FacilityId> {
+            ActiveFacility::<T>::get(who)
+        }
+
+        fn is_active_patient(patient: &T::AccountId) -> bool {
+            Self::is_active_patient(patient)
+        }
+
+        #[cfg(feature = "runtime-benchmarks")]
+        fn set_role(who: &T::AccountId, role: Role) {
+            UserRoles::<T>::insert(who, role);
+            if role.requires_licence() {
+                let credential = Credential {
+                    licence_id_hash: [0u8; 32],
+                    issuing_body: IssuingBody::MedicalCouncil,
+                    valid_until: u32::MAX.into(),
+                    facility_id: 0,
+                    registered_at: <frame_system::Pallet<T>>::block_number(),
+                };
+                Credentials::<T>::insert(who, credential);
+            }
+        }
+
+        #[cfg(feature = "runtime-benchmarks")]
+        fn set_valid_access(patient: &T::AccountId, accessor: &T::AccountId) {
+            let grant = AccessLog {
+                accessor: accessor.clone(),
+                access_type: AccessType::Regular,
+                granted_at: <frame_system::Pallet<T>>::block_number(),
+                expires_at: None,
+                reason_hash: [0u8; 32],
+                revoked: false,
+            };
+            ActiveAccess::<T>::insert(patient, accessor, grant);
         }
     }
 }
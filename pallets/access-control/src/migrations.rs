@@ -0,0 +1,64 @@
+//! Storage migrations for the access-control pallet
+//!
+//! Add new migrations as `vN` modules and list them in the runtime's
+//! `Migrations` tuple; `VersionedMigration` makes each one run exactly once.
+//! The chain starts from the layout of the first release (storage version 0).
+
+use crate::{AccessLog, AccessType, ActiveAccess, Config, Pallet};
+use frame_support::{
+    migrations::VersionedMigration, pallet_prelude::*, traits::UncheckedOnRuntimeUpgrade,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+
+/// v1: access grants may be indefinite, so `expires_at` becomes optional
+pub mod v1 {
+    use super::*;
+
+    /// Access grant as stored before v1
+    #[derive(Encode, Decode)]
+    pub(crate) struct OldAccessLog<T: Config> {
+        pub accessor: T::AccountId,
+        pub access_type: AccessType,
+        pub granted_at: BlockNumberFor<T>,
+        pub expires_at: BlockNumberFor<T>,
+        pub reason_hash: [u8; 32],
+        pub revoked: bool,
+    }
+
+    /// Wrap every existing expiry block in `Some`
+    ///
+    /// Every grant made before v1 had a fixed expiry, so none of them become
+    /// indefinite. Grants from the first release were never put in
+    /// `ExpiryQueue`; access checks still refuse them once `is_expired`.
+    pub struct InnerMigrateV0ToV1<T>(core::marker::PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV0ToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated: u64 = 0;
+
+            // Rule 2: bounded by the number of access grants (one-off upgrade)
+            ActiveAccess::<T>::translate::<OldAccessLog<T>, _>(|_, _, old| {
+                translated = translated.saturating_add(1);
+                Some(AccessLog {
+                    accessor: old.accessor,
+                    access_type: old.access_type,
+                    granted_at: old.granted_at,
+                    expires_at: Some(old.expires_at),
+                    reason_hash: old.reason_hash,
+                    revoked: old.revoked,
+                })
+            });
+
+            T::DbWeight::get().reads_writes(translated, translated)
+        }
+    }
+
+    /// Runs `InnerMigrateV0ToV1` once, when the on-chain storage version is 0
+    pub type MigrateToV1<T> = VersionedMigration<
+        0,
+        1,
+        InnerMigrateV0ToV1<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
@@ -7,8 +7,9 @@
 use crate as pallet_access_control;
 use frame_support::{
     derive_impl,
-    traits::{ConstU16, ConstU64},
+    traits::{ConstU16, ConstU32, ConstU64, Hooks},
 };
+use frame_system::EnsureRoot;
 use sp_core::H256;
 use sp_runtime::{
     traits::{BlakeTwo256, IdentityLookup},
@@ -49,11 +50,66 @@ impl frame_system::Config for Test {
     type SystemWeightInfo = ();
     type SS58Prefix = ConstU16<42>;
     type OnSetCode = ();
-    type MaxConsumers = frame_support::traits::ConstU32<16>;
+    type MaxConsumers = ConstU32<16>;
+}
+
+/// Emergency access durations (blocks); Doctors get longer than Nurses
+pub const DEFAULT_ACCESS_DURATION: u64 = 150;
+pub const DOCTOR_ACCESS_DURATION: u64 = 300;
+pub const NURSE_ACCESS_DURATION: u64 = 100;
+/// Access bounds (Rule 2)
+pub const MAX_ACTIVE_ACCESSES: u32 = 10;
+pub const MAX_EXPIRIES_PER_BLOCK: u32 = 64;
+pub const MAX_QUEUE_SPILL: u32 = 4;
+pub const MAX_AUDIT_ENTRIES: u32 = 5;
+pub const MAX_GUARDIANS: u32 = 2;
+/// Blocks an emergency accessor has to justify their grant
+pub const JUSTIFICATION_PERIOD: u64 = 1_000;
+/// Blocks before a credential lapses that the provider is warned
+pub const CREDENTIAL_WARNING_PERIOD: u64 = 100;
+
+/// Patient whose identity is no longer active (e.g. deceased)
+pub const INACTIVE_PATIENT: u64 = 101;
+
+/// Identity registry stand-in: only `INACTIVE_PATIENT` is inactive
+pub struct MockPatientStatus;
+
+impl pallet_access_control::PatientStatus<u64> for MockPatientStatus {
+    fn is_active(patient: &u64) -> bool {
+        *patient != INACTIVE_PATIENT
+    }
+}
+
+/// Patient never treated at any facility
+pub const OTHER_PATIENT: u64 = 102;
+
+/// Medical-records stand-in: every patient but `OTHER_PATIENT` is treated at every facility
+pub struct MockPatientFacilities;
+
+impl pallet_access_control::PatientFacilities<u64> for MockPatientFacilities {
+    fn is_treated_at(patient: &u64, _facility_id: u32) -> bool {
+        *patient != OTHER_PATIENT
+    }
 }
 
 impl pallet_access_control::Config for Test {
     type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = ();
+    type AdminOrigin = EnsureRoot<u64>;
+    type PatientStatus = MockPatientStatus;
+    type PatientFacilities = MockPatientFacilities;
+    type DefaultAccessDuration = ConstU64<DEFAULT_ACCESS_DURATION>;
+    type DoctorAccessDuration = ConstU64<DOCTOR_ACCESS_DURATION>;
+    type NurseAccessDuration = ConstU64<NURSE_ACCESS_DURATION>;
+    type MaxReasonLength = ConstU32<256>;
+    type MaxActiveAccesses = ConstU32<MAX_ACTIVE_ACCESSES>;
+    type MaxExpiriesPerBlock = ConstU32<MAX_EXPIRIES_PER_BLOCK>;
+    type MaxQueueSpill = ConstU32<MAX_QUEUE_SPILL>;
+    type MaxAuditEntries = ConstU32<MAX_AUDIT_ENTRIES>;
+    type MaxGuardians = ConstU32<MAX_GUARDIANS>;
+    type JustificationPeriod = ConstU64<JUSTIFICATION_PERIOD>;
+    type CredentialWarningPeriod = ConstU64<CREDENTIAL_WARNING_PERIOD>;
+    type MaxFacilityNameLength = ConstU32<64>;
 }
 
 /// Test account constants for RBAC testing
@@ -62,48 +118,79 @@ pub const DOCTOR: u64 = 2;
 pub const NURSE: u64 = 3;
 pub const LAB_TECH: u64 = 4;
 pub const PHARMACIST: u64 = 5;
+pub const PRIVACY_OFFICER: u64 = 6;
+/// Facility Admin (no global role) and facility staff (no global role)
+pub const FACILITY_ADMIN: u64 = 10;
+pub const STAFF: u64 = 20;
 pub const PATIENT: u64 = 100;
+pub const GUARDIAN: u64 = 200;
 pub const UNAUTHORIZED: u64 = 999;
 
-/// Build genesis storage for testing with initial admin
-pub fn new_test_ext() -> sp_io::TestExternalities {
-    let t = frame_system::GenesisConfig::<Test>::default()
+/// Genesis hospital where the licensed test providers practise
+pub const HOSPITAL: u32 = 0;
+/// Last block the genesis providers' credentials are valid
+pub const CREDENTIAL_VALID_UNTIL: u64 = 1_000_000;
+
+/// Build test externalities from a list of genesis role assignments
+///
+/// Licensed roles get a credential at `HOSPITAL`, valid until `CREDENTIAL_VALID_UNTIL`.
+pub fn new_test_ext_from_genesis(
+    roles: Vec<(u64, pallet_access_control::Role)>,
+) -> sp_io::TestExternalities {
+    use pallet_access_control::{FacilityKind, IssuingBody};
+
+    let mut t = frame_system::GenesisConfig::<Test>::default()
         .build_storage()
         .unwrap();
-    let mut ext: sp_io::TestExternalities = t.into();
-    ext.execute_with(|| {
-        // Setup initial admin role for testing
-        // In production, this would be set in genesis config
-        pallet_access_control::UserRoles::<Test>::insert(ADMIN, pallet_access_control::Role::Admin);
-    });
-    ext
+    let credentials = roles
+        .iter()
+        .filter(|(_, role)| role.requires_licence())
+        .map(|(account, _)| {
+            (
+                *account,
+                [0u8; 32],
+                IssuingBody::MedicalCouncil,
+                CREDENTIAL_VALID_UNTIL,
+                HOSPITAL,
+            )
+        })
+        .collect();
+    pallet_access_control::GenesisConfig::<Test> {
+        roles,
+        facilities: vec![(FacilityKind::Hospital, b"Genesis Hospital".to_vec(), ADMIN)],
+        credentials,
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+    t.into()
+}
+
+/// Build genesis storage for testing with initial admin
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    new_test_ext_from_genesis(vec![(ADMIN, pallet_access_control::Role::Admin)])
 }
 
 /// Build test ext with all roles pre-configured
 pub fn new_test_ext_with_roles() -> sp_io::TestExternalities {
-    let t = frame_system::GenesisConfig::<Test>::default()
-        .build_storage()
-        .unwrap();
-    let mut ext: sp_io::TestExternalities = t.into();
-    ext.execute_with(|| {
-        pallet_access_control::UserRoles::<Test>::insert(ADMIN, pallet_access_control::Role::Admin);
-        pallet_access_control::UserRoles::<Test>::insert(
-            DOCTOR,
-            pallet_access_control::Role::Doctor,
-        );
-        pallet_access_control::UserRoles::<Test>::insert(NURSE, pallet_access_control::Role::Nurse);
-        pallet_access_control::UserRoles::<Test>::insert(
-            LAB_TECH,
-            pallet_access_control::Role::LabTechnician,
-        );
-        pallet_access_control::UserRoles::<Test>::insert(
-            PHARMACIST,
-            pallet_access_control::Role::Pharmacist,
-        );
-        pallet_access_control::UserRoles::<Test>::insert(
-            PATIENT,
-            pallet_access_control::Role::Patient,
-        );
-    });
-    ext
+    use pallet_access_control::Role;
+
+    new_test_ext_from_genesis(vec![
+        (ADMIN, Role::Admin),
+        (DOCTOR, Role::Doctor),
+        (NURSE, Role::Nurse),
+        (LAB_TECH, Role::LabTechnician),
+        (PHARMACIST, Role::Pharmacist),
+        (PRIVACY_OFFICER, Role::PrivacyOfficer),
+        (PATIENT, Role::Patient),
+    ])
+}
+
+/// Advance to block `n`, running `on_initialize` for every new block
+pub fn run_to_block(n: u64) {
+    // Rule 2: bounded by target block number
+    while System::block_number() < n {
+        let next = System::block_number() + 1;
+        System::set_block_number(next);
+        AccessControl::on_initialize(next);
+    }
 }
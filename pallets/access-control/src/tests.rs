@@ -4,53 +4,87 @@
 
 #![cfg(test)]
 
-use crate::{mock::*, AccessType, Error, Role, DEFAULT_ACCESS_DURATION};
-use frame_support::{assert_noop, assert_ok};
+use crate::{
+    mock::*, AccessPermissions, AccessType, AuditAction, ConsentDirectives, ConsentViewer,
+    CredentialWarnings, Credentials, Disclosure, Error, Event, ExpiryQueue, FacilityKind,
+    GenesisConfig, GuardianRelationship, GuardianScope, GuardianScopes, IssuingBody,
+    NextFacilityId, NextReviewId, Permission, ReviewDeadlines, ReviewStatus, ReviewVerdict, Role,
+};
+use frame_support::{assert_noop, assert_ok, BoundedVec};
+use sp_runtime::{BuildStorage, DispatchError};
 
 // =============================================================================
 // Role Management Tests
 // =============================================================================
 
-/// Test assigning a role by admin
+/// Test assigning a role through AdminOrigin, licensed roles only once
+/// the provider's credential is recorded
 #[test]
 fn assign_role_works() {
     new_test_ext().execute_with(|| {
+        System::set_block_number(1);
         let new_doctor = 10u64;
 
+        assert_noop!(
+            AccessControl::assign_role(RuntimeOrigin::root(), new_doctor, Role::Doctor),
+            Error::<Test>::CredentialRequired
+        );
+        assert_ok!(AccessControl::register_credential(
+            RuntimeOrigin::root(),
+            new_doctor,
+            [7u8; 32],
+            IssuingBody::MedicalCouncil,
+            1_000,
+            HOSPITAL,
+        ));
         assert_ok!(AccessControl::assign_role(
-            RuntimeOrigin::signed(ADMIN),
+            RuntimeOrigin::root(),
             new_doctor,
             Role::Doctor,
         ));
 
         assert!(AccessControl::is_doctor(&new_doctor));
         assert!(AccessControl::is_healthcare_provider(&new_doctor));
+        System::assert_last_event(
+            Event::RoleAssigned {
+                account: new_doctor,
+                role: Role::Doctor,
+            }
+            .into(),
+        );
     });
 }
 
-/// Test non-admin cannot assign roles
+/// Test signed accounts, even a single Admin, cannot assign roles
 #[test]
-fn assign_role_fails_if_not_admin() {
+fn assign_role_fails_if_not_admin_origin() {
     new_test_ext_with_roles().execute_with(|| {
         let new_user = 50u64;
 
         assert_noop!(
             AccessControl::assign_role(RuntimeOrigin::signed(DOCTOR), new_user, Role::Nurse,),
-            Error::<Test>::InsufficientRole
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            AccessControl::assign_role(RuntimeOrigin::signed(ADMIN), new_user, Role::Nurse,),
+            DispatchError::BadOrigin
         );
     });
 }
 
-/// Test cannot assign Admin role
+/// Test AdminOrigin can add further Admins after genesis
 #[test]
-fn assign_admin_role_fails() {
+fn assign_admin_role_works() {
     new_test_ext().execute_with(|| {
-        let new_user = 50u64;
+        let new_admin = 50u64;
 
-        assert_noop!(
-            AccessControl::assign_role(RuntimeOrigin::signed(ADMIN), new_user, Role::Admin,),
-            Error::<Test>::CannotAssignAdmin
-        );
+        assert_ok!(AccessControl::assign_role(
+            RuntimeOrigin::root(),
+            new_admin,
+            Role::Admin,
+        ));
+
+        assert!(AccessControl::is_admin(&new_admin));
     });
 }
 
@@ -59,46 +93,47 @@ fn assign_admin_role_fails() {
 fn assign_role_fails_if_already_assigned() {
     new_test_ext_with_roles().execute_with(|| {
         assert_noop!(
-            AccessControl::assign_role(RuntimeOrigin::signed(ADMIN), DOCTOR, Role::Nurse,),
+            AccessControl::assign_role(RuntimeOrigin::root(), DOCTOR, Role::Nurse,),
             Error::<Test>::RoleAlreadyAssigned
         );
     });
 }
 
-/// Test revoking a role by admin
+/// Test revoking a role through AdminOrigin
 #[test]
 fn revoke_role_works() {
     new_test_ext_with_roles().execute_with(|| {
         assert!(AccessControl::is_doctor(&DOCTOR));
 
-        assert_ok!(AccessControl::revoke_role(
-            RuntimeOrigin::signed(ADMIN),
-            DOCTOR,
-        ));
+        assert_ok!(AccessControl::revoke_role(RuntimeOrigin::root(), DOCTOR));
 
         assert!(!AccessControl::is_doctor(&DOCTOR));
         assert!(AccessControl::get_role(&DOCTOR).is_none());
     });
 }
 
-/// Test non-admin cannot revoke roles
+/// Test signed accounts cannot revoke roles, including an Admin's own
 #[test]
-fn revoke_role_fails_if_not_admin() {
+fn revoke_role_fails_if_not_admin_origin() {
     new_test_ext_with_roles().execute_with(|| {
         assert_noop!(
             AccessControl::revoke_role(RuntimeOrigin::signed(DOCTOR), NURSE,),
-            Error::<Test>::InsufficientRole
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            AccessControl::revoke_role(RuntimeOrigin::signed(ADMIN), ADMIN,),
+            DispatchError::BadOrigin
         );
     });
 }
 
-/// Test cannot revoke own role
+/// Test revoking from an account without a role fails
 #[test]
-fn revoke_own_role_fails() {
+fn revoke_role_fails_without_role() {
     new_test_ext().execute_with(|| {
         assert_noop!(
-            AccessControl::revoke_role(RuntimeOrigin::signed(ADMIN), ADMIN,),
-            Error::<Test>::CannotRevokeOwnRole
+            AccessControl::revoke_role(RuntimeOrigin::root(), UNAUTHORIZED),
+            Error::<Test>::NoRoleToRevoke
         );
     });
 }
@@ -115,10 +150,13 @@ fn role_helper_functions_work() {
         assert!(AccessControl::is_doctor(&DOCTOR));
         assert!(!AccessControl::is_doctor(&NURSE));
 
-        // Healthcare provider checks (Admin, Doctor, Nurse)
+        // Healthcare provider checks (every role holding ViewRecords)
         assert!(AccessControl::is_healthcare_provider(&ADMIN));
         assert!(AccessControl::is_healthcare_provider(&DOCTOR));
         assert!(AccessControl::is_healthcare_provider(&NURSE));
+        assert!(AccessControl::is_healthcare_provider(&LAB_TECH));
+        assert!(AccessControl::is_healthcare_provider(&PHARMACIST));
+        assert!(!AccessControl::is_healthcare_provider(&PRIVACY_OFFICER));
         assert!(!AccessControl::is_healthcare_provider(&PATIENT));
 
         // Can register patients checks
@@ -133,12 +171,164 @@ fn role_helper_functions_work() {
         assert!(AccessControl::can_edit_medical_records(&NURSE));
         assert!(!AccessControl::can_edit_medical_records(&PATIENT));
 
+        // Can verify blood type checks (Doctor, LabTechnician)
+        assert!(AccessControl::can_verify_blood_type(&DOCTOR));
+        assert!(AccessControl::can_verify_blood_type(&LAB_TECH));
+        assert!(!AccessControl::can_verify_blood_type(&NURSE));
+        assert!(!AccessControl::can_verify_blood_type(&ADMIN));
+
         // Patient checks
         assert!(AccessControl::is_patient(&PATIENT));
         assert!(!AccessControl::is_patient(&DOCTOR));
     });
 }
 
+/// Test permission matrix rows and the account-level has_permission check
+#[test]
+fn permission_matrix_works() {
+    use crate::Permission;
+
+    new_test_ext_with_roles().execute_with(|| {
+        // Lab technicians and pharmacists cannot register patients or edit records
+        assert!(!AccessControl::has_permission(
+            &LAB_TECH,
+            &PATIENT,
+            Permission::RegisterPatient
+        ));
+        assert!(!AccessControl::has_permission(
+            &PHARMACIST,
+            &PATIENT,
+            Permission::RegisterPatient
+        ));
+        assert!(!AccessControl::has_permission(
+            &LAB_TECH,
+            &PATIENT,
+            Permission::EditRecord
+        ));
+        assert!(AccessControl::has_permission(
+            &LAB_TECH,
+            &PATIENT,
+            Permission::AddLabResult
+        ));
+        assert!(AccessControl::has_permission(
+            &PHARMACIST,
+            &PATIENT,
+            Permission::ViewRecords
+        ));
+        assert!(!AccessControl::has_permission(
+            &PHARMACIST,
+            &PATIENT,
+            Permission::AddLabResult
+        ));
+
+        // Emergency access only for Admin, Doctor, Nurse
+        assert!(AccessControl::has_permission(
+            &NURSE,
+            &PATIENT,
+            Permission::EmergencyAccess
+        ));
+        assert!(!AccessControl::has_permission(
+            &PHARMACIST,
+            &PATIENT,
+            Permission::EmergencyAccess
+        ));
+
+        // Patients and accounts without a role hold no permissions
+        assert!(Role::Patient.permissions().is_empty());
+        assert!(!AccessControl::has_permission(
+            &PATIENT,
+            &PATIENT,
+            Permission::ViewRecords
+        ));
+        assert!(!AccessControl::has_permission(
+            &UNAUTHORIZED,
+            &PATIENT,
+            Permission::ViewRecords
+        ));
+    });
+}
+
+/// Test pharmacists cannot take emergency access
+#[test]
+fn grant_emergency_access_fails_for_pharmacist() {
+    new_test_ext_with_roles().execute_with(|| {
+        assert_noop!(
+            AccessControl::grant_emergency_access(
+                RuntimeOrigin::signed(PHARMACIST),
+                PATIENT,
+                [0u8; 32]
+            ),
+            Error::<Test>::NotHealthcareProvider
+        );
+    });
+}
+
+// =============================================================================
+// Genesis Tests
+// =============================================================================
+
+/// Test genesis config seeds Admin and provider roles
+#[test]
+fn genesis_config_seeds_roles() {
+    new_test_ext_from_genesis(vec![
+        (ADMIN, Role::Admin),
+        (DOCTOR, Role::Doctor),
+        (PHARMACIST, Role::Pharmacist),
+    ])
+    .execute_with(|| {
+        assert!(AccessControl::is_admin(&ADMIN));
+        assert_eq!(AccessControl::get_role(&DOCTOR), Some(Role::Doctor));
+        assert_eq!(AccessControl::get_role(&PHARMACIST), Some(Role::Pharmacist));
+        assert!(AccessControl::get_role(&NURSE).is_none());
+
+        // Genesis providers practise at a genesis facility with a genesis credential
+        assert_eq!(AccessControl::facility(HOSPITAL).unwrap().admin, ADMIN);
+        assert_eq!(AccessControl::next_facility_id(), HOSPITAL + 1);
+        let credential = AccessControl::credential(DOCTOR).unwrap();
+        assert_eq!(credential.valid_until, CREDENTIAL_VALID_UNTIL);
+        assert_eq!(credential.facility_id, HOSPITAL);
+        assert!(AccessControl::is_doctor(&DOCTOR));
+        assert!(AccessControl::credential(ADMIN).is_none());
+
+        // Genesis roles can be extended through AdminOrigin
+        assert_ok!(AccessControl::register_credential(
+            RuntimeOrigin::root(),
+            NURSE,
+            [8u8; 32],
+            IssuingBody::NursingCouncil,
+            1_000,
+            HOSPITAL,
+        ));
+        assert_ok!(AccessControl::assign_role(
+            RuntimeOrigin::root(),
+            NURSE,
+            Role::Nurse,
+        ));
+    });
+}
+
+/// Test genesis config rejects an account listed twice
+#[test]
+#[should_panic(expected = "Account listed twice in access-control genesis roles")]
+fn genesis_config_rejects_duplicate_accounts() {
+    new_test_ext_from_genesis(vec![(ADMIN, Role::Admin), (ADMIN, Role::Doctor)]);
+}
+
+/// Test genesis config rejects a licensed role without a credential
+#[test]
+#[should_panic(expected = "Licensed genesis role without a genesis credential")]
+fn genesis_config_rejects_providers_without_credentials() {
+    let mut t = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+    GenesisConfig::<Test> {
+        roles: vec![(DOCTOR, Role::Doctor)],
+        ..Default::default()
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+}
+
 // =============================================================================
 // Emergency Access Tests (updated for RBAC)
 // =============================================================================
@@ -159,11 +349,41 @@ fn grant_emergency_access_works() {
         // Verify access was granted
         let access = AccessControl::active_access(patient, DOCTOR).unwrap();
         assert_eq!(access.reason_hash, reason_hash);
-        assert_eq!(access.revoked, false);
+        assert!(!access.revoked);
         assert!(matches!(access.access_type, AccessType::Emergency));
     });
 }
 
+/// Test no access can be granted to a patient whose identity is no longer active
+#[test]
+fn grant_fails_for_inactive_patient() {
+    new_test_ext_with_roles().execute_with(|| {
+        assert_ok!(AccessControl::assign_role(
+            RuntimeOrigin::root(),
+            INACTIVE_PATIENT,
+            Role::Patient,
+        ));
+
+        assert_noop!(
+            AccessControl::grant_emergency_access(
+                RuntimeOrigin::signed(DOCTOR),
+                INACTIVE_PATIENT,
+                [1u8; 32],
+            ),
+            Error::<Test>::PatientNotActive
+        );
+        assert_noop!(
+            AccessControl::grant_access(
+                RuntimeOrigin::signed(INACTIVE_PATIENT),
+                DOCTOR,
+                AccessType::Regular,
+                None,
+            ),
+            Error::<Test>::PatientNotActive
+        );
+    });
+}
+
 /// Test patient cannot grant emergency access
 #[test]
 fn grant_emergency_access_fails_for_patient() {
@@ -206,6 +426,43 @@ fn grant_emergency_access_fails_if_exists() {
     });
 }
 
+/// Test emergency access duration follows the accessor's role
+#[test]
+fn emergency_access_duration_depends_on_role() {
+    new_test_ext_with_roles().execute_with(|| {
+        System::set_block_number(1);
+
+        // Rule 2: bounded by the three provider roles
+        for (accessor, duration) in [
+            (ADMIN, DEFAULT_ACCESS_DURATION),
+            (DOCTOR, DOCTOR_ACCESS_DURATION),
+            (NURSE, NURSE_ACCESS_DURATION),
+        ] {
+            assert_ok!(AccessControl::grant_emergency_access(
+                RuntimeOrigin::signed(accessor),
+                PATIENT,
+                [1u8; 32],
+            ));
+            System::assert_last_event(
+                Event::EmergencyAccessGranted {
+                    patient: PATIENT,
+                    accessor,
+                    expires_at: 1 + duration,
+                }
+                .into(),
+            );
+        }
+
+        // Nurse access lapses first, Doctor access outlasts the default
+        run_to_block(NURSE_ACCESS_DURATION + 2);
+        assert!(!AccessControl::has_valid_access(&PATIENT, &NURSE));
+        assert!(AccessControl::has_valid_access(&PATIENT, &ADMIN));
+        run_to_block(DEFAULT_ACCESS_DURATION + 2);
+        assert!(!AccessControl::has_valid_access(&PATIENT, &ADMIN));
+        assert!(AccessControl::has_valid_access(&PATIENT, &DOCTOR));
+    });
+}
+
 /// Test access revocation by patient
 #[test]
 fn revoke_access_by_patient_works() {
@@ -224,7 +481,7 @@ fn revoke_access_by_patient_works() {
         ));
 
         let access = AccessControl::active_access(PATIENT, DOCTOR).unwrap();
-        assert_eq!(access.revoked, true);
+        assert!(access.revoked);
     });
 }
 
@@ -246,7 +503,7 @@ fn revoke_access_by_accessor_works() {
         ));
 
         let access = AccessControl::active_access(PATIENT, DOCTOR).unwrap();
-        assert_eq!(access.revoked, true);
+        assert!(access.revoked);
     });
 }
 
@@ -338,7 +595,7 @@ fn cleanup_expired_access_works() {
         ));
 
         // Fast forward past expiration
-        System::set_block_number(DEFAULT_ACCESS_DURATION as u64 + 10);
+        System::set_block_number(DOCTOR_ACCESS_DURATION + 10);
 
         // Anyone can cleanup expired access
         assert_ok!(AccessControl::cleanup_expired_access(
@@ -352,3 +609,2238 @@ fn cleanup_expired_access_works() {
         assert_eq!(AccessControl::access_count(PATIENT), 0);
     });
 }
+
+/// Test cleaning up a revoked grant does not decrement the count twice
+#[test]
+fn cleanup_revoked_access_does_not_double_decrement() {
+    new_test_ext_with_roles().execute_with(|| {
+        assert_ok!(AccessControl::grant_emergency_access(
+            RuntimeOrigin::signed(DOCTOR),
+            PATIENT,
+            [1u8; 32],
+        ));
+        assert_ok!(AccessControl::grant_emergency_access(
+            RuntimeOrigin::signed(NURSE),
+            PATIENT,
+            [2u8; 32],
+        ));
+        assert_ok!(AccessControl::revoke_access(
+            RuntimeOrigin::signed(PATIENT),
+            PATIENT,
+            DOCTOR,
+        ));
+        assert_eq!(AccessControl::access_count(PATIENT), 1);
+
+        assert_ok!(AccessControl::cleanup_expired_access(
+            RuntimeOrigin::signed(UNAUTHORIZED),
+            PATIENT,
+            DOCTOR,
+        ));
+
+        // Nurse still holds active access
+        assert_eq!(AccessControl::access_count(PATIENT), 1);
+    });
+}
+
+// =============================================================================
+// Automatic Expiry Tests
+// =============================================================================
+
+/// Test expired grants are removed automatically in on_initialize
+#[test]
+fn expired_access_is_cleaned_automatically() {
+    new_test_ext_with_roles().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(AccessControl::grant_emergency_access(
+            RuntimeOrigin::signed(DOCTOR),
+            PATIENT,
+            [1u8; 32],
+        ));
+        let expires_at = 1 + DOCTOR_ACCESS_DURATION;
+        assert_eq!(AccessControl::expiry_queue(expires_at).len(), 1);
+
+        // Still valid up to and including the expiry block
+        run_to_block(expires_at);
+        assert!(AccessControl::has_valid_access(&PATIENT, &DOCTOR));
+        assert_eq!(AccessControl::access_count(PATIENT), 1);
+
+        run_to_block(expires_at + 1);
+        assert!(AccessControl::active_access(PATIENT, DOCTOR).is_none());
+        assert_eq!(AccessControl::access_count(PATIENT), 0);
+        assert!(ExpiryQueue::<Test>::get(expires_at).is_empty());
+        System::assert_last_event(
+            Event::ExpiredAccessCleaned {
+                patient: PATIENT,
+                accessor: DOCTOR,
+            }
+            .into(),
+        );
+    });
+}
+
+/// Test expired grants free their slot toward MaxActiveAccesses
+#[test]
+fn expired_access_frees_access_slot() {
+    new_test_ext_with_roles().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(AccessControl::grant_emergency_access(
+            RuntimeOrigin::signed(DOCTOR),
+            PATIENT,
+            [1u8; 32],
+        ));
+
+        run_to_block(DOCTOR_ACCESS_DURATION + 2);
+
+        // Same doctor can take fresh emergency access after expiry
+        assert_ok!(AccessControl::grant_emergency_access(
+            RuntimeOrigin::signed(DOCTOR),
+            PATIENT,
+            [2u8; 32],
+        ));
+        assert_eq!(AccessControl::access_count(PATIENT), 1);
+    });
+}
+
+/// Test a stale queue entry does not remove a later re-grant
+#[test]
+fn stale_expiry_entry_keeps_regranted_access() {
+    new_test_ext_with_roles().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(AccessControl::grant_emergency_access(
+            RuntimeOrigin::signed(DOCTOR),
+            PATIENT,
+            [1u8; 32],
+        ));
+        assert_ok!(AccessControl::revoke_access(
+            RuntimeOrigin::signed(PATIENT),
+            PATIENT,
+            DOCTOR,
+        ));
+        assert_ok!(AccessControl::cleanup_expired_access(
+            RuntimeOrigin::signed(UNAUTHORIZED),
+            PATIENT,
+            DOCTOR,
+        ));
+
+        run_to_block(2);
+        assert_ok!(AccessControl::grant_emergency_access(
+            RuntimeOrigin::signed(DOCTOR),
+            PATIENT,
+            [2u8; 32],
+        ));
+
+        // First grant's queue entry is drained, re-grant expires one block later
+        run_to_block(DOCTOR_ACCESS_DURATION + 2);
+        assert!(AccessControl::has_valid_access(&PATIENT, &DOCTOR));
+        assert_eq!(AccessControl::access_count(PATIENT), 1);
+
+        run_to_block(DOCTOR_ACCESS_DURATION + 3);
+        assert!(AccessControl::active_access(PATIENT, DOCTOR).is_none());
+        assert_eq!(AccessControl::access_count(PATIENT), 0);
+    });
+}
+
+/// Test a grant whose expiry block is full is still granted and queued later
+#[test]
+fn grant_spills_into_next_block_when_expiry_queue_full() {
+    new_test_ext_with_roles().execute_with(|| {
+        System::set_block_number(1);
+        // Rule 2: bounded by MaxExpiriesPerBlock
+        for patient in 0..MAX_EXPIRIES_PER_BLOCK as u64 {
+            assert_ok!(AccessControl::grant_emergency_access(
+                RuntimeOrigin::signed(DOCTOR),
+                1_000 + patient,
+                [1u8; 32],
+            ));
+        }
+        let expires_at = 1 + DOCTOR_ACCESS_DURATION;
+
+        // Nurse grant expires at the same, already full block
+        run_to_block(expires_at - NURSE_ACCESS_DURATION);
+        assert_ok!(AccessControl::grant_emergency_access(
+            RuntimeOrigin::signed(NURSE),
+            PATIENT,
+            [1u8; 32],
+        ));
+        let access = AccessControl::active_access(PATIENT, NURSE).unwrap();
+        assert_eq!(access.expires_at, Some(expires_at));
+        assert_eq!(
+            ExpiryQueue::<Test>::get(expires_at + 1).into_inner(),
+            vec![(PATIENT, NURSE)]
+        );
+
+        // Invalid from its own expiry block, removed one block later than usual
+        run_to_block(expires_at + 1);
+        assert!(!AccessControl::has_valid_access(&PATIENT, &NURSE));
+        assert!(AccessControl::active_access(PATIENT, NURSE).is_some());
+
+        run_to_block(expires_at + 2);
+        assert!(AccessControl::active_access(PATIENT, NURSE).is_none());
+        assert_eq!(AccessControl::access_count(PATIENT), 0);
+    });
+}
+
+// =============================================================================
+// Patient-Granted Access Tests
+// =============================================================================
+
+/// Test patient grants time-limited Regular access to their doctor
+#[test]
+fn grant_access_by_patient_works() {
+    new_test_ext_with_roles().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(AccessControl::grant_access(
+            RuntimeOrigin::signed(PATIENT),
+            DOCTOR,
+            AccessType::Regular,
+            Some(1_000),
+        ));
+
+        let access = AccessControl::active_access(PATIENT, DOCTOR).unwrap();
+        assert!(matches!(access.access_type, AccessType::Regular));
+        assert_eq!(access.expires_at, Some(1_001));
+        assert!(AccessControl::has_valid_access(&PATIENT, &DOCTOR));
+        assert_eq!(AccessControl::access_count(PATIENT), 1);
+        System::assert_last_event(
+            Event::AccessGranted {
+                patient: PATIENT,
+                accessor: DOCTOR,
+                access_type: AccessType::Regular,
+                expires_at: Some(1_001),
+            }
+            .into(),
+        );
+
+        // Expires automatically like emergency access
+        run_to_block(1_002);
+        assert!(!AccessControl::has_valid_access(&PATIENT, &DOCTOR));
+        assert_eq!(AccessControl::access_count(PATIENT), 0);
+    });
+}
+
+/// Test indefinite Full access lasts until the patient revokes it
+#[test]
+fn grant_indefinite_access_until_revoked() {
+    new_test_ext_with_roles().execute_with(|| {
+        assert_ok!(AccessControl::grant_access(
+            RuntimeOrigin::signed(PATIENT),
+            DOCTOR,
+            AccessType::Full,
+            None,
+        ));
+
+        System::set_block_number(1_000_000);
+        assert!(AccessControl::has_valid_access(&PATIENT, &DOCTOR));
+
+        assert_ok!(AccessControl::revoke_access(
+            RuntimeOrigin::signed(PATIENT),
+            PATIENT,
+            DOCTOR,
+        ));
+        assert!(!AccessControl::has_valid_access(&PATIENT, &DOCTOR));
+        assert_eq!(AccessControl::access_count(PATIENT), 0);
+    });
+}
+
+/// Test grants stored before v1 keep their expiry block after the migration
+#[test]
+fn storage_migration_makes_expiry_optional() {
+    use crate::{
+        migrations::v1::{MigrateToV1, OldAccessLog},
+        ActiveAccess,
+    };
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+    new_test_ext_with_roles().execute_with(|| {
+        let old = OldAccessLog::<Test> {
+            accessor: DOCTOR,
+            access_type: AccessType::Emergency,
+            granted_at: 2,
+            expires_at: 14_402,
+            reason_hash: [7u8; 32],
+            revoked: false,
+        };
+        frame_support::storage::unhashed::put(
+            &ActiveAccess::<Test>::hashed_key_for(PATIENT, DOCTOR),
+            &old,
+        );
+        StorageVersion::new(0).put::<AccessControl>();
+
+        MigrateToV1::<Test>::on_runtime_upgrade();
+
+        let access = AccessControl::active_access(PATIENT, DOCTOR).unwrap();
+        assert!(matches!(access.access_type, AccessType::Emergency));
+        assert_eq!(access.granted_at, 2);
+        assert_eq!(access.expires_at, Some(14_402));
+        assert_eq!(access.reason_hash, [7u8; 32]);
+        assert!(!access.revoked);
+        assert_eq!(
+            AccessControl::on_chain_storage_version(),
+            StorageVersion::new(1)
+        );
+    });
+}
+
+/// Test only patients can grant access, and only to healthcare providers
+#[test]
+fn grant_access_fails_for_invalid_parties() {
+    new_test_ext_with_roles().execute_with(|| {
+        assert_noop!(
+            AccessControl::grant_access(
+                RuntimeOrigin::signed(DOCTOR),
+                NURSE,
+                AccessType::Regular,
+                None,
+            ),
+            Error::<Test>::NotAuthorized
+        );
+        assert_noop!(
+            AccessControl::grant_access(
+                RuntimeOrigin::signed(PATIENT),
+                PATIENT,
+                AccessType::Regular,
+                None,
+            ),
+            Error::<Test>::NotAuthorized
+        );
+        assert_noop!(
+            AccessControl::grant_access(
+                RuntimeOrigin::signed(PATIENT),
+                PRIVACY_OFFICER,
+                AccessType::Regular,
+                None,
+            ),
+            Error::<Test>::NotHealthcareProvider
+        );
+    });
+}
+
+/// Test lab technicians and pharmacists can be granted access (they hold ViewRecords)
+#[test]
+fn grant_access_to_lab_tech_and_pharmacist_works() {
+    new_test_ext_with_roles().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(AccessControl::grant_access(
+            RuntimeOrigin::signed(PATIENT),
+            LAB_TECH,
+            AccessType::Regular,
+            Some(100),
+        ));
+        assert_ok!(AccessControl::grant_access(
+            RuntimeOrigin::signed(PATIENT),
+            PHARMACIST,
+            AccessType::Regular,
+            None,
+        ));
+
+        assert!(AccessControl::has_valid_access(&PATIENT, &LAB_TECH));
+        assert!(AccessControl::has_valid_access(&PATIENT, &PHARMACIST));
+        assert_eq!(AccessControl::access_count(PATIENT), 2);
+    });
+}
+
+/// Test patients cannot grant Emergency access or zero-length access
+#[test]
+fn grant_access_fails_for_invalid_terms() {
+    new_test_ext_with_roles().execute_with(|| {
+        assert_noop!(
+            AccessControl::grant_access(
+                RuntimeOrigin::signed(PATIENT),
+                DOCTOR,
+                AccessType::Emergency,
+                None,
+            ),
+            Error::<Test>::InvalidAccessType
+        );
+        assert_noop!(
+            AccessControl::grant_access(
+                RuntimeOrigin::signed(PATIENT),
+                DOCTOR,
+                AccessType::Regular,
+                Some(0),
+            ),
+            Error::<Test>::InvalidDuration
+        );
+    });
+}
+
+/// Test patient cannot grant over an existing emergency access
+#[test]
+fn grant_access_fails_if_exists() {
+    new_test_ext_with_roles().execute_with(|| {
+        assert_ok!(AccessControl::grant_emergency_access(
+            RuntimeOrigin::signed(DOCTOR),
+            PATIENT,
+            [1u8; 32],
+        ));
+
+        assert_noop!(
+            AccessControl::grant_access(
+                RuntimeOrigin::signed(PATIENT),
+                DOCTOR,
+                AccessType::Full,
+                None,
+            ),
+            Error::<Test>::AccessAlreadyGranted
+        );
+    });
+}
+
+// =============================================================================
+// Guardian Tests
+// =============================================================================
+
+/// Scopes allowing a guardian to read records only
+const VIEW_ONLY: GuardianScopes = GuardianScopes {
+    grant_access: false,
+    revoke_access: false,
+    view_records: true,
+};
+
+/// Test providers appoint a parent through `AdminOrigin`, who can act within their scopes
+#[test]
+fn appoint_guardian_works() {
+    new_test_ext_with_roles().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(AccessControl::appoint_guardian(
+            RuntimeOrigin::root(),
+            PATIENT,
+            GUARDIAN,
+            GuardianRelationship::Parent,
+            GuardianScopes::ALL,
+            Some(500),
+        ));
+
+        let guardianship = AccessControl::guardianship(PATIENT, GUARDIAN).unwrap();
+        assert_eq!(guardianship.relationship, GuardianRelationship::Parent);
+        assert_eq!(guardianship.appointed_by, None);
+        assert_eq!(guardianship.expires_at, Some(501));
+        assert_eq!(AccessControl::guardian_count(PATIENT), 1);
+        assert!(AccessControl::is_guardian(
+            &PATIENT,
+            &GUARDIAN,
+            GuardianScope::ViewRecords
+        ));
+        System::assert_last_event(
+            Event::GuardianAppointed {
+                patient: PATIENT,
+                guardian: GUARDIAN,
+                relationship: GuardianRelationship::Parent,
+                scopes: GuardianScopes::ALL,
+                appointed_by: None,
+                expires_at: Some(501),
+            }
+            .into(),
+        );
+
+        // Appointment lapses after its expiry block
+        System::set_block_number(502);
+        assert!(!AccessControl::is_guardian(
+            &PATIENT,
+            &GUARDIAN,
+            GuardianScope::ViewRecords
+        ));
+    });
+}
+
+/// Test only the patient or `AdminOrigin` can appoint, within bounds
+#[test]
+fn appoint_guardian_fails_for_invalid_terms() {
+    new_test_ext_with_roles().execute_with(|| {
+        let appoint = |who: u64, patient: u64, guardian: u64, scopes: GuardianScopes| {
+            AccessControl::appoint_guardian(
+                RuntimeOrigin::signed(who),
+                patient,
+                guardian,
+                GuardianRelationship::NextOfKin,
+                scopes,
+                None,
+            )
+        };
+
+        // Signed providers cannot appoint, even those who register patients
+        assert_noop!(
+            appoint(PHARMACIST, PATIENT, GUARDIAN, GuardianScopes::ALL),
+            Error::<Test>::NotAuthorized
+        );
+        assert_noop!(
+            appoint(DOCTOR, PATIENT, GUARDIAN, GuardianScopes::ALL),
+            Error::<Test>::NotAuthorized
+        );
+        // Only patients get guardians
+        assert_noop!(
+            AccessControl::appoint_guardian(
+                RuntimeOrigin::root(),
+                NURSE,
+                GUARDIAN,
+                GuardianRelationship::Parent,
+                GuardianScopes::ALL,
+                None,
+            ),
+            Error::<Test>::NotAuthorized
+        );
+        // A patient cannot be their own guardian
+        assert_noop!(
+            appoint(PATIENT, PATIENT, PATIENT, GuardianScopes::ALL),
+            Error::<Test>::CannotAppointSelf
+        );
+        // Providers cannot be guardians, clinical or not
+        assert_noop!(
+            appoint(PATIENT, PATIENT, DOCTOR, GuardianScopes::ALL),
+            Error::<Test>::GuardianIsProvider
+        );
+        assert_noop!(
+            appoint(PATIENT, PATIENT, PRIVACY_OFFICER, VIEW_ONLY),
+            Error::<Test>::GuardianIsProvider
+        );
+        assert_noop!(
+            appoint(PATIENT, PATIENT, GUARDIAN, GuardianScopes::default()),
+            Error::<Test>::NoGuardianScopes
+        );
+        assert_noop!(
+            AccessControl::appoint_guardian(
+                RuntimeOrigin::signed(PATIENT),
+                PATIENT,
+                GUARDIAN,
+                GuardianRelationship::HealthcareProxy,
+                GuardianScopes::ALL,
+                Some(0),
+            ),
+            Error::<Test>::InvalidDuration
+        );
+
+        assert_ok!(appoint(PATIENT, PATIENT, GUARDIAN, GuardianScopes::ALL));
+        assert_noop!(
+            AccessControl::appoint_guardian(
+                RuntimeOrigin::root(),
+                PATIENT,
+                GUARDIAN,
+                GuardianRelationship::Parent,
+                GuardianScopes::ALL,
+                None,
+            ),
+            Error::<Test>::GuardianAlreadyAppointed
+        );
+
+        // Rule 2: bounded by MaxGuardians
+        assert_ok!(appoint(PATIENT, PATIENT, GUARDIAN + 1, VIEW_ONLY));
+        assert_eq!(AccessControl::guardian_count(PATIENT), MAX_GUARDIANS);
+        assert_noop!(
+            appoint(PATIENT, PATIENT, GUARDIAN + 2, VIEW_ONLY),
+            Error::<Test>::TooManyGuardians
+        );
+    });
+}
+
+/// Test guardians grant and revoke provider access on the patient's behalf
+#[test]
+fn guardian_grants_and_revokes_access() {
+    new_test_ext_with_roles().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(AccessControl::appoint_guardian(
+            RuntimeOrigin::root(),
+            PATIENT,
+            GUARDIAN,
+            GuardianRelationship::LegalGuardian,
+            GuardianScopes::ALL,
+            None,
+        ));
+
+        assert_ok!(AccessControl::grant_access_as_guardian(
+            RuntimeOrigin::signed(GUARDIAN),
+            PATIENT,
+            NURSE,
+            AccessType::Regular,
+            None,
+        ));
+        assert!(AccessControl::has_valid_access(&PATIENT, &NURSE));
+        System::assert_last_event(
+            Event::AccessGrantedByGuardian {
+                patient: PATIENT,
+                guardian: GUARDIAN,
+                accessor: NURSE,
+            }
+            .into(),
+        );
+
+        // Emergency access taken by a provider can be revoked by the guardian too
+        assert_ok!(AccessControl::grant_emergency_access(
+            RuntimeOrigin::signed(DOCTOR),
+            PATIENT,
+            [1u8; 32],
+        ));
+        assert_ok!(AccessControl::revoke_access(
+            RuntimeOrigin::signed(GUARDIAN),
+            PATIENT,
+            DOCTOR,
+        ));
+        assert_ok!(AccessControl::revoke_access(
+            RuntimeOrigin::signed(GUARDIAN),
+            PATIENT,
+            NURSE,
+        ));
+        assert!(!AccessControl::has_valid_access(&PATIENT, &DOCTOR));
+        assert!(!AccessControl::has_valid_access(&PATIENT, &NURSE));
+        assert_eq!(AccessControl::access_count(PATIENT), 0);
+    });
+}
+
+/// Test guardians can only act within their scopes and while appointed
+#[test]
+fn guardian_actions_respect_scopes_and_expiry() {
+    new_test_ext_with_roles().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(AccessControl::appoint_guardian(
+            RuntimeOrigin::signed(PATIENT),
+            PATIENT,
+            GUARDIAN,
+            GuardianRelationship::HealthcareProxy,
+            VIEW_ONLY,
+            Some(10),
+        ));
+        assert_ok!(AccessControl::grant_emergency_access(
+            RuntimeOrigin::signed(NURSE),
+            PATIENT,
+            [1u8; 32],
+        ));
+
+        assert_noop!(
+            AccessControl::grant_access_as_guardian(
+                RuntimeOrigin::signed(GUARDIAN),
+                PATIENT,
+                DOCTOR,
+                AccessType::Full,
+                None,
+            ),
+            Error::<Test>::GuardianScopeMissing
+        );
+        assert_noop!(
+            AccessControl::revoke_access(RuntimeOrigin::signed(GUARDIAN), PATIENT, NURSE),
+            Error::<Test>::NotAuthorized
+        );
+        // Not a guardian of anyone else
+        assert_noop!(
+            AccessControl::grant_access_as_guardian(
+                RuntimeOrigin::signed(UNAUTHORIZED),
+                PATIENT,
+                DOCTOR,
+                AccessType::Full,
+                None,
+            ),
+            Error::<Test>::GuardianNotFound
+        );
+
+        assert_ok!(AccessControl::appoint_guardian(
+            RuntimeOrigin::signed(PATIENT),
+            PATIENT,
+            GUARDIAN + 1,
+            GuardianRelationship::NextOfKin,
+            GuardianScopes::ALL,
+            Some(10),
+        ));
+
+        System::set_block_number(12);
+        assert_noop!(
+            AccessControl::grant_access_as_guardian(
+                RuntimeOrigin::signed(GUARDIAN + 1),
+                PATIENT,
+                NURSE,
+                AccessType::Full,
+                None,
+            ),
+            Error::<Test>::GuardianshipExpired
+        );
+    });
+}
+
+/// Test only the patient or `AdminOrigin` can remove a guardian, expired or not
+#[test]
+fn remove_guardian_works() {
+    new_test_ext_with_roles().execute_with(|| {
+        System::set_block_number(1);
+        let appoint = |guardian: u64, duration: Option<u64>| {
+            AccessControl::appoint_guardian(
+                RuntimeOrigin::signed(PATIENT),
+                PATIENT,
+                guardian,
+                GuardianRelationship::NextOfKin,
+                VIEW_ONLY,
+                duration,
+            )
+        };
+
+        assert_ok!(appoint(GUARDIAN, Some(10)));
+        // Neither strangers, the guardian nor signed providers can remove it
+        for who in [UNAUTHORIZED, GUARDIAN, DOCTOR] {
+            assert_noop!(
+                AccessControl::remove_guardian(RuntimeOrigin::signed(who), PATIENT, GUARDIAN),
+                Error::<Test>::NotAuthorized
+            );
+        }
+        assert_ok!(AccessControl::remove_guardian(
+            RuntimeOrigin::signed(PATIENT),
+            PATIENT,
+            GUARDIAN,
+        ));
+        assert!(AccessControl::guardianship(PATIENT, GUARDIAN).is_none());
+        assert_eq!(AccessControl::guardian_count(PATIENT), 0);
+        System::assert_last_event(
+            Event::GuardianRemoved {
+                patient: PATIENT,
+                guardian: GUARDIAN,
+                removed_by: Some(PATIENT),
+            }
+            .into(),
+        );
+        assert_noop!(
+            AccessControl::remove_guardian(RuntimeOrigin::signed(PATIENT), PATIENT, GUARDIAN),
+            Error::<Test>::GuardianNotFound
+        );
+
+        // Expiry does not open removal to anyone else
+        assert_ok!(appoint(GUARDIAN, Some(10)));
+        System::set_block_number(12);
+        assert_noop!(
+            AccessControl::remove_guardian(RuntimeOrigin::signed(UNAUTHORIZED), PATIENT, GUARDIAN),
+            Error::<Test>::NotAuthorized
+        );
+        assert_ok!(AccessControl::remove_guardian(
+            RuntimeOrigin::root(),
+            PATIENT,
+            GUARDIAN,
+        ));
+        System::assert_last_event(
+            Event::GuardianRemoved {
+                patient: PATIENT,
+                guardian: GUARDIAN,
+                removed_by: None,
+            }
+            .into(),
+        );
+
+        // Appointments made through `AdminOrigin` are removed the same way
+        assert_ok!(AccessControl::appoint_guardian(
+            RuntimeOrigin::root(),
+            PATIENT,
+            GUARDIAN,
+            GuardianRelationship::LegalGuardian,
+            VIEW_ONLY,
+            None,
+        ));
+        assert_ok!(AccessControl::remove_guardian(
+            RuntimeOrigin::root(),
+            PATIENT,
+            GUARDIAN,
+        ));
+
+        let actions: Vec<AuditAction> = AccessControl::audit_log(&PATIENT, 0, MAX_AUDIT_ENTRIES)
+            .into_iter()
+            .map(|(_, entry)| entry.action)
+            .collect();
+        assert_eq!(
+            actions,
+            vec![
+                AuditAction::GuardianRemoved,
+                AuditAction::GuardianAppointed,
+                AuditAction::GuardianRemoved,
+                AuditAction::GuardianAppointed,
+                AuditAction::GuardianRemoved,
+            ]
+        );
+    });
+}
+
+// =============================================================================
+// Consent Directive Tests
+// =============================================================================
+
+/// Test the disclosure each viewer gets under default and restrictive directives
+#[test]
+fn consent_disclosure_matrix_works() {
+    let all = Disclosure {
+        emergency_subset: true,
+        sensitive_conditions: true,
+        organ_donor: true,
+        full_records: true,
+    };
+    let defaults = ConsentDirectives::default();
+    assert_eq!(defaults.disclosure(ConsentViewer::PatientOrGuardian), all);
+    assert_eq!(defaults.disclosure(ConsentViewer::TreatingProvider), all);
+    assert_eq!(defaults.disclosure(ConsentViewer::EmergencyProvider), all);
+    assert_eq!(
+        defaults.disclosure(ConsentViewer::Researcher),
+        Disclosure::default()
+    );
+
+    let restrictive = ConsentDirectives {
+        share_emergency_subset: true,
+        restrict_sensitive_to_treating: true,
+        allow_research_use: true,
+        disclose_organ_donor: false,
+    };
+    assert_eq!(
+        restrictive.disclosure(ConsentViewer::PatientOrGuardian),
+        all
+    );
+    assert_eq!(
+        restrictive.disclosure(ConsentViewer::TreatingProvider),
+        Disclosure {
+            organ_donor: false,
+            ..all
+        }
+    );
+    let emergency = Disclosure {
+        sensitive_conditions: false,
+        organ_donor: false,
+        ..all
+    };
+    assert_eq!(
+        restrictive.disclosure(ConsentViewer::EmergencyProvider),
+        emergency
+    );
+    assert_eq!(restrictive.disclosure(ConsentViewer::Researcher), emergency);
+
+    let withheld = ConsentDirectives {
+        share_emergency_subset: false,
+        ..Default::default()
+    };
+    assert_eq!(
+        withheld.disclosure(ConsentViewer::EmergencyProvider),
+        Disclosure::default()
+    );
+    assert!(
+        withheld
+            .disclosure(ConsentViewer::TreatingProvider)
+            .full_records
+    );
+}
+
+/// Test patients and guardians with GrantAccess set directives, nobody else
+#[test]
+fn set_consent_directives_works() {
+    new_test_ext_with_roles().execute_with(|| {
+        System::set_block_number(1);
+        let directives = ConsentDirectives {
+            restrict_sensitive_to_treating: true,
+            ..Default::default()
+        };
+
+        assert_noop!(
+            AccessControl::set_consent_directives(
+                RuntimeOrigin::signed(DOCTOR),
+                PATIENT,
+                directives
+            ),
+            Error::<Test>::NotAuthorized
+        );
+        assert_noop!(
+            AccessControl::set_consent_directives(RuntimeOrigin::signed(NURSE), NURSE, directives),
+            Error::<Test>::NotAuthorized
+        );
+
+        assert_ok!(AccessControl::set_consent_directives(
+            RuntimeOrigin::signed(PATIENT),
+            PATIENT,
+            directives
+        ));
+        assert_eq!(AccessControl::consent_directives(&PATIENT), directives);
+        let record = AccessControl::consent_record(PATIENT).unwrap();
+        assert_eq!(record.updated_by, PATIENT);
+        assert_eq!(record.updated_at, 1);
+        System::assert_last_event(
+            Event::ConsentDirectivesUpdated {
+                patient: PATIENT,
+                directives,
+                updated_by: PATIENT,
+            }
+            .into(),
+        );
+
+        // Guardians need GrantAccess, not just ViewRecords
+        assert_ok!(AccessControl::appoint_guardian(
+            RuntimeOrigin::signed(PATIENT),
+            PATIENT,
+            GUARDIAN,
+            GuardianRelationship::HealthcareProxy,
+            VIEW_ONLY,
+            None,
+        ));
+        assert_noop!(
+            AccessControl::set_consent_directives(
+                RuntimeOrigin::signed(GUARDIAN),
+                PATIENT,
+                ConsentDirectives::default()
+            ),
+            Error::<Test>::NotAuthorized
+        );
+        assert_ok!(AccessControl::remove_guardian(
+            RuntimeOrigin::signed(PATIENT),
+            PATIENT,
+            GUARDIAN,
+        ));
+        assert_ok!(AccessControl::appoint_guardian(
+            RuntimeOrigin::signed(PATIENT),
+            PATIENT,
+            GUARDIAN,
+            GuardianRelationship::HealthcareProxy,
+            GuardianScopes::ALL,
+            None,
+        ));
+        assert_ok!(AccessControl::set_consent_directives(
+            RuntimeOrigin::signed(GUARDIAN),
+            PATIENT,
+            ConsentDirectives::default()
+        ));
+        assert_eq!(
+            AccessControl::audit_entry(PATIENT, AccessControl::audit_log_length(PATIENT) - 1)
+                .unwrap()
+                .action,
+            AuditAction::ConsentUpdated
+        );
+    });
+}
+
+/// Test emergency access is refused once the patient withholds the emergency subset
+#[test]
+fn emergency_access_respects_consent() {
+    new_test_ext_with_roles().execute_with(|| {
+        assert_ok!(AccessControl::set_consent_directives(
+            RuntimeOrigin::signed(PATIENT),
+            PATIENT,
+            ConsentDirectives {
+                share_emergency_subset: false,
+                ..Default::default()
+            }
+        ));
+
+        assert_noop!(
+            AccessControl::grant_emergency_access(
+                RuntimeOrigin::signed(DOCTOR),
+                PATIENT,
+                [1u8; 32]
+            ),
+            Error::<Test>::ConsentWithheld
+        );
+
+        // The patient can still grant their treating providers access
+        assert_ok!(AccessControl::grant_access(
+            RuntimeOrigin::signed(PATIENT),
+            DOCTOR,
+            AccessType::Regular,
+            None,
+        ));
+        assert!(AccessControl::disclosure(&PATIENT, &DOCTOR).full_records);
+        assert!(!AccessControl::disclosure(&PATIENT, &NURSE).full_records);
+    });
+}
+
+/// Test only Regular or Full grants make a treating provider
+#[test]
+fn consent_viewer_depends_on_grant_type() {
+    new_test_ext_with_roles().execute_with(|| {
+        assert_eq!(
+            AccessControl::consent_viewer(&PATIENT, &PATIENT),
+            ConsentViewer::PatientOrGuardian
+        );
+        assert_eq!(
+            AccessControl::consent_viewer(&PATIENT, &DOCTOR),
+            ConsentViewer::EmergencyProvider
+        );
+
+        assert_ok!(AccessControl::grant_emergency_access(
+            RuntimeOrigin::signed(NURSE),
+            PATIENT,
+            [1u8; 32]
+        ));
+        assert_eq!(
+            AccessControl::consent_viewer(&PATIENT, &NURSE),
+            ConsentViewer::EmergencyProvider
+        );
+
+        assert_ok!(AccessControl::grant_access(
+            RuntimeOrigin::signed(PATIENT),
+            DOCTOR,
+            AccessType::Full,
+            None,
+        ));
+        assert_eq!(
+            AccessControl::consent_viewer(&PATIENT, &DOCTOR),
+            ConsentViewer::TreatingProvider
+        );
+        assert_ok!(AccessControl::revoke_access(
+            RuntimeOrigin::signed(PATIENT),
+            PATIENT,
+            DOCTOR,
+        ));
+        assert_eq!(
+            AccessControl::consent_viewer(&PATIENT, &DOCTOR),
+            ConsentViewer::EmergencyProvider
+        );
+    });
+}
+
+// =============================================================================
+// Break-Glass Review Tests
+// =============================================================================
+
+/// Test every emergency grant opens a review due after JustificationPeriod
+#[test]
+fn emergency_grant_opens_review() {
+    new_test_ext_with_roles().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(AccessControl::grant_emergency_access(
+            RuntimeOrigin::signed(DOCTOR),
+            PATIENT,
+            [1u8; 32],
+        ));
+
+        let review = AccessControl::break_glass_review(0).unwrap();
+        assert_eq!(review.patient, PATIENT);
+        assert_eq!(review.accessor, DOCTOR);
+        assert_eq!(review.reason_hash, [1u8; 32]);
+        assert_eq!(review.justify_by, 1 + JUSTIFICATION_PERIOD);
+        assert_eq!(review.status, ReviewStatus::AwaitingJustification);
+        assert_eq!(AccessControl::next_review_id(), 1);
+        assert_eq!(
+            AccessControl::review_deadlines(1 + JUSTIFICATION_PERIOD).into_inner(),
+            vec![0]
+        );
+        System::assert_has_event(
+            Event::BreakGlassReviewOpened {
+                review_id: 0,
+                patient: PATIENT,
+                accessor: DOCTOR,
+                justify_by: 1 + JUSTIFICATION_PERIOD,
+            }
+            .into(),
+        );
+    });
+}
+
+/// Test an emergency grant is refused once review ids are exhausted
+#[test]
+fn emergency_grant_fails_when_review_ids_exhausted() {
+    new_test_ext_with_roles().execute_with(|| {
+        System::set_block_number(1);
+        NextReviewId::<Test>::put(u32::MAX);
+
+        assert_noop!(
+            AccessControl::grant_emergency_access(
+                RuntimeOrigin::signed(DOCTOR),
+                PATIENT,
+                [1u8; 32],
+            ),
+            Error::<Test>::ReviewIdOverflow
+        );
+        assert!(!AccessControl::has_valid_access(&PATIENT, &DOCTOR));
+    });
+}
+
+/// Test only the accessor justifies, once, before the deadline
+#[test]
+fn submit_justification_works() {
+    new_test_ext_with_roles().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(AccessControl::grant_emergency_access(
+            RuntimeOrigin::signed(DOCTOR),
+            PATIENT,
+            [1u8; 32],
+        ));
+
+        assert_noop!(
+            AccessControl::submit_justification(RuntimeOrigin::signed(NURSE), 0, [2u8; 32]),
+            Error::<Test>::NotAuthorized
+        );
+        assert_noop!(
+            AccessControl::submit_justification(RuntimeOrigin::signed(DOCTOR), 1, [2u8; 32]),
+            Error::<Test>::ReviewNotFound
+        );
+
+        assert_ok!(AccessControl::submit_justification(
+            RuntimeOrigin::signed(DOCTOR),
+            0,
+            [2u8; 32]
+        ));
+        let review = AccessControl::break_glass_review(0).unwrap();
+        assert_eq!(review.status, ReviewStatus::Justified);
+        assert_eq!(review.justification_hash, Some([2u8; 32]));
+        System::assert_last_event(
+            Event::EmergencyAccessJustified {
+                review_id: 0,
+                accessor: DOCTOR,
+            }
+            .into(),
+        );
+        let (_, entry) = AccessControl::audit_log(&PATIENT, 0, MAX_AUDIT_ENTRIES)
+            .pop()
+            .unwrap();
+        assert_eq!(entry.action, AuditAction::AccessJustified);
+        assert_eq!(entry.reason_hash, [2u8; 32]);
+
+        assert_noop!(
+            AccessControl::submit_justification(RuntimeOrigin::signed(DOCTOR), 0, [3u8; 32]),
+            Error::<Test>::NotAwaitingJustification
+        );
+
+        // Late justifications are rejected even before on_initialize runs
+        assert_ok!(AccessControl::grant_emergency_access(
+            RuntimeOrigin::signed(NURSE),
+            PATIENT,
+            [1u8; 32],
+        ));
+        System::set_block_number(JUSTIFICATION_PERIOD + 2);
+        assert_noop!(
+            AccessControl::submit_justification(RuntimeOrigin::signed(NURSE), 1, [2u8; 32]),
+            Error::<Test>::JustificationDeadlinePassed
+        );
+    });
+}
+
+/// Test a grant left unjustified raises an alert and counts as misuse
+#[test]
+fn unjustified_grant_counts_as_misuse() {
+    new_test_ext_with_roles().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(AccessControl::grant_emergency_access(
+            RuntimeOrigin::signed(DOCTOR),
+            PATIENT,
+            [1u8; 32],
+        ));
+        assert_ok!(AccessControl::grant_emergency_access(
+            RuntimeOrigin::signed(NURSE),
+            PATIENT,
+            [1u8; 32],
+        ));
+        assert_ok!(AccessControl::submit_justification(
+            RuntimeOrigin::signed(NURSE),
+            1,
+            [2u8; 32]
+        ));
+
+        run_to_block(JUSTIFICATION_PERIOD + 1);
+        assert_eq!(
+            AccessControl::break_glass_review(0).unwrap().status,
+            ReviewStatus::AwaitingJustification
+        );
+
+        run_to_block(JUSTIFICATION_PERIOD + 2);
+        assert_eq!(
+            AccessControl::break_glass_review(0).unwrap().status,
+            ReviewStatus::Unjustified
+        );
+        assert_eq!(
+            AccessControl::break_glass_review(1).unwrap().status,
+            ReviewStatus::Justified
+        );
+        assert_eq!(AccessControl::misuse_count(DOCTOR), 1);
+        assert_eq!(AccessControl::misuse_count(NURSE), 0);
+        assert!(AccessControl::review_deadlines(1 + JUSTIFICATION_PERIOD).is_empty());
+        System::assert_has_event(
+            Event::EmergencyAccessUnjustified {
+                review_id: 0,
+                patient: PATIENT,
+                accessor: DOCTOR,
+                misuse_count: 1,
+            }
+            .into(),
+        );
+
+        assert_noop!(
+            AccessControl::submit_justification(RuntimeOrigin::signed(DOCTOR), 0, [2u8; 32]),
+            Error::<Test>::NotAwaitingJustification
+        );
+    });
+}
+
+/// Test Admins and Privacy Officers approve or flag justified grants, closing the review
+#[test]
+fn review_emergency_access_works() {
+    new_test_ext_with_roles().execute_with(|| {
+        System::set_block_number(1);
+        // Rule 2: two grants
+        for accessor in [DOCTOR, NURSE] {
+            assert_ok!(AccessControl::grant_emergency_access(
+                RuntimeOrigin::signed(accessor),
+                PATIENT,
+                [1u8; 32],
+            ));
+        }
+
+        // Nothing to review until the accessor has had their say
+        assert_noop!(
+            AccessControl::review_emergency_access(
+                RuntimeOrigin::signed(PRIVACY_OFFICER),
+                0,
+                ReviewVerdict::Approve
+            ),
+            Error::<Test>::ReviewNotReady
+        );
+
+        assert_ok!(AccessControl::submit_justification(
+            RuntimeOrigin::signed(DOCTOR),
+            0,
+            [2u8; 32]
+        ));
+        assert_ok!(AccessControl::submit_justification(
+            RuntimeOrigin::signed(NURSE),
+            1,
+            [2u8; 32]
+        ));
+
+        assert_ok!(AccessControl::review_emergency_access(
+            RuntimeOrigin::signed(PRIVACY_OFFICER),
+            0,
+            ReviewVerdict::Approve
+        ));
+        assert!(AccessControl::break_glass_review(0).is_none());
+        assert_eq!(AccessControl::misuse_count(DOCTOR), 0);
+        let (_, entry) = AccessControl::audit_log(&PATIENT, 0, MAX_AUDIT_ENTRIES)
+            .pop()
+            .unwrap();
+        assert_eq!(entry.who, PRIVACY_OFFICER);
+        assert_eq!(
+            entry.action,
+            AuditAction::AccessReviewed(ReviewVerdict::Approve)
+        );
+
+        assert_ok!(AccessControl::review_emergency_access(
+            RuntimeOrigin::signed(ADMIN),
+            1,
+            ReviewVerdict::Flag
+        ));
+        assert!(AccessControl::break_glass_review(1).is_none());
+        assert_eq!(AccessControl::misuse_count(NURSE), 1);
+        System::assert_last_event(
+            Event::EmergencyAccessFlagged {
+                review_id: 1,
+                patient: PATIENT,
+                accessor: NURSE,
+                reviewer: ADMIN,
+                misuse_count: 1,
+            }
+            .into(),
+        );
+        let (_, entry) = AccessControl::audit_log(&PATIENT, 0, MAX_AUDIT_ENTRIES)
+            .pop()
+            .unwrap();
+        assert_eq!(entry.who, ADMIN);
+        assert_eq!(
+            entry.action,
+            AuditAction::AccessReviewed(ReviewVerdict::Flag)
+        );
+
+        assert_noop!(
+            AccessControl::review_emergency_access(
+                RuntimeOrigin::signed(PRIVACY_OFFICER),
+                1,
+                ReviewVerdict::Approve
+            ),
+            Error::<Test>::ReviewNotFound
+        );
+
+        // Closed reviews are gone by the time their deadline is processed
+        run_to_block(JUSTIFICATION_PERIOD + 2);
+        assert_eq!(AccessControl::misuse_count(DOCTOR), 0);
+        assert_eq!(AccessControl::misuse_count(NURSE), 1);
+    });
+}
+
+/// Test reviewers need the permission, cannot review themselves, and can clear
+/// an unjustified grant
+#[test]
+fn review_emergency_access_checks_reviewer() {
+    new_test_ext_with_roles().execute_with(|| {
+        use crate::Permission;
+
+        assert!(Role::PrivacyOfficer.has_permission(Permission::ReviewEmergencyAccess));
+        assert!(!Role::PrivacyOfficer.has_permission(Permission::ViewRecords));
+        assert!(!Role::Doctor.has_permission(Permission::ReviewEmergencyAccess));
+
+        System::set_block_number(1);
+        assert_ok!(AccessControl::grant_emergency_access(
+            RuntimeOrigin::signed(ADMIN),
+            PATIENT,
+            [1u8; 32],
+        ));
+        run_to_block(JUSTIFICATION_PERIOD + 2);
+        assert_eq!(AccessControl::misuse_count(ADMIN), 1);
+
+        assert_noop!(
+            AccessControl::review_emergency_access(
+                RuntimeOrigin::signed(DOCTOR),
+                0,
+                ReviewVerdict::Approve
+            ),
+            Error::<Test>::InsufficientRole
+        );
+        assert_noop!(
+            AccessControl::review_emergency_access(
+                RuntimeOrigin::signed(ADMIN),
+                0,
+                ReviewVerdict::Approve
+            ),
+            Error::<Test>::CannotReviewOwnAccess
+        );
+        assert_noop!(
+            AccessControl::review_emergency_access(
+                RuntimeOrigin::signed(PRIVACY_OFFICER),
+                1,
+                ReviewVerdict::Approve
+            ),
+            Error::<Test>::ReviewNotFound
+        );
+
+        // Justified off-chain: approving takes the misuse count back
+        assert_ok!(AccessControl::review_emergency_access(
+            RuntimeOrigin::signed(PRIVACY_OFFICER),
+            0,
+            ReviewVerdict::Approve
+        ));
+        assert_eq!(AccessControl::misuse_count(ADMIN), 0);
+        assert!(AccessControl::break_glass_review(0).is_none());
+    });
+}
+
+/// Test a grant whose review deadline block is full is still granted and its review queued later
+#[test]
+fn grant_spills_review_deadline_when_queue_full() {
+    new_test_ext_with_roles().execute_with(|| {
+        System::set_block_number(1);
+        // Rule 2: bounded by MaxExpiriesPerBlock
+        for patient in 0..MAX_EXPIRIES_PER_BLOCK as u64 {
+            assert_ok!(AccessControl::grant_emergency_access(
+                RuntimeOrigin::signed(DOCTOR),
+                1_000 + patient,
+                [1u8; 32],
+            ));
+        }
+        let justify_by = 1 + JUSTIFICATION_PERIOD;
+        let review_id = MAX_EXPIRIES_PER_BLOCK;
+
+        // Nurse grants expire at another block but fall due at the same one
+        assert_ok!(AccessControl::grant_emergency_access(
+            RuntimeOrigin::signed(NURSE),
+            PATIENT,
+            [1u8; 32],
+        ));
+        assert!(AccessControl::has_valid_access(&PATIENT, &NURSE));
+        assert_eq!(
+            AccessControl::break_glass_review(review_id)
+                .unwrap()
+                .justify_by,
+            justify_by
+        );
+        assert_eq!(
+            ReviewDeadlines::<Test>::get(justify_by + 1).into_inner(),
+            vec![review_id]
+        );
+
+        // Justification closes at the deadline, the review is marked one block later than usual
+        run_to_block(justify_by + 1);
+        assert_noop!(
+            AccessControl::submit_justification(RuntimeOrigin::signed(NURSE), review_id, [2u8; 32]),
+            Error::<Test>::JustificationDeadlinePassed
+        );
+        assert_eq!(
+            AccessControl::break_glass_review(review_id).unwrap().status,
+            ReviewStatus::AwaitingJustification
+        );
+
+        run_to_block(justify_by + 2);
+        assert_eq!(
+            AccessControl::break_glass_review(review_id).unwrap().status,
+            ReviewStatus::Unjustified
+        );
+        assert_eq!(AccessControl::misuse_count(NURSE), 1);
+    });
+}
+
+/// Test anyone can mark an overdue review unjustified when its deadline could not be queued
+#[test]
+fn mark_review_unjustified_closes_overdue_reviews() {
+    new_test_ext_with_roles().execute_with(|| {
+        System::set_block_number(1);
+        let justify_by = 1 + JUSTIFICATION_PERIOD;
+        // Rule 2: bounded by MaxQueueSpill
+        for block in justify_by..=justify_by + MAX_QUEUE_SPILL as u64 {
+            ReviewDeadlines::<Test>::insert(
+                block,
+                BoundedVec::try_from(vec![u32::MAX; MAX_EXPIRIES_PER_BLOCK as usize]).unwrap(),
+            );
+        }
+
+        assert_ok!(AccessControl::grant_emergency_access(
+            RuntimeOrigin::signed(NURSE),
+            PATIENT,
+            [1u8; 32],
+        ));
+        assert!(!ReviewDeadlines::<Test>::get(justify_by).contains(&0));
+
+        System::set_block_number(justify_by);
+        assert_noop!(
+            AccessControl::mark_review_unjustified(RuntimeOrigin::signed(UNAUTHORIZED), 0),
+            Error::<Test>::JustificationNotOverdue
+        );
+
+        System::set_block_number(justify_by + 1);
+        assert_ok!(AccessControl::mark_review_unjustified(
+            RuntimeOrigin::signed(UNAUTHORIZED),
+            0
+        ));
+        assert_eq!(
+            AccessControl::break_glass_review(0).unwrap().status,
+            ReviewStatus::Unjustified
+        );
+        System::assert_last_event(
+            Event::EmergencyAccessUnjustified {
+                review_id: 0,
+                patient: PATIENT,
+                accessor: NURSE,
+                misuse_count: 1,
+            }
+            .into(),
+        );
+
+        assert_noop!(
+            AccessControl::mark_review_unjustified(RuntimeOrigin::signed(UNAUTHORIZED), 0),
+            Error::<Test>::NotAwaitingJustification
+        );
+        assert_noop!(
+            AccessControl::mark_review_unjustified(RuntimeOrigin::signed(UNAUTHORIZED), 1),
+            Error::<Test>::ReviewNotFound
+        );
+    });
+}
+
+// =============================================================================
+// Credential Tests
+// =============================================================================
+
+/// Test AdminOrigin records or renews a provider's credential
+#[test]
+fn register_credential_works() {
+    new_test_ext_with_roles().execute_with(|| {
+        System::set_block_number(1);
+        let genesis_warn_at = CREDENTIAL_VALID_UNTIL - CREDENTIAL_WARNING_PERIOD;
+        assert!(AccessControl::credential_warnings(genesis_warn_at).contains(&DOCTOR));
+
+        assert_ok!(AccessControl::register_credential(
+            RuntimeOrigin::root(),
+            DOCTOR,
+            [7u8; 32],
+            IssuingBody::MedicalCouncil,
+            1_000,
+            HOSPITAL,
+        ));
+
+        let credential = AccessControl::credential(DOCTOR).unwrap();
+        assert_eq!(credential.licence_id_hash, [7u8; 32]);
+        assert_eq!(credential.issuing_body, IssuingBody::MedicalCouncil);
+        assert_eq!(credential.valid_until, 1_000);
+        assert_eq!(credential.facility_id, HOSPITAL);
+        assert_eq!(
+            AccessControl::credential_warnings(1_000 - CREDENTIAL_WARNING_PERIOD).into_inner(),
+            vec![DOCTOR]
+        );
+        System::assert_last_event(
+            Event::CredentialRegistered {
+                provider: DOCTOR,
+                issuing_body: IssuingBody::MedicalCouncil,
+                valid_until: 1_000,
+                facility_id: HOSPITAL,
+            }
+            .into(),
+        );
+
+        // The renewal dropped the genesis credential's pending warning
+        assert!(!AccessControl::credential_warnings(genesis_warn_at).contains(&DOCTOR));
+        assert!(AccessControl::credential_warnings(genesis_warn_at).contains(&NURSE));
+
+        // Only AdminOrigin (not even a single Admin), only for licences still
+        // valid, and only at registered facilities
+        assert_noop!(
+            AccessControl::register_credential(
+                RuntimeOrigin::root(),
+                NURSE,
+                [8u8; 32],
+                IssuingBody::NursingCouncil,
+                1_000,
+                HOSPITAL,
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            AccessControl::register_credential(
+                RuntimeOrigin::root(),
+                NURSE,
+                [8u8; 32],
+                IssuingBody::NursingCouncil,
+                1,
+                HOSPITAL,
+            ),
+            Error::<Test>::CredentialAlreadyExpired
+        );
+        assert_noop!(
+            AccessControl::register_credential(
+                RuntimeOrigin::root(),
+                NURSE,
+                [8u8; 32],
+                IssuingBody::NursingCouncil,
+                1_000,
+                99,
+            ),
+            Error::<Test>::FacilityNotFound
+        );
+    });
+}
+
+/// Test a lapsed credential strips the role's permissions until renewed
+#[test]
+fn lapsed_credential_fails_role_checks() {
+    new_test_ext_with_roles().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(AccessControl::register_credential(
+            RuntimeOrigin::root(),
+            DOCTOR,
+            [7u8; 32],
+            IssuingBody::MedicalCouncil,
+            50,
+            HOSPITAL,
+        ));
+
+        System::set_block_number(50);
+        assert!(AccessControl::is_healthcare_provider(&DOCTOR));
+
+        System::set_block_number(51);
+        assert!(!AccessControl::has_valid_credential(&DOCTOR));
+        assert!(!AccessControl::is_healthcare_provider(&DOCTOR));
+        assert!(!AccessControl::is_doctor(&DOCTOR));
+        assert!(!AccessControl::can_edit_medical_records(&DOCTOR));
+        assert_eq!(AccessControl::get_role(&DOCTOR), Some(Role::Doctor));
+        assert_eq!(AccessControl::active_role(&DOCTOR, &PATIENT), None);
+        assert_noop!(
+            AccessControl::grant_emergency_access(
+                RuntimeOrigin::signed(DOCTOR),
+                PATIENT,
+                [1u8; 32]
+            ),
+            Error::<Test>::NotHealthcareProvider
+        );
+
+        // Other providers' credentials and unlicensed roles are unaffected
+        assert!(AccessControl::is_healthcare_provider(&NURSE));
+        assert!(AccessControl::is_admin(&ADMIN));
+
+        // A licensed role with no credential recorded grants nothing either
+        Credentials::<Test>::remove(NURSE);
+        assert!(!AccessControl::has_valid_credential(&NURSE));
+        assert!(!AccessControl::is_healthcare_provider(&NURSE));
+        assert_eq!(AccessControl::active_role(&NURSE, &PATIENT), None);
+
+        // Renewal restores the role
+        assert_ok!(AccessControl::register_credential(
+            RuntimeOrigin::root(),
+            DOCTOR,
+            [7u8; 32],
+            IssuingBody::MedicalCouncil,
+            1_000,
+            HOSPITAL,
+        ));
+        assert!(AccessControl::is_healthcare_provider(&DOCTOR));
+    });
+}
+
+/// Test providers are warned CredentialWarningPeriod blocks before expiry,
+/// unless the credential was renewed
+#[test]
+fn credential_expiry_warning_fires() {
+    new_test_ext_with_roles().execute_with(|| {
+        System::set_block_number(1);
+        let valid_until = 500;
+        // Rule 2: two providers
+        for provider in [DOCTOR, NURSE] {
+            assert_ok!(AccessControl::register_credential(
+                RuntimeOrigin::root(),
+                provider,
+                [7u8; 32],
+                IssuingBody::NursingCouncil,
+                valid_until,
+                HOSPITAL,
+            ));
+        }
+        // The nurse renews before the warning falls due
+        assert_ok!(AccessControl::register_credential(
+            RuntimeOrigin::root(),
+            NURSE,
+            [7u8; 32],
+            IssuingBody::NursingCouncil,
+            valid_until + 1_000,
+            HOSPITAL,
+        ));
+
+        let expiring = |provider| {
+            System::events().iter().any(|record| {
+                record.event
+                    == Event::CredentialExpiring {
+                        provider,
+                        valid_until,
+                    }
+                    .into()
+            })
+        };
+
+        run_to_block(valid_until - CREDENTIAL_WARNING_PERIOD - 1);
+        assert!(!expiring(DOCTOR));
+
+        run_to_block(valid_until - CREDENTIAL_WARNING_PERIOD);
+        assert!(expiring(DOCTOR));
+        assert!(!expiring(NURSE));
+        assert!(
+            AccessControl::credential_warnings(valid_until - CREDENTIAL_WARNING_PERIOD).is_empty()
+        );
+
+        // A credential recorded inside the warning period is flagged straight away
+        assert_ok!(AccessControl::register_credential(
+            RuntimeOrigin::root(),
+            PHARMACIST,
+            [9u8; 32],
+            IssuingBody::PharmacyCouncil,
+            valid_until,
+            HOSPITAL,
+        ));
+        assert!(expiring(PHARMACIST));
+    });
+}
+
+/// Test a full warning block never refuses a credential: the warning spills
+/// into the next block with room, and past the spill fires straight away
+#[test]
+fn credential_warning_spills_past_full_blocks() {
+    new_test_ext_with_roles().execute_with(|| {
+        System::set_block_number(1);
+        let valid_until = 500;
+        let warn_at = valid_until - CREDENTIAL_WARNING_PERIOD;
+        let full =
+            || BoundedVec::try_from(vec![u64::MAX; MAX_EXPIRIES_PER_BLOCK as usize]).unwrap();
+        CredentialWarnings::<Test>::insert(warn_at, full());
+
+        assert_ok!(AccessControl::register_credential(
+            RuntimeOrigin::root(),
+            DOCTOR,
+            [7u8; 32],
+            IssuingBody::MedicalCouncil,
+            valid_until,
+            HOSPITAL,
+        ));
+        assert!(AccessControl::credential_warnings(warn_at + 1).contains(&DOCTOR));
+
+        let expiring = |provider| {
+            System::events().iter().any(|record| {
+                record.event
+                    == Event::CredentialExpiring {
+                        provider,
+                        valid_until,
+                    }
+                    .into()
+            })
+        };
+
+        run_to_block(warn_at);
+        assert!(!expiring(DOCTOR));
+        run_to_block(warn_at + 1);
+        assert!(expiring(DOCTOR));
+
+        // Every block up to MaxQueueSpill is full: the nurse is warned at once
+        let valid_until = 1_000;
+        let warn_at = valid_until - CREDENTIAL_WARNING_PERIOD;
+        // Rule 2: bounded by MaxQueueSpill
+        for block in warn_at..=warn_at + MAX_QUEUE_SPILL as u64 {
+            CredentialWarnings::<Test>::insert(block, full());
+        }
+        assert_ok!(AccessControl::register_credential(
+            RuntimeOrigin::root(),
+            NURSE,
+            [7u8; 32],
+            IssuingBody::NursingCouncil,
+            valid_until,
+            HOSPITAL,
+        ));
+        System::assert_last_event(
+            Event::CredentialExpiring {
+                provider: NURSE,
+                valid_until,
+            }
+            .into(),
+        );
+    });
+}
+
+// =============================================================================
+// Facility Tests
+// =============================================================================
+
+/// Register a hospital run by FACILITY_ADMIN, returning its id
+fn register_hospital() -> u32 {
+    let facility_id = AccessControl::next_facility_id();
+    assert_ok!(AccessControl::register_facility(
+        RuntimeOrigin::root(),
+        FacilityKind::Hospital,
+        b"Kenyatta National Hospital".to_vec(),
+        FACILITY_ADMIN,
+    ));
+    facility_id
+}
+
+/// Test `AdminOrigin` registers facilities and names their Admin
+#[test]
+fn register_facility_works() {
+    new_test_ext_with_roles().execute_with(|| {
+        System::set_block_number(1);
+        let hospital = register_hospital();
+
+        let facility = AccessControl::facility(hospital).unwrap();
+        assert_eq!(facility.kind, FacilityKind::Hospital);
+        assert_eq!(
+            facility.name.into_inner(),
+            b"Kenyatta National Hospital".to_vec()
+        );
+        assert_eq!(facility.admin, FACILITY_ADMIN);
+        assert_eq!(AccessControl::next_facility_id(), hospital + 1);
+        System::assert_last_event(
+            Event::FacilityRegistered {
+                facility_id: hospital,
+                kind: FacilityKind::Hospital,
+                admin: FACILITY_ADMIN,
+            }
+            .into(),
+        );
+
+        // Only AdminOrigin (not even a single Admin), and names within MaxFacilityNameLength
+        assert_noop!(
+            AccessControl::register_facility(
+                RuntimeOrigin::signed(ADMIN),
+                FacilityKind::Clinic,
+                b"Clinic".to_vec(),
+                ADMIN,
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            AccessControl::register_facility(
+                RuntimeOrigin::root(),
+                FacilityKind::Clinic,
+                vec![b'c'; 65],
+                FACILITY_ADMIN,
+            ),
+            Error::<Test>::FacilityNameTooLong
+        );
+
+        // The ministry can hand the facility to a new Admin
+        assert_ok!(AccessControl::set_facility_admin(
+            RuntimeOrigin::root(),
+            hospital,
+            STAFF
+        ));
+        assert_eq!(AccessControl::facility(hospital).unwrap().admin, STAFF);
+        assert_noop!(
+            AccessControl::set_facility_admin(RuntimeOrigin::signed(ADMIN), hospital, ADMIN),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            AccessControl::set_facility_admin(RuntimeOrigin::root(), 99, STAFF),
+            Error::<Test>::FacilityNotFound
+        );
+    });
+}
+
+/// Test facility Admins add credentialed staff (Privacy Officers only through
+/// AdminOrigin), within the roles the facility kind allows, and remove them
+#[test]
+fn facility_admin_manages_staff() {
+    new_test_ext_with_roles().execute_with(|| {
+        System::set_block_number(1);
+        let hospital = register_hospital();
+        assert_ok!(AccessControl::register_facility(
+            RuntimeOrigin::root(),
+            FacilityKind::Pharmacy,
+            b"City Pharmacy".to_vec(),
+            PHARMACIST,
+        ));
+        let pharmacy = hospital + 1;
+
+        // A licensed role needs the account's credential first
+        assert_noop!(
+            AccessControl::add_facility_staff(RuntimeOrigin::root(), hospital, STAFF, Role::Doctor),
+            Error::<Test>::CredentialRequired
+        );
+        assert_ok!(AccessControl::register_credential(
+            RuntimeOrigin::root(),
+            STAFF,
+            [7u8; 32],
+            IssuingBody::MedicalCouncil,
+            1_000,
+            hospital,
+        ));
+        assert_ok!(AccessControl::add_facility_staff(
+            RuntimeOrigin::root(),
+            hospital,
+            STAFF,
+            Role::Doctor,
+        ));
+        assert_eq!(
+            AccessControl::facility_role(hospital, STAFF),
+            Some(Role::Doctor)
+        );
+        System::assert_last_event(
+            Event::FacilityStaffAdded {
+                facility_id: hospital,
+                account: STAFF,
+                role: Role::Doctor,
+                added_by: None,
+            }
+            .into(),
+        );
+
+        // The facility Admin adds a Nurse whose credential AdminOrigin recorded
+        assert_ok!(AccessControl::add_facility_staff(
+            RuntimeOrigin::signed(FACILITY_ADMIN),
+            hospital,
+            NURSE,
+            Role::Nurse,
+        ));
+        assert_eq!(
+            AccessControl::facility_role(hospital, NURSE),
+            Some(Role::Nurse)
+        );
+        System::assert_last_event(
+            Event::FacilityStaffAdded {
+                facility_id: hospital,
+                account: NURSE,
+                role: Role::Nurse,
+                added_by: Some(FACILITY_ADMIN),
+            }
+            .into(),
+        );
+
+        // ...but not one without a credential, nor a Privacy Officer (even as a
+        // single ministry Admin), nor any role to themselves
+        assert_noop!(
+            AccessControl::add_facility_staff(
+                RuntimeOrigin::signed(FACILITY_ADMIN),
+                hospital,
+                UNAUTHORIZED,
+                Role::Nurse,
+            ),
+            Error::<Test>::CredentialRequired
+        );
+        // Rule 2: bounded by the two callers
+        for caller in [FACILITY_ADMIN, ADMIN] {
+            assert_noop!(
+                AccessControl::add_facility_staff(
+                    RuntimeOrigin::signed(caller),
+                    hospital,
+                    UNAUTHORIZED,
+                    Role::PrivacyOfficer,
+                ),
+                Error::<Test>::RoleRequiresAdminOrigin
+            );
+        }
+        assert_noop!(
+            AccessControl::add_facility_staff(
+                RuntimeOrigin::signed(FACILITY_ADMIN),
+                hospital,
+                FACILITY_ADMIN,
+                Role::Patient,
+            ),
+            Error::<Test>::CannotAssignSelf
+        );
+
+        // Admins and Patients are never facility roles, and a pharmacy only
+        // employs pharmacists
+        assert_noop!(
+            AccessControl::add_facility_staff(
+                RuntimeOrigin::root(),
+                hospital,
+                UNAUTHORIZED,
+                Role::Admin,
+            ),
+            Error::<Test>::RoleNotAllowedAtFacility
+        );
+        assert_noop!(
+            AccessControl::add_facility_staff(
+                RuntimeOrigin::signed(FACILITY_ADMIN),
+                hospital,
+                UNAUTHORIZED,
+                Role::Patient,
+            ),
+            Error::<Test>::RoleNotAllowedAtFacility
+        );
+        assert_noop!(
+            AccessControl::add_facility_staff(RuntimeOrigin::root(), pharmacy, STAFF, Role::Doctor),
+            Error::<Test>::RoleNotAllowedAtFacility
+        );
+        assert_noop!(
+            AccessControl::add_facility_staff(RuntimeOrigin::root(), hospital, STAFF, Role::Nurse),
+            Error::<Test>::StaffAlreadyAssigned
+        );
+        assert_noop!(
+            AccessControl::add_facility_staff(RuntimeOrigin::root(), 99, STAFF, Role::Doctor),
+            Error::<Test>::FacilityNotFound
+        );
+
+        // Another facility's Admin cannot touch this facility's staff
+        assert_noop!(
+            AccessControl::add_facility_staff(
+                RuntimeOrigin::signed(PHARMACIST),
+                hospital,
+                UNAUTHORIZED,
+                Role::Patient,
+            ),
+            Error::<Test>::NotFacilityAdmin
+        );
+        assert_noop!(
+            AccessControl::remove_facility_staff(
+                RuntimeOrigin::signed(PHARMACIST),
+                hospital,
+                STAFF
+            ),
+            Error::<Test>::NotFacilityAdmin
+        );
+
+        assert_ok!(AccessControl::remove_facility_staff(
+            RuntimeOrigin::signed(FACILITY_ADMIN),
+            hospital,
+            STAFF,
+        ));
+        assert_eq!(AccessControl::facility_role(hospital, STAFF), None);
+        assert_eq!(AccessControl::active_facility(STAFF), None);
+        assert_noop!(
+            AccessControl::remove_facility_staff(
+                RuntimeOrigin::signed(FACILITY_ADMIN),
+                hospital,
+                STAFF
+            ),
+            Error::<Test>::StaffNotFound
+        );
+    });
+}
+
+/// Test staff act in the role of their active facility
+#[test]
+fn facility_role_follows_active_facility() {
+    new_test_ext_with_roles().execute_with(|| {
+        System::set_block_number(1);
+        let hospital = register_hospital();
+        assert_ok!(AccessControl::register_facility(
+            RuntimeOrigin::root(),
+            FacilityKind::Laboratory,
+            b"Lancet Laboratories".to_vec(),
+            FACILITY_ADMIN,
+        ));
+        let lab = hospital + 1;
+
+        assert!(!AccessControl::is_healthcare_provider(&STAFF));
+        assert_ok!(AccessControl::register_credential(
+            RuntimeOrigin::root(),
+            STAFF,
+            [7u8; 32],
+            IssuingBody::MedicalCouncil,
+            1_000,
+            hospital,
+        ));
+        assert_ok!(AccessControl::add_facility_staff(
+            RuntimeOrigin::root(),
+            hospital,
+            STAFF,
+            Role::Doctor,
+        ));
+        assert_ok!(AccessControl::add_facility_staff(
+            RuntimeOrigin::root(),
+            lab,
+            STAFF,
+            Role::LabTechnician,
+        ));
+
+        // The first facility joined becomes active
+        assert_eq!(AccessControl::active_facility(STAFF), Some(hospital));
+        assert!(AccessControl::is_doctor(&STAFF));
+        assert!(AccessControl::has_permission(
+            &STAFF,
+            &PATIENT,
+            Permission::EditRecord
+        ));
+
+        assert_ok!(AccessControl::set_active_facility(
+            RuntimeOrigin::signed(STAFF),
+            lab
+        ));
+        System::assert_last_event(
+            Event::ActiveFacilitySet {
+                account: STAFF,
+                facility_id: lab,
+            }
+            .into(),
+        );
+        assert_eq!(
+            AccessControl::active_role(&STAFF, &PATIENT),
+            Some(Role::LabTechnician)
+        );
+        assert!(!AccessControl::is_doctor(&STAFF));
+
+        // Only facilities the caller works at
+        assert_noop!(
+            AccessControl::set_active_facility(RuntimeOrigin::signed(NURSE), lab),
+            Error::<Test>::StaffNotFound
+        );
+
+        // Facility roles lapse with the credential like global ones
+        assert_ok!(AccessControl::register_credential(
+            RuntimeOrigin::root(),
+            STAFF,
+            [7u8; 32],
+            IssuingBody::HealthProfessionsCouncil,
+            10,
+            lab,
+        ));
+        run_to_block(11);
+        assert_eq!(AccessControl::active_role(&STAFF, &PATIENT), None);
+
+        // Leaving the active facility leaves the account without a role
+        assert_ok!(AccessControl::remove_facility_staff(
+            RuntimeOrigin::signed(FACILITY_ADMIN),
+            lab,
+            STAFF,
+        ));
+        assert_eq!(AccessControl::active_facility(STAFF), None);
+        assert_eq!(AccessControl::active_role(&STAFF, &PATIENT), None);
+    });
+}
+
+/// Test a facility role reaches only the facility's patients and patients
+/// who granted the staff member access
+#[test]
+fn facility_role_limited_to_facility_patients() {
+    new_test_ext_with_roles().execute_with(|| {
+        System::set_block_number(1);
+        let hospital = register_hospital();
+        assert_ok!(AccessControl::register_credential(
+            RuntimeOrigin::root(),
+            STAFF,
+            [7u8; 32],
+            IssuingBody::MedicalCouncil,
+            1_000,
+            hospital,
+        ));
+        assert_ok!(AccessControl::add_facility_staff(
+            RuntimeOrigin::root(),
+            hospital,
+            STAFF,
+            Role::Doctor,
+        ));
+
+        assert_eq!(
+            AccessControl::active_role(&STAFF, &PATIENT),
+            Some(Role::Doctor)
+        );
+        assert_eq!(AccessControl::active_role(&STAFF, &OTHER_PATIENT), None);
+        assert_eq!(AccessControl::current_role(&STAFF), Some(Role::Doctor));
+
+        // OTHER_PATIENT was never treated at this hospital: no break-glass
+        assert_noop!(
+            AccessControl::grant_emergency_access(
+                RuntimeOrigin::signed(STAFF),
+                OTHER_PATIENT,
+                [1u8; 32],
+            ),
+            Error::<Test>::NotHealthcareProvider
+        );
+        assert_ok!(AccessControl::grant_emergency_access(
+            RuntimeOrigin::signed(STAFF),
+            PATIENT,
+            [1u8; 32],
+        ));
+
+        // A patient's own grant brings the facility Doctor in
+        assert_ok!(AccessControl::grant_access(
+            RuntimeOrigin::signed(OTHER_PATIENT),
+            STAFF,
+            AccessType::Regular,
+            None,
+        ));
+        assert!(AccessControl::has_permission(
+            &STAFF,
+            &OTHER_PATIENT,
+            Permission::EditRecord
+        ));
+
+        // Global roles reach every patient
+        assert_eq!(
+            AccessControl::active_role(&DOCTOR, &OTHER_PATIENT),
+            Some(Role::Doctor)
+        );
+    });
+}
+
+/// Test facility staff registered as patients keep acting in their facility role
+#[test]
+fn patient_role_does_not_hide_facility_role() {
+    new_test_ext_with_roles().execute_with(|| {
+        System::set_block_number(1);
+        let hospital = register_hospital();
+        assert_ok!(AccessControl::register_credential(
+            RuntimeOrigin::root(),
+            STAFF,
+            [7u8; 32],
+            IssuingBody::NursingCouncil,
+            1_000,
+            hospital,
+        ));
+        assert_ok!(AccessControl::add_facility_staff(
+            RuntimeOrigin::root(),
+            hospital,
+            STAFF,
+            Role::Nurse,
+        ));
+
+        <AccessControl as AccessPermissions<u64>>::ensure_patient_role(&STAFF);
+        assert!(AccessControl::is_patient(&STAFF));
+        assert_eq!(AccessControl::current_role(&STAFF), Some(Role::Nurse));
+        assert_eq!(
+            AccessControl::active_role(&STAFF, &PATIENT),
+            Some(Role::Nurse)
+        );
+
+        // Outside the facility's patients, and after leaving it, they are a patient
+        assert_eq!(
+            AccessControl::active_role(&STAFF, &OTHER_PATIENT),
+            Some(Role::Patient)
+        );
+        assert_ok!(AccessControl::remove_facility_staff(
+            RuntimeOrigin::signed(FACILITY_ADMIN),
+            hospital,
+            STAFF,
+        ));
+        assert_eq!(AccessControl::current_role(&STAFF), Some(Role::Patient));
+    });
+}
+
+/// Test registering a facility fails once facility ids are exhausted
+#[test]
+fn register_facility_fails_when_ids_exhausted() {
+    new_test_ext_with_roles().execute_with(|| {
+        NextFacilityId::<Test>::put(u32::MAX);
+        assert_noop!(
+            AccessControl::register_facility(
+                RuntimeOrigin::root(),
+                FacilityKind::Clinic,
+                b"Clinic".to_vec(),
+                FACILITY_ADMIN,
+            ),
+            Error::<Test>::FacilityIdOverflow
+        );
+    });
+}
+
+// =============================================================================
+// Audit Log Tests
+// =============================================================================
+
+/// Test grants, revocations and expiries are appended to the patient's audit log
+#[test]
+fn audit_log_records_access_lifecycle() {
+    new_test_ext_with_roles().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(AccessControl::grant_emergency_access(
+            RuntimeOrigin::signed(DOCTOR),
+            PATIENT,
+            [1u8; 32],
+        ));
+        assert_ok!(AccessControl::grant_access(
+            RuntimeOrigin::signed(PATIENT),
+            NURSE,
+            AccessType::Regular,
+            None,
+        ));
+        assert_ok!(AccessControl::revoke_access(
+            RuntimeOrigin::signed(PATIENT),
+            PATIENT,
+            NURSE,
+        ));
+        run_to_block(DOCTOR_ACCESS_DURATION + 2);
+
+        let log = AccessControl::audit_log(&PATIENT, 0, MAX_AUDIT_ENTRIES);
+        let actions: Vec<_> = log
+            .iter()
+            .map(|(_, entry)| (entry.who, entry.action.clone()))
+            .collect();
+        assert_eq!(
+            actions,
+            vec![
+                (DOCTOR, AuditAction::AccessGranted(AccessType::Emergency)),
+                (NURSE, AuditAction::AccessGranted(AccessType::Regular)),
+                (NURSE, AuditAction::AccessRevoked),
+                (DOCTOR, AuditAction::AccessExpired),
+            ]
+        );
+
+        // Expiry entry keeps the grant's reason and the block it was removed at
+        let (index, expired) = &log[3];
+        assert_eq!(*index, 3);
+        assert_eq!(expired.reason_hash, [1u8; 32]);
+        assert_eq!(expired.block, DOCTOR_ACCESS_DURATION + 2);
+
+        // Removing the entry does not erase its history
+        assert!(AccessControl::active_access(PATIENT, DOCTOR).is_none());
+        assert_eq!(AccessControl::audit_log_length(PATIENT), 4);
+    });
+}
+
+/// Test the audit log keeps only the latest MaxAuditEntries entries
+#[test]
+fn audit_log_prunes_oldest_entries() {
+    new_test_ext_with_roles().execute_with(|| {
+        let total = MAX_AUDIT_ENTRIES + 2;
+        // Rule 2: bounded by total
+        for _ in 0..total {
+            AccessControl::log_audit(&PATIENT, &DOCTOR, AuditAction::RecordRead, [0u8; 32]);
+        }
+
+        assert_eq!(AccessControl::audit_log_length(PATIENT), total);
+        assert!(AccessControl::audit_entry(PATIENT, 0).is_none());
+        assert!(AccessControl::audit_entry(PATIENT, 1).is_none());
+
+        let indices: Vec<u32> = AccessControl::audit_log(&PATIENT, 0, u32::MAX)
+            .into_iter()
+            .map(|(index, _)| index)
+            .collect();
+        assert_eq!(indices, (2..total).collect::<Vec<_>>());
+    });
+}
+
+/// Test audit log pages are bounded by start and limit
+#[test]
+fn audit_log_paginates() {
+    new_test_ext_with_roles().execute_with(|| {
+        // Rule 2: bounded by MAX_AUDIT_ENTRIES
+        for _ in 0..MAX_AUDIT_ENTRIES {
+            AccessControl::log_audit(&PATIENT, &DOCTOR, AuditAction::RecordWritten, [0u8; 32]);
+        }
+
+        let page = |start, limit| -> Vec<u32> {
+            AccessControl::audit_log(&PATIENT, start, limit)
+                .into_iter()
+                .map(|(index, _)| index)
+                .collect()
+        };
+        assert_eq!(page(0, 2), vec![0, 1]);
+        assert_eq!(page(2, 2), vec![2, 3]);
+        assert_eq!(page(4, 2), vec![4]);
+        assert!(page(5, 2).is_empty());
+
+        // Other patients have their own, empty, log
+        assert!(AccessControl::audit_log(&UNAUTHORIZED, 0, 10).is_empty());
+    });
+}
@@ -0,0 +1,855 @@
+//! Weights for `pallet_access_control`
+//!
+//! Layout follows the `frame-benchmarking-cli` output so the file can be
+//! regenerated in place from `benchmarking.rs`:
+//!
+//! ```text
+//! frame-omni-bencher v1 benchmark pallet \
+//!     --runtime target/release/wbuild/medichain-runtime/medichain_runtime.compact.compressed.wasm \
+//!     --pallet pallet_access_control --extrinsic "*" \
+//!     --output pallets/access-control/src/weights.rs
+//! ```
+//!
+//! Storage reads/writes per call match the accesses in `lib.rs`
+//! (NASA Power of 10: Rule 2 - every call has a fixed upper bound).
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_access_control`.
+pub trait WeightInfo {
+	fn assign_role() -> Weight;
+	fn revoke_role() -> Weight;
+	fn grant_emergency_access() -> Weight;
+	fn revoke_access() -> Weight;
+	fn cleanup_expired_access() -> Weight;
+	fn on_initialize_expired(n: u32, ) -> Weight;
+	fn grant_access() -> Weight;
+	fn appoint_guardian() -> Weight;
+	fn remove_guardian() -> Weight;
+	fn grant_access_as_guardian() -> Weight;
+	fn set_consent_directives() -> Weight;
+	fn submit_justification() -> Weight;
+	fn review_emergency_access() -> Weight;
+	fn on_initialize_unjustified(n: u32, ) -> Weight;
+	fn register_credential() -> Weight;
+	fn on_initialize_credential_warnings(n: u32, ) -> Weight;
+	fn register_facility() -> Weight;
+	fn set_facility_admin() -> Weight;
+	fn add_facility_staff() -> Weight;
+	fn remove_facility_staff() -> Weight;
+	fn set_active_facility() -> Weight;
+	fn mark_review_unjustified() -> Weight;
+}
+
+/// Weights for `pallet_access_control` using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `AccessControl::UserRoles` (r:1 w:1)
+	/// Proof: `AccessControl::UserRoles` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::Credentials` (r:1 w:0)
+	/// Proof: `AccessControl::Credentials` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `MaxEncodedLen`)
+	fn assign_role() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `99`
+		//  Estimated: `3558`
+		Weight::from_parts(17_000_000, 3558)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AccessControl::UserRoles` (r:1 w:1)
+	/// Proof: `AccessControl::UserRoles` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	fn revoke_role() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `74`
+		//  Estimated: `3514`
+		Weight::from_parts(15_000_000, 3514)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AccessControl::UserRoles` (r:1 w:0)
+	/// Proof: `AccessControl::UserRoles` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::Credentials` (r:1 w:0)
+	/// Proof: `AccessControl::Credentials` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::ActiveFacility` (r:1 w:0)
+	/// Proof: `AccessControl::ActiveFacility` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::FacilityStaff` (r:1 w:0)
+	/// Proof: `AccessControl::FacilityStaff` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::Consents` (r:1 w:0)
+	/// Proof: `AccessControl::Consents` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::ActiveAccess` (r:1 w:1)
+	/// Proof: `AccessControl::ActiveAccess` (`max_values`: None, `max_size`: Some(179), added: 2654, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AccessCount` (r:1 w:1)
+	/// Proof: `AccessControl::AccessCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::ExpiryQueue` (r:1 w:1)
+	/// Proof: `AccessControl::ExpiryQueue` (`max_values`: None, `max_size`: Some(4121), added: 6596, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLogLength` (r:1 w:1)
+	/// Proof: `AccessControl::AuditLogLength` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLog` (r:0 w:2)
+	/// Proof: `AccessControl::AuditLog` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::NextReviewId` (r:1 w:1)
+	/// Proof: `AccessControl::NextReviewId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::ReviewDeadlines` (r:1 w:1)
+	/// Proof: `AccessControl::ReviewDeadlines` (`max_values`: None, `max_size`: Some(281), added: 2756, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::BreakGlassReviews` (r:0 w:1)
+	/// Proof: `AccessControl::BreakGlassReviews` (`max_values`: None, `max_size`: Some(150), added: 2625, mode: `MaxEncodedLen`)
+	/// Storage: `PatientIdentity::Identities` (r:1 w:0)
+	/// Proof: `PatientIdentity::Identities` (`max_values`: None, `max_size`: Some(499), added: 2974, mode: `MaxEncodedLen`)
+	fn grant_emergency_access() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `126`
+		//  Estimated: `7586`
+		Weight::from_parts(41_000_000, 7586)
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	/// Storage: `AccessControl::Guardians` (r:1 w:0)
+	/// Proof: `AccessControl::Guardians` (`max_values`: None, `max_size`: Some(141), added: 2616, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::ActiveAccess` (r:1 w:1)
+	/// Proof: `AccessControl::ActiveAccess` (`max_values`: None, `max_size`: Some(179), added: 2654, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AccessCount` (r:1 w:1)
+	/// Proof: `AccessControl::AccessCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLogLength` (r:1 w:1)
+	/// Proof: `AccessControl::AuditLogLength` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLog` (r:0 w:2)
+	/// Proof: `AccessControl::AuditLog` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	fn revoke_access() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `304`
+		//  Estimated: `3643`
+		Weight::from_parts(27_000_000, 3643)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `AccessControl::ActiveAccess` (r:1 w:1)
+	/// Proof: `AccessControl::ActiveAccess` (`max_values`: None, `max_size`: Some(179), added: 2654, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AccessCount` (r:1 w:1)
+	/// Proof: `AccessControl::AccessCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLogLength` (r:1 w:1)
+	/// Proof: `AccessControl::AuditLogLength` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLog` (r:0 w:2)
+	/// Proof: `AccessControl::AuditLog` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	fn cleanup_expired_access() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `304`
+		//  Estimated: `3643`
+		Weight::from_parts(26_000_000, 3643)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `AccessControl::ExpiryQueue` (r:1 w:1)
+	/// Proof: `AccessControl::ExpiryQueue` (`max_values`: None, `max_size`: Some(4121), added: 6596, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::ActiveAccess` (r:64 w:64)
+	/// Proof: `AccessControl::ActiveAccess` (`max_values`: None, `max_size`: Some(179), added: 2654, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AccessCount` (r:64 w:64)
+	/// Proof: `AccessControl::AccessCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLogLength` (r:64 w:64)
+	/// Proof: `AccessControl::AuditLogLength` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLog` (r:0 w:128)
+	/// Proof: `AccessControl::AuditLog` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 64]`.
+	fn on_initialize_expired(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `87 + n * (262 ±0)`
+		//  Estimated: `7586 + n * (2654 ±0)`
+		Weight::from_parts(4_000_000, 7586)
+			// Standard Error: 9_000
+			.saturating_add(Weight::from_parts(19_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((5_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2654).saturating_mul(n.into()))
+	}
+	/// Storage: `AccessControl::UserRoles` (r:2 w:0)
+	/// Proof: `AccessControl::UserRoles` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::Credentials` (r:1 w:0)
+	/// Proof: `AccessControl::Credentials` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::ActiveFacility` (r:1 w:0)
+	/// Proof: `AccessControl::ActiveFacility` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::FacilityStaff` (r:1 w:0)
+	/// Proof: `AccessControl::FacilityStaff` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::ActiveAccess` (r:1 w:1)
+	/// Proof: `AccessControl::ActiveAccess` (`max_values`: None, `max_size`: Some(179), added: 2654, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AccessCount` (r:1 w:1)
+	/// Proof: `AccessControl::AccessCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::ExpiryQueue` (r:1 w:1)
+	/// Proof: `AccessControl::ExpiryQueue` (`max_values`: None, `max_size`: Some(4121), added: 6596, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLogLength` (r:1 w:1)
+	/// Proof: `AccessControl::AuditLogLength` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLog` (r:0 w:2)
+	/// Proof: `AccessControl::AuditLog` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	/// Storage: `PatientIdentity::Identities` (r:1 w:0)
+	/// Proof: `PatientIdentity::Identities` (`max_values`: None, `max_size`: Some(499), added: 2974, mode: `MaxEncodedLen`)
+	fn grant_access() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `178`
+		//  Estimated: `7586`
+		Weight::from_parts(34_000_000, 7586)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `AccessControl::UserRoles` (r:2 w:0)
+	/// Proof: `AccessControl::UserRoles` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::Credentials` (r:1 w:0)
+	/// Proof: `AccessControl::Credentials` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::ActiveFacility` (r:1 w:0)
+	/// Proof: `AccessControl::ActiveFacility` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::Guardians` (r:1 w:1)
+	/// Proof: `AccessControl::Guardians` (`max_values`: None, `max_size`: Some(141), added: 2616, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::GuardianCount` (r:1 w:1)
+	/// Proof: `AccessControl::GuardianCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLogLength` (r:1 w:1)
+	/// Proof: `AccessControl::AuditLogLength` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLog` (r:0 w:2)
+	/// Proof: `AccessControl::AuditLog` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	fn appoint_guardian() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `152`
+		//  Estimated: `3606`
+		Weight::from_parts(27_000_000, 3606)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `AccessControl::Guardians` (r:1 w:1)
+	/// Proof: `AccessControl::Guardians` (`max_values`: None, `max_size`: Some(141), added: 2616, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::GuardianCount` (r:1 w:1)
+	/// Proof: `AccessControl::GuardianCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLogLength` (r:1 w:1)
+	/// Proof: `AccessControl::AuditLogLength` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLog` (r:0 w:2)
+	/// Proof: `AccessControl::AuditLog` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	fn remove_guardian() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `291`
+		//  Estimated: `3606`
+		Weight::from_parts(24_000_000, 3606)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `AccessControl::Guardians` (r:1 w:0)
+	/// Proof: `AccessControl::Guardians` (`max_values`: None, `max_size`: Some(141), added: 2616, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::UserRoles` (r:1 w:0)
+	/// Proof: `AccessControl::UserRoles` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::Credentials` (r:1 w:0)
+	/// Proof: `AccessControl::Credentials` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::ActiveFacility` (r:1 w:0)
+	/// Proof: `AccessControl::ActiveFacility` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::FacilityStaff` (r:1 w:0)
+	/// Proof: `AccessControl::FacilityStaff` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::ActiveAccess` (r:1 w:1)
+	/// Proof: `AccessControl::ActiveAccess` (`max_values`: None, `max_size`: Some(179), added: 2654, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AccessCount` (r:1 w:1)
+	/// Proof: `AccessControl::AccessCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::ExpiryQueue` (r:1 w:1)
+	/// Proof: `AccessControl::ExpiryQueue` (`max_values`: None, `max_size`: Some(4121), added: 6596, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLogLength` (r:1 w:1)
+	/// Proof: `AccessControl::AuditLogLength` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLog` (r:0 w:2)
+	/// Proof: `AccessControl::AuditLog` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	/// Storage: `PatientIdentity::Identities` (r:1 w:0)
+	/// Proof: `PatientIdentity::Identities` (`max_values`: None, `max_size`: Some(499), added: 2974, mode: `MaxEncodedLen`)
+	fn grant_access_as_guardian() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `297`
+		//  Estimated: `7586`
+		Weight::from_parts(37_000_000, 7586)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `AccessControl::Guardians` (r:1 w:0)
+	/// Proof: `AccessControl::Guardians` (`max_values`: None, `max_size`: Some(141), added: 2616, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::UserRoles` (r:1 w:0)
+	/// Proof: `AccessControl::UserRoles` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::Consents` (r:0 w:1)
+	/// Proof: `AccessControl::Consents` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLogLength` (r:1 w:1)
+	/// Proof: `AccessControl::AuditLogLength` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLog` (r:0 w:2)
+	/// Proof: `AccessControl::AuditLog` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	fn set_consent_directives() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `264`
+		//  Estimated: `3606`
+		Weight::from_parts(23_000_000, 3606)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `AccessControl::BreakGlassReviews` (r:1 w:1)
+	/// Proof: `AccessControl::BreakGlassReviews` (`max_values`: None, `max_size`: Some(150), added: 2625, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLogLength` (r:1 w:1)
+	/// Proof: `AccessControl::AuditLogLength` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLog` (r:0 w:2)
+	/// Proof: `AccessControl::AuditLog` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	fn submit_justification() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `239`
+		//  Estimated: `3615`
+		Weight::from_parts(22_000_000, 3615)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `AccessControl::UserRoles` (r:1 w:0)
+	/// Proof: `AccessControl::UserRoles` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::BreakGlassReviews` (r:1 w:1)
+	/// Proof: `AccessControl::BreakGlassReviews` (`max_values`: None, `max_size`: Some(150), added: 2625, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::MisuseCount` (r:1 w:1)
+	/// Proof: `AccessControl::MisuseCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLogLength` (r:1 w:1)
+	/// Proof: `AccessControl::AuditLogLength` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLog` (r:0 w:2)
+	/// Proof: `AccessControl::AuditLog` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	fn review_emergency_access() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `312`
+		//  Estimated: `3615`
+		Weight::from_parts(28_000_000, 3615)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `AccessControl::ReviewDeadlines` (r:1 w:1)
+	/// Proof: `AccessControl::ReviewDeadlines` (`max_values`: None, `max_size`: Some(281), added: 2756, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::BreakGlassReviews` (r:64 w:64)
+	/// Proof: `AccessControl::BreakGlassReviews` (`max_values`: None, `max_size`: Some(150), added: 2625, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::MisuseCount` (r:64 w:64)
+	/// Proof: `AccessControl::MisuseCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLogLength` (r:64 w:64)
+	/// Proof: `AccessControl::AuditLogLength` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLog` (r:0 w:128)
+	/// Proof: `AccessControl::AuditLog` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 64]`.
+	fn on_initialize_unjustified(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `87 + n * (245 ±0)`
+		//  Estimated: `3746 + n * (2625 ±0)`
+		Weight::from_parts(3_000_000, 3746)
+			// Standard Error: 8_000
+			.saturating_add(Weight::from_parts(17_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((5_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2625).saturating_mul(n.into()))
+	}
+	/// Storage: `AccessControl::Facilities` (r:1 w:0)
+	/// Proof: `AccessControl::Facilities` (`max_values`: None, `max_size`: Some(179), added: 2654, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::Credentials` (r:1 w:1)
+	/// Proof: `AccessControl::Credentials` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::CredentialWarnings` (r:2 w:2)
+	/// Proof: `AccessControl::CredentialWarnings` (`max_values`: None, `max_size`: Some(2073), added: 4548, mode: `MaxEncodedLen`)
+	fn register_credential() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2431`
+		//  Estimated: `10086`
+		Weight::from_parts(38_000_000, 10086)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `AccessControl::CredentialWarnings` (r:1 w:1)
+	/// Proof: `AccessControl::CredentialWarnings` (`max_values`: None, `max_size`: Some(2073), added: 4548, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::Credentials` (r:64 w:0)
+	/// Proof: `AccessControl::Credentials` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 64]`.
+	fn on_initialize_credential_warnings(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `87 + n * (158 ±0)`
+		//  Estimated: `5538 + n * (2568 ±0)`
+		Weight::from_parts(3_000_000, 5538)
+			// Standard Error: 4_000
+			.saturating_add(Weight::from_parts(6_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 2568).saturating_mul(n.into()))
+	}
+	/// Storage: `AccessControl::NextFacilityId` (r:1 w:1)
+	/// Proof: `AccessControl::NextFacilityId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::Facilities` (r:0 w:1)
+	/// Proof: `AccessControl::Facilities` (`max_values`: None, `max_size`: Some(179), added: 2654, mode: `MaxEncodedLen`)
+	fn register_facility() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6`
+		//  Estimated: `3644`
+		Weight::from_parts(17_000_000, 3644)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `AccessControl::Facilities` (r:1 w:1)
+	/// Proof: `AccessControl::Facilities` (`max_values`: None, `max_size`: Some(179), added: 2654, mode: `MaxEncodedLen`)
+	fn set_facility_admin() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `149`
+		//  Estimated: `3644`
+		Weight::from_parts(18_000_000, 3644)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AccessControl::Facilities` (r:1 w:0)
+	/// Proof: `AccessControl::Facilities` (`max_values`: None, `max_size`: Some(179), added: 2654, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::Credentials` (r:1 w:0)
+	/// Proof: `AccessControl::Credentials` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::FacilityStaff` (r:1 w:1)
+	/// Proof: `AccessControl::FacilityStaff` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::ActiveFacility` (r:1 w:1)
+	/// Proof: `AccessControl::ActiveFacility` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn add_facility_staff() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `149`
+		//  Estimated: `3644`
+		Weight::from_parts(22_000_000, 3644)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `AccessControl::Facilities` (r:1 w:0)
+	/// Proof: `AccessControl::Facilities` (`max_values`: None, `max_size`: Some(179), added: 2654, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::UserRoles` (r:1 w:0)
+	/// Proof: `AccessControl::UserRoles` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::FacilityStaff` (r:1 w:1)
+	/// Proof: `AccessControl::FacilityStaff` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::ActiveFacility` (r:1 w:1)
+	/// Proof: `AccessControl::ActiveFacility` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn remove_facility_staff() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `230`
+		//  Estimated: `3644`
+		Weight::from_parts(23_000_000, 3644)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `AccessControl::FacilityStaff` (r:1 w:0)
+	/// Proof: `AccessControl::FacilityStaff` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::ActiveFacility` (r:0 w:1)
+	/// Proof: `AccessControl::ActiveFacility` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn set_active_facility() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `140`
+		//  Estimated: `3526`
+		Weight::from_parts(14_000_000, 3526)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AccessControl::BreakGlassReviews` (r:1 w:1)
+	/// Proof: `AccessControl::BreakGlassReviews` (`max_values`: None, `max_size`: Some(150), added: 2625, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::MisuseCount` (r:1 w:1)
+	/// Proof: `AccessControl::MisuseCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLogLength` (r:1 w:1)
+	/// Proof: `AccessControl::AuditLogLength` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLog` (r:0 w:2)
+	/// Proof: `AccessControl::AuditLog` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	fn mark_review_unjustified() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `245`
+		//  Estimated: `3615`
+		Weight::from_parts(21_000_000, 3615)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `AccessControl::UserRoles` (r:1 w:1)
+	/// Proof: `AccessControl::UserRoles` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::Credentials` (r:1 w:0)
+	/// Proof: `AccessControl::Credentials` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `MaxEncodedLen`)
+	fn assign_role() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `99`
+		//  Estimated: `3558`
+		Weight::from_parts(17_000_000, 3558)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AccessControl::UserRoles` (r:1 w:1)
+	/// Proof: `AccessControl::UserRoles` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	fn revoke_role() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `74`
+		//  Estimated: `3514`
+		Weight::from_parts(15_000_000, 3514)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AccessControl::UserRoles` (r:1 w:0)
+	/// Proof: `AccessControl::UserRoles` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::Credentials` (r:1 w:0)
+	/// Proof: `AccessControl::Credentials` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::ActiveFacility` (r:1 w:0)
+	/// Proof: `AccessControl::ActiveFacility` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::FacilityStaff` (r:1 w:0)
+	/// Proof: `AccessControl::FacilityStaff` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::Consents` (r:1 w:0)
+	/// Proof: `AccessControl::Consents` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::ActiveAccess` (r:1 w:1)
+	/// Proof: `AccessControl::ActiveAccess` (`max_values`: None, `max_size`: Some(179), added: 2654, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AccessCount` (r:1 w:1)
+	/// Proof: `AccessControl::AccessCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::ExpiryQueue` (r:1 w:1)
+	/// Proof: `AccessControl::ExpiryQueue` (`max_values`: None, `max_size`: Some(4121), added: 6596, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLogLength` (r:1 w:1)
+	/// Proof: `AccessControl::AuditLogLength` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLog` (r:0 w:2)
+	/// Proof: `AccessControl::AuditLog` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::NextReviewId` (r:1 w:1)
+	/// Proof: `AccessControl::NextReviewId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::ReviewDeadlines` (r:1 w:1)
+	/// Proof: `AccessControl::ReviewDeadlines` (`max_values`: None, `max_size`: Some(281), added: 2756, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::BreakGlassReviews` (r:0 w:1)
+	/// Proof: `AccessControl::BreakGlassReviews` (`max_values`: None, `max_size`: Some(150), added: 2625, mode: `MaxEncodedLen`)
+	/// Storage: `PatientIdentity::Identities` (r:1 w:0)
+	/// Proof: `PatientIdentity::Identities` (`max_values`: None, `max_size`: Some(499), added: 2974, mode: `MaxEncodedLen`)
+	fn grant_emergency_access() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `126`
+		//  Estimated: `7586`
+		Weight::from_parts(41_000_000, 7586)
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+	/// Storage: `AccessControl::Guardians` (r:1 w:0)
+	/// Proof: `AccessControl::Guardians` (`max_values`: None, `max_size`: Some(141), added: 2616, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::ActiveAccess` (r:1 w:1)
+	/// Proof: `AccessControl::ActiveAccess` (`max_values`: None, `max_size`: Some(179), added: 2654, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AccessCount` (r:1 w:1)
+	/// Proof: `AccessControl::AccessCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLogLength` (r:1 w:1)
+	/// Proof: `AccessControl::AuditLogLength` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLog` (r:0 w:2)
+	/// Proof: `AccessControl::AuditLog` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	fn revoke_access() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `304`
+		//  Estimated: `3643`
+		Weight::from_parts(27_000_000, 3643)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `AccessControl::ActiveAccess` (r:1 w:1)
+	/// Proof: `AccessControl::ActiveAccess` (`max_values`: None, `max_size`: Some(179), added: 2654, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AccessCount` (r:1 w:1)
+	/// Proof: `AccessControl::AccessCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLogLength` (r:1 w:1)
+	/// Proof: `AccessControl::AuditLogLength` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLog` (r:0 w:2)
+	/// Proof: `AccessControl::AuditLog` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	fn cleanup_expired_access() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `304`
+		//  Estimated: `3643`
+		Weight::from_parts(26_000_000, 3643)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `AccessControl::ExpiryQueue` (r:1 w:1)
+	/// Proof: `AccessControl::ExpiryQueue` (`max_values`: None, `max_size`: Some(4121), added: 6596, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::ActiveAccess` (r:64 w:64)
+	/// Proof: `AccessControl::ActiveAccess` (`max_values`: None, `max_size`: Some(179), added: 2654, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AccessCount` (r:64 w:64)
+	/// Proof: `AccessControl::AccessCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLogLength` (r:64 w:64)
+	/// Proof: `AccessControl::AuditLogLength` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLog` (r:0 w:128)
+	/// Proof: `AccessControl::AuditLog` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 64]`.
+	fn on_initialize_expired(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `87 + n * (262 ±0)`
+		//  Estimated: `7586 + n * (2654 ±0)`
+		Weight::from_parts(4_000_000, 7586)
+			// Standard Error: 9_000
+			.saturating_add(Weight::from_parts(19_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((5_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2654).saturating_mul(n.into()))
+	}
+	/// Storage: `AccessControl::UserRoles` (r:2 w:0)
+	/// Proof: `AccessControl::UserRoles` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::Credentials` (r:1 w:0)
+	/// Proof: `AccessControl::Credentials` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::ActiveFacility` (r:1 w:0)
+	/// Proof: `AccessControl::ActiveFacility` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::FacilityStaff` (r:1 w:0)
+	/// Proof: `AccessControl::FacilityStaff` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::ActiveAccess` (r:1 w:1)
+	/// Proof: `AccessControl::ActiveAccess` (`max_values`: None, `max_size`: Some(179), added: 2654, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AccessCount` (r:1 w:1)
+	/// Proof: `AccessControl::AccessCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::ExpiryQueue` (r:1 w:1)
+	/// Proof: `AccessControl::ExpiryQueue` (`max_values`: None, `max_size`: Some(4121), added: 6596, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLogLength` (r:1 w:1)
+	/// Proof: `AccessControl::AuditLogLength` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLog` (r:0 w:2)
+	/// Proof: `AccessControl::AuditLog` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	/// Storage: `PatientIdentity::Identities` (r:1 w:0)
+	/// Proof: `PatientIdentity::Identities` (`max_values`: None, `max_size`: Some(499), added: 2974, mode: `MaxEncodedLen`)
+	fn grant_access() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `178`
+		//  Estimated: `7586`
+		Weight::from_parts(34_000_000, 7586)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `AccessControl::UserRoles` (r:2 w:0)
+	/// Proof: `AccessControl::UserRoles` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::Credentials` (r:1 w:0)
+	/// Proof: `AccessControl::Credentials` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::ActiveFacility` (r:1 w:0)
+	/// Proof: `AccessControl::ActiveFacility` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::Guardians` (r:1 w:1)
+	/// Proof: `AccessControl::Guardians` (`max_values`: None, `max_size`: Some(141), added: 2616, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::GuardianCount` (r:1 w:1)
+	/// Proof: `AccessControl::GuardianCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLogLength` (r:1 w:1)
+	/// Proof: `AccessControl::AuditLogLength` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLog` (r:0 w:2)
+	/// Proof: `AccessControl::AuditLog` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	fn appoint_guardian() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `152`
+		//  Estimated: `3606`
+		Weight::from_parts(27_000_000, 3606)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `AccessControl::Guardians` (r:1 w:1)
+	/// Proof: `AccessControl::Guardians` (`max_values`: None, `max_size`: Some(141), added: 2616, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::GuardianCount` (r:1 w:1)
+	/// Proof: `AccessControl::GuardianCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLogLength` (r:1 w:1)
+	/// Proof: `AccessControl::AuditLogLength` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLog` (r:0 w:2)
+	/// Proof: `AccessControl::AuditLog` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	fn remove_guardian() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `291`
+		//  Estimated: `3606`
+		Weight::from_parts(24_000_000, 3606)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `AccessControl::Guardians` (r:1 w:0)
+	/// Proof: `AccessControl::Guardians` (`max_values`: None, `max_size`: Some(141), added: 2616, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::UserRoles` (r:1 w:0)
+	/// Proof: `AccessControl::UserRoles` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::Credentials` (r:1 w:0)
+	/// Proof: `AccessControl::Credentials` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::ActiveFacility` (r:1 w:0)
+	/// Proof: `AccessControl::ActiveFacility` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::FacilityStaff` (r:1 w:0)
+	/// Proof: `AccessControl::FacilityStaff` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::ActiveAccess` (r:1 w:1)
+	/// Proof: `AccessControl::ActiveAccess` (`max_values`: None, `max_size`: Some(179), added: 2654, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AccessCount` (r:1 w:1)
+	/// Proof: `AccessControl::AccessCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::ExpiryQueue` (r:1 w:1)
+	/// Proof: `AccessControl::ExpiryQueue` (`max_values`: None, `max_size`: Some(4121), added: 6596, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLogLength` (r:1 w:1)
+	/// Proof: `AccessControl::AuditLogLength` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLog` (r:0 w:2)
+	/// Proof: `AccessControl::AuditLog` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	/// Storage: `PatientIdentity::Identities` (r:1 w:0)
+	/// Proof: `PatientIdentity::Identities` (`max_values`: None, `max_size`: Some(499), added: 2974, mode: `MaxEncodedLen`)
+	fn grant_access_as_guardian() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `297`
+		//  Estimated: `7586`
+		Weight::from_parts(37_000_000, 7586)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `AccessControl::Guardians` (r:1 w:0)
+	/// Proof: `AccessControl::Guardians` (`max_values`: None, `max_size`: Some(141), added: 2616, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::UserRoles` (r:1 w:0)
+	/// Proof: `AccessControl::UserRoles` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::Consents` (r:0 w:1)
+	/// Proof: `AccessControl::Consents` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLogLength` (r:1 w:1)
+	/// Proof: `AccessControl::AuditLogLength` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLog` (r:0 w:2)
+	/// Proof: `AccessControl::AuditLog` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	fn set_consent_directives() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `264`
+		//  Estimated: `3606`
+		Weight::from_parts(23_000_000, 3606)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `AccessControl::BreakGlassReviews` (r:1 w:1)
+	/// Proof: `AccessControl::BreakGlassReviews` (`max_values`: None, `max_size`: Some(150), added: 2625, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLogLength` (r:1 w:1)
+	/// Proof: `AccessControl::AuditLogLength` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLog` (r:0 w:2)
+	/// Proof: `AccessControl::AuditLog` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	fn submit_justification() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `239`
+		//  Estimated: `3615`
+		Weight::from_parts(22_000_000, 3615)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `AccessControl::UserRoles` (r:1 w:0)
+	/// Proof: `AccessControl::UserRoles` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::BreakGlassReviews` (r:1 w:1)
+	/// Proof: `AccessControl::BreakGlassReviews` (`max_values`: None, `max_size`: Some(150), added: 2625, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::MisuseCount` (r:1 w:1)
+	/// Proof: `AccessControl::MisuseCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLogLength` (r:1 w:1)
+	/// Proof: `AccessControl::AuditLogLength` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLog` (r:0 w:2)
+	/// Proof: `AccessControl::AuditLog` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	fn review_emergency_access() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `312`
+		//  Estimated: `3615`
+		Weight::from_parts(28_000_000, 3615)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `AccessControl::ReviewDeadlines` (r:1 w:1)
+	/// Proof: `AccessControl::ReviewDeadlines` (`max_values`: None, `max_size`: Some(281), added: 2756, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::BreakGlassReviews` (r:64 w:64)
+	/// Proof: `AccessControl::BreakGlassReviews` (`max_values`: None, `max_size`: Some(150), added: 2625, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::MisuseCount` (r:64 w:64)
+	/// Proof: `AccessControl::MisuseCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLogLength` (r:64 w:64)
+	/// Proof: `AccessControl::AuditLogLength` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLog` (r:0 w:128)
+	/// Proof: `AccessControl::AuditLog` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 64]`.
+	fn on_initialize_unjustified(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `87 + n * (245 ±0)`
+		//  Estimated: `3746 + n * (2625 ±0)`
+		Weight::from_parts(3_000_000, 3746)
+			// Standard Error: 8_000
+			.saturating_add(Weight::from_parts(17_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((5_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2625).saturating_mul(n.into()))
+	}
+	/// Storage: `AccessControl::Facilities` (r:1 w:0)
+	/// Proof: `AccessControl::Facilities` (`max_values`: None, `max_size`: Some(179), added: 2654, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::Credentials` (r:1 w:1)
+	/// Proof: `AccessControl::Credentials` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::CredentialWarnings` (r:2 w:2)
+	/// Proof: `AccessControl::CredentialWarnings` (`max_values`: None, `max_size`: Some(2073), added: 4548, mode: `MaxEncodedLen`)
+	fn register_credential() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2431`
+		//  Estimated: `10086`
+		Weight::from_parts(38_000_000, 10086)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `AccessControl::CredentialWarnings` (r:1 w:1)
+	/// Proof: `AccessControl::CredentialWarnings` (`max_values`: None, `max_size`: Some(2073), added: 4548, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::Credentials` (r:64 w:0)
+	/// Proof: `AccessControl::Credentials` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 64]`.
+	fn on_initialize_credential_warnings(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `87 + n * (158 ±0)`
+		//  Estimated: `5538 + n * (2568 ±0)`
+		Weight::from_parts(3_000_000, 5538)
+			// Standard Error: 4_000
+			.saturating_add(Weight::from_parts(6_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 2568).saturating_mul(n.into()))
+	}
+	/// Storage: `AccessControl::NextFacilityId` (r:1 w:1)
+	/// Proof: `AccessControl::NextFacilityId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::Facilities` (r:0 w:1)
+	/// Proof: `AccessControl::Facilities` (`max_values`: None, `max_size`: Some(179), added: 2654, mode: `MaxEncodedLen`)
+	fn register_facility() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6`
+		//  Estimated: `3644`
+		Weight::from_parts(17_000_000, 3644)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `AccessControl::Facilities` (r:1 w:1)
+	/// Proof: `AccessControl::Facilities` (`max_values`: None, `max_size`: Some(179), added: 2654, mode: `MaxEncodedLen`)
+	fn set_facility_admin() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `149`
+		//  Estimated: `3644`
+		Weight::from_parts(18_000_000, 3644)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AccessControl::Facilities` (r:1 w:0)
+	/// Proof: `AccessControl::Facilities` (`max_values`: None, `max_size`: Some(179), added: 2654, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::Credentials` (r:1 w:0)
+	/// Proof: `AccessControl::Credentials` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::FacilityStaff` (r:1 w:1)
+	/// Proof: `AccessControl::FacilityStaff` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::ActiveFacility` (r:1 w:1)
+	/// Proof: `AccessControl::ActiveFacility` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn add_facility_staff() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `149`
+		//  Estimated: `3644`
+		Weight::from_parts(22_000_000, 3644)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `AccessControl::Facilities` (r:1 w:0)
+	/// Proof: `AccessControl::Facilities` (`max_values`: None, `max_size`: Some(179), added: 2654, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::UserRoles` (r:1 w:0)
+	/// Proof: `AccessControl::UserRoles` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::FacilityStaff` (r:1 w:1)
+	/// Proof: `AccessControl::FacilityStaff` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::ActiveFacility` (r:1 w:1)
+	/// Proof: `AccessControl::ActiveFacility` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn remove_facility_staff() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `230`
+		//  Estimated: `3644`
+		Weight::from_parts(23_000_000, 3644)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `AccessControl::FacilityStaff` (r:1 w:0)
+	/// Proof: `AccessControl::FacilityStaff` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::ActiveFacility` (r:0 w:1)
+	/// Proof: `AccessControl::ActiveFacility` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn set_active_facility() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `140`
+		//  Estimated: `3526`
+		Weight::from_parts(14_000_000, 3526)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AccessControl::BreakGlassReviews` (r:1 w:1)
+	/// Proof: `AccessControl::BreakGlassReviews` (`max_values`: None, `max_size`: Some(150), added: 2625, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::MisuseCount` (r:1 w:1)
+	/// Proof: `AccessControl::MisuseCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLogLength` (r:1 w:1)
+	/// Proof: `AccessControl::AuditLogLength` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLog` (r:0 w:2)
+	/// Proof: `AccessControl::AuditLog` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	fn mark_review_unjustified() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `245`
+		//  Estimated: `3615`
+		Weight::from_parts(21_000_000, 3615)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+}
@@ -0,0 +1,205 @@
+//! Benchmarks for medical-records pallet
+//!
+//! Each benchmark exercises the worst-case storage path of its extrinsic
+//! (longest IPFS hash, alert list one short of `MaxAlerts`, target alert
+//! last in a full list).
+//! Run with `cargo test -p pallet-medical-records --features runtime-benchmarks`.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet as MedicalRecords;
+use frame_benchmarking::v2::*;
+use frame_support::traits::Get;
+use frame_system::RawOrigin;
+use pallet_access_control::{AccessPermissions, Role};
+use sp_std::vec;
+
+/// Create a doctor and a patient record with `alerts` alerts already attached
+fn setup_record<T: Config>(alerts: u32) -> (T::AccountId, T::AccountId) {
+    let doctor: T::AccountId = whitelisted_caller();
+    T::AccessPermissions::set_role(&doctor, Role::Doctor);
+    let patient: T::AccountId = account("patient", 0, 0);
+
+    MedicalRecords::<T>::create_health_record(
+        RawOrigin::Signed(doctor.clone()).into(),
+        patient.clone(),
+        BloodType::OPositive,
+        vec![b'Q'; T::MaxIpfsHashLength::get() as usize],
+    )
+    .expect("doctor can create record");
+
+    // Rule 2: bounded by MaxAlerts
+    for _ in 0..alerts {
+        MedicalRecords::<T>::add_alert(
+            RawOrigin::Signed(doctor.clone()).into(),
+            patient.clone(),
+            AlertType::Allergy,
+            [1u8; 32],
+            3,
+        )
+        .expect("record has room for alert");
+    }
+
+    (doctor, patient)
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn create_health_record() {
+        let doctor: T::AccountId = whitelisted_caller();
+        T::AccessPermissions::set_role(&doctor, Role::Doctor);
+        let patient: T::AccountId = account("patient", 0, 0);
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(doctor),
+            patient.clone(),
+            BloodType::OPositive,
+            vec![b'Q'; T::MaxIpfsHashLength::get() as usize],
+        );
+
+        assert!(HealthRecords::<T>::contains_key(&patient));
+    }
+
+    #[benchmark]
+    fn add_alert() {
+        let (doctor, patient) = setup_record::<T>(T::MaxAlerts::get() - 1);
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(doctor),
+            patient.clone(),
+            AlertType::ChronicCondition,
+            [2u8; 32],
+            5,
+        );
+
+        let record = HealthRecords::<T>::get(&patient).expect("record exists");
+        assert_eq!(record.alerts.len() as u32, T::MaxAlerts::get());
+    }
+
+    #[benchmark]
+    fn update_ipfs_hash() {
+        let (doctor, patient) = setup_record::<T>(T::MaxAlerts::get());
+        let new_hash = vec![b'R'; T::MaxIpfsHashLength::get() as usize];
+        // Worst case: history is full, so the oldest version is pruned
+        HealthRecords::<T>::mutate(&patient, |record| {
+            if let Some(record) = record {
+                record.version = T::MaxRecordVersions::get().saturating_add(1);
+            }
+        });
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(doctor), patient.clone(), new_hash.clone());
+
+        let record = HealthRecords::<T>::get(&patient).expect("record exists");
+        assert_eq!(record.ipfs_hash.into_inner(), new_hash);
+    }
+
+    #[benchmark]
+    fn update_alert() {
+        let (doctor, patient) = setup_record::<T>(T::MaxAlerts::get());
+        let alert_id = T::MaxAlerts::get() - 1;
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(doctor),
+            patient.clone(),
+            alert_id,
+            AlertType::Medication,
+            [2u8; 32],
+            4,
+        );
+
+        let record = HealthRecords::<T>::get(&patient).expect("record exists");
+        assert_eq!(record.alerts[alert_id as usize].severity, 4);
+    }
+
+    #[benchmark]
+    fn resolve_alert() {
+        let (doctor, patient) = setup_record::<T>(T::MaxAlerts::get());
+        let alert_id = T::MaxAlerts::get() - 1;
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(doctor), patient.clone(), alert_id);
+
+        let record = HealthRecords::<T>::get(&patient).expect("record exists");
+        assert!(!record.alerts[alert_id as usize].is_active());
+    }
+
+    #[benchmark]
+    fn remove_alert() {
+        let (doctor, patient) = setup_record::<T>(T::MaxAlerts::get());
+        let alert_id = T::MaxAlerts::get() - 1;
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(doctor), patient.clone(), alert_id);
+
+        let record = HealthRecords::<T>::get(&patient).expect("record exists");
+        assert_eq!(record.alerts.len() as u32, T::MaxAlerts::get() - 1);
+    }
+
+    #[benchmark]
+    fn update_blood_type() {
+        let (doctor, patient) = setup_record::<T>(T::MaxAlerts::get());
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(doctor),
+            patient.clone(),
+            BloodType::ABNegative,
+            [4u8; 32],
+        );
+
+        let record = HealthRecords::<T>::get(&patient).expect("record exists");
+        assert_eq!(record.blood_type, BloodType::ABNegative);
+    }
+
+    #[benchmark]
+    fn record_access() {
+        let (doctor, patient) = setup_record::<T>(0);
+        // Worst case: a provider reading through an access grant
+        T::AccessPermissions::set_valid_access(&patient, &doctor);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(doctor), patient.clone());
+
+        assert!(T::AccessPermissions::has_valid_access(
+            &patient,
+            &whitelisted_caller()
+        ));
+    }
+
+    #[benchmark]
+    fn add_document() {
+        let doctor: T::AccountId = whitelisted_caller();
+        T::AccessPermissions::set_role(&doctor, Role::Doctor);
+        let patient: T::AccountId = account("patient", 0, 0);
+        // Worst case: the last free document slot
+        let document_id = T::MaxDocuments::get() - 1;
+        DocumentCount::<T>::insert(&patient, document_id);
+        let cid = vec![b'Q'; T::MaxIpfsHashLength::get() as usize];
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(doctor),
+            patient.clone(),
+            DocumentType::LabResult,
+            cid.clone(),
+            cid,
+            [3u8; 32],
+        );
+
+        assert!(Documents::<T>::contains_key(&patient, document_id));
+    }
+
+    impl_benchmark_test_suite!(
+        MedicalRecords,
+        crate::mock::new_test_ext(),
+        crate::mock::Test
+    );
+}
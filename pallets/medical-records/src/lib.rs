@@ -5,37 +5,57 @@
 //!
 //! ## IMPORTANT: Access Control
 //! - Only healthcare providers (Doctor, Nurse, Admin) can CREATE/EDIT records
-//! - Patients can only READ their records (enforced at API layer)
+//! - Facility staff act only for patients whose record was created or last
+//!   modified at their facility, or who granted them access (`PatientFacilities`)
+//! - Reads are recorded on-chain via `record_access`, which requires the patient
+//!   themselves, a valid access grant or a guardian with the `ViewRecords` scope,
+//!   and the patient's consent directives (checked through `AccessPermissions`)
 //! - All modifications are logged with the healthcare provider who made them
+//!   and the facility they were acting for
 //!
 //! ## NASA Power of 10 Compliance
 //! - Rule 1: No recursion
-//! - Rule 2: All loops have fixed upper bounds (max 10 allergies)
+//! - Rule 2: All loops have fixed upper bounds (max 10 allergies, MaxDocuments documents)
 //! - Rule 3: No dynamic memory after init
 //! - Rule 6: Data objects declared at smallest scope
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+pub mod migrations;
 pub mod mock;
 pub mod tests;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+
 pub use pallet::*;
+pub use weights::WeightInfo;
 
 #[frame_support::pallet]
 pub mod pallet {
+    use crate::WeightInfo;
     use frame_support::pallet_prelude::*;
     use frame_system::pallet_prelude::*;
+    use pallet_access_control::{
+        AccessPermissions, AuditAction, FacilityId, GuardianScope, Permission,
+    };
     use sp_std::vec::Vec;
 
-    /// Maximum allergies per patient (Rule 2: bounded loops)
-    pub const MAX_ALLERGIES: u32 = 10;
-    /// Maximum IPFS hash length
-    pub const MAX_IPFS_HASH_LENGTH: u32 = 64;
     /// Maximum name length
     pub const MAX_NAME_LENGTH: u32 = 128;
 
+    /// Current storage version
+    /// (1: records carry an IPFS hash version,
+    ///  2: alerts carry an id, onset, resolution and verifier,
+    ///  3: records carry blood type provenance,
+    ///  4: records track the facility that created and last modified them)
+    pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(4);
+
     /// Blood type enumeration
-    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[derive(
+        Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default,
+    )]
     pub enum BloodType {
         APositive,
         ANegative,
@@ -45,40 +65,73 @@ pub mod pallet {
         ABNegative,
         OPositive,
         ONegative,
+        #[default]
         Unknown,
     }
 
-    impl Default for BloodType {
-        fn default() -> Self {
-            BloodType::Unknown
-        }
-    }
-
     /// Medical alert for critical conditions
     #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
-    pub struct MedicalAlert {
+    #[scale_info(skip_type_params(T))]
+    pub struct MedicalAlert<T: Config> {
+        /// Alert id, unique within the patient's record
+        pub id: u32,
         /// Type of alert (Allergy, ChronicCondition, etc.)
         pub alert_type: AlertType,
         /// Description hash (stored encrypted on IPFS)
         pub description_hash: [u8; 32],
         /// Severity level (1-5, 5 being most severe)
         pub severity: u8,
+        /// Block when the alert was recorded
+        pub onset: BlockNumberFor<T>,
+        /// Block when the condition was resolved (None while active)
+        pub resolved_at: Option<BlockNumberFor<T>>,
+        /// Healthcare provider who last verified the alert
+        pub verified_by: T::AccountId,
+    }
+
+    impl<T: Config> MedicalAlert<T> {
+        /// Active alerts are shown in emergency views
+        pub fn is_active(&self) -> bool {
+            self.resolved_at.is_none()
+        }
     }
 
     /// Types of medical alerts
-    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[derive(
+        Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default,
+    )]
     pub enum AlertType {
         Allergy,
         ChronicCondition,
         Medication,
         Disability,
+        #[default]
         Other,
     }
 
-    impl Default for AlertType {
-        fn default() -> Self {
-            AlertType::Other
-        }
+    /// Provenance of a verified blood type
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct BloodTypeVerification<T: Config> {
+        /// Doctor or lab technician who verified the type
+        pub verified_by: T::AccountId,
+        /// Block when verified
+        pub verified_at: BlockNumberFor<T>,
+        /// Hash of the lab submission or test report that established the type
+        pub evidence_hash: [u8; 32],
+    }
+
+    /// Types of documents attached to a patient's records
+    #[derive(
+        Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default,
+    )]
+    pub enum DocumentType {
+        LabResult,
+        Imaging,
+        Prescription,
+        ClinicalNote,
+        #[default]
+        Other,
     }
 
     /// Health record stored on-chain (metadata only)
@@ -89,25 +142,90 @@ pub mod pallet {
         pub patient: T::AccountId,
         /// Blood type
         pub blood_type: BloodType,
+        /// How `blood_type` was verified (None until `update_blood_type`)
+        pub blood_type_verification: Option<BloodTypeVerification<T>>,
         /// IPFS hash of encrypted full record
-        pub ipfs_hash: BoundedVec<u8, ConstU32<MAX_IPFS_HASH_LENGTH>>,
+        pub ipfs_hash: BoundedVec<u8, T::MaxIpfsHashLength>,
         /// Medical alerts (allergies, conditions)
-        pub alerts: BoundedVec<MedicalAlert, ConstU32<MAX_ALLERGIES>>,
+        pub alerts: BoundedVec<MedicalAlert<T>, T::MaxAlerts>,
+        /// Id given to the next alert added to this record
+        pub next_alert_id: u32,
         /// Block when created
         pub created_at: BlockNumberFor<T>,
         /// Block when last updated
         pub updated_at: BlockNumberFor<T>,
         /// Healthcare provider who created/last updated the record
         pub last_modified_by: T::AccountId,
+        /// Facility the record was created at (None if the creator acted for none)
+        pub created_at_facility: Option<FacilityId>,
+        /// Facility the record was last updated at
+        pub last_modified_facility: Option<FacilityId>,
+        /// Version of `ipfs_hash` (1 on creation, +1 per `update_ipfs_hash`)
+        pub version: u32,
+    }
+
+    /// Archived record version, written when `update_ipfs_hash` replaces a hash
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct RecordVersion<T: Config> {
+        /// Version the update produced
+        pub version: u32,
+        /// IPFS hash that was replaced (the content of `version - 1`)
+        pub previous_hash: BoundedVec<u8, T::MaxIpfsHashLength>,
+        /// Healthcare provider who made the update
+        pub modified_by: T::AccountId,
+        /// Block when the update was made
+        pub modified_at: BlockNumberFor<T>,
+    }
+
+    /// Document attached to a patient's records (content lives encrypted on IPFS)
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct MedicalDocument<T: Config> {
+        /// IPFS CID of the encrypted document
+        pub content_cid: BoundedVec<u8, T::MaxIpfsHashLength>,
+        /// IPFS CID of the encrypted document metadata
+        pub metadata_cid: BoundedVec<u8, T::MaxIpfsHashLength>,
+        /// Kind of document (lab result, imaging, ...)
+        pub document_type: DocumentType,
+        /// SHA-256 checksum of the encrypted content
+        pub checksum: [u8; 32],
+        /// Healthcare provider who uploaded the document
+        pub uploaded_by: T::AccountId,
+        /// Block when uploaded
+        pub uploaded_at: BlockNumberFor<T>,
     }
 
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     #[pallet::config]
-    pub trait Config: frame_system::Config + pallet_access_control::Config {
+    pub trait Config: frame_system::Config {
         /// The overarching event type
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// Weight information for extrinsics in this pallet
+        type WeightInfo: WeightInfo;
+
+        /// Role and access-grant checks (the access-control pallet)
+        type AccessPermissions: AccessPermissions<Self::AccountId>;
+
+        /// Maximum alerts (allergies, conditions) per record (Rule 2: bounded loops)
+        #[pallet::constant]
+        type MaxAlerts: Get<u32>;
+
+        /// Maximum IPFS hash length
+        #[pallet::constant]
+        type MaxIpfsHashLength: Get<u32>;
+
+        /// Archived versions retained per patient; older ones are pruned (Rule 2: bounded)
+        #[pallet::constant]
+        type MaxRecordVersions: Get<u32>;
+
+        /// Maximum documents per patient (Rule 2: bounded)
+        #[pallet::constant]
+        type MaxDocuments: Get<u32>;
     }
 
     /// Storage: Map patient account to health record
@@ -116,26 +234,103 @@ pub mod pallet {
     pub type HealthRecords<T: Config> =
         StorageMap<_, Blake2_128Concat, T::AccountId, HealthRecord<T>, OptionQuery>;
 
+    /// Storage: Record history, (patient, version) to the update that produced it
+    /// Only the latest `MaxRecordVersions` updates per patient are kept
+    #[pallet::storage]
+    #[pallet::getter(fn record_history)]
+    pub type RecordHistory<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId, // patient
+        Twox64Concat,
+        u32, // version
+        RecordVersion<T>,
+        OptionQuery,
+    >;
+
+    /// Storage: Documents, (patient, document_id) to the document index entry
+    #[pallet::storage]
+    #[pallet::getter(fn documents)]
+    pub type Documents<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId, // patient
+        Twox64Concat,
+        u32, // document_id
+        MedicalDocument<T>,
+        OptionQuery,
+    >;
+
+    /// Storage: Number of documents per patient (also the next document_id)
+    #[pallet::storage]
+    #[pallet::getter(fn document_count)]
+    pub type DocumentCount<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
         /// Health record created [patient, ipfs_hash, created_by]
         RecordCreated {
             patient: T::AccountId,
-            ipfs_hash: BoundedVec<u8, ConstU32<MAX_IPFS_HASH_LENGTH>>,
+            ipfs_hash: BoundedVec<u8, T::MaxIpfsHashLength>,
             created_by: T::AccountId,
         },
-        /// Medical alert added [patient, alert_type, added_by]
+        /// Medical alert added [patient, alert_id, alert_type, added_by]
         AlertAdded {
             patient: T::AccountId,
+            alert_id: u32,
             alert_type: AlertType,
             added_by: T::AccountId,
         },
-        /// IPFS hash updated [patient, new_hash, updated_by]
+        /// Medical alert corrected and re-verified [patient, alert_id, updated_by]
+        AlertUpdated {
+            patient: T::AccountId,
+            alert_id: u32,
+            updated_by: T::AccountId,
+        },
+        /// Medical alert marked resolved [patient, alert_id, resolved_by]
+        AlertResolved {
+            patient: T::AccountId,
+            alert_id: u32,
+            resolved_by: T::AccountId,
+        },
+        /// Medical alert removed (entered in error) [patient, alert_id, removed_by]
+        AlertRemoved {
+            patient: T::AccountId,
+            alert_id: u32,
+            removed_by: T::AccountId,
+        },
+        /// IPFS hash updated [patient, new_hash, updated_by, version]
         IpfsHashUpdated {
             patient: T::AccountId,
-            new_hash: BoundedVec<u8, ConstU32<MAX_IPFS_HASH_LENGTH>>,
+            new_hash: BoundedVec<u8, T::MaxIpfsHashLength>,
+            updated_by: T::AccountId,
+            version: u32,
+        },
+        /// Previous record version archived to history [patient, version]
+        RecordVersionArchived { patient: T::AccountId, version: u32 },
+        /// Oldest archived version dropped from history [patient, version]
+        RecordVersionPruned { patient: T::AccountId, version: u32 },
+        /// Blood type set from a lab result or test [patient, previous, blood_type, updated_by, evidence_hash]
+        BloodTypeUpdated {
+            patient: T::AccountId,
+            previous: BloodType,
+            blood_type: BloodType,
             updated_by: T::AccountId,
+            evidence_hash: [u8; 32],
+        },
+        /// Patient's records read [patient, accessor]
+        RecordAccessed {
+            patient: T::AccountId,
+            accessor: T::AccountId,
+        },
+        /// Document added to the patient's index [patient, document_id, document_type, uploaded_by]
+        DocumentAdded {
+            patient: T::AccountId,
+            document_id: u32,
+            document_type: DocumentType,
+            uploaded_by: T::AccountId,
         },
     }
 
@@ -145,7 +340,7 @@ pub mod pallet {
         RecordAlreadyExists,
         /// Record not found
         RecordNotFound,
-        /// Too many alerts (max 10)
+        /// Too many alerts (exceeds MaxAlerts)
         TooManyAlerts,
         /// Invalid IPFS hash format
         InvalidIpfsHash,
@@ -153,6 +348,24 @@ pub mod pallet {
         NotHealthcareProvider,
         /// Invalid severity level
         InvalidSeverity,
+        /// Too many documents (exceeds MaxDocuments)
+        TooManyDocuments,
+        /// Only Doctors and LabTechnicians can verify blood types
+        NotAuthorizedForBloodType,
+        /// Blood type cannot be updated to `Unknown`
+        InvalidBloodType,
+        /// Caller has no role assigned
+        NoRoleAssigned,
+        /// Caller holds no valid access grant for this patient
+        NoValidAccess,
+        /// No alert with this id on the patient's record
+        AlertNotFound,
+        /// Alert is already resolved
+        AlertAlreadyResolved,
+        /// Patient's consent directives withhold the full record from the caller
+        ConsentWithheld,
+        /// Patient's identity is inactive, deceased or merged
+        PatientNotActive,
     }
 
     #[pallet::call]
@@ -169,10 +382,11 @@ pub mod pallet {
         ///
         /// # Errors
         /// * `NotHealthcareProvider` - Caller is not authorized
+        /// * `PatientNotActive` - Patient's identity is inactive, deceased or merged
         /// * `RecordAlreadyExists` - Patient already has a record
         /// * `InvalidIpfsHash` - IPFS hash exceeds maximum length
         #[pallet::call_index(0)]
-        #[pallet::weight(Weight::from_parts(10_000, 0))]
+        #[pallet::weight(<T as Config>::WeightInfo::create_health_record())]
         pub fn create_health_record(
             origin: OriginFor<T>,
             patient: T::AccountId,
@@ -183,32 +397,43 @@ pub mod pallet {
 
             // CRITICAL: Only healthcare providers can create records
             ensure!(
-                pallet_access_control::Pallet::<T>::can_edit_medical_records(&provider),
+                T::AccessPermissions::has_permission(&provider, &patient, Permission::EditRecord),
                 Error::<T>::NotHealthcareProvider
             );
+            ensure!(
+                T::AccessPermissions::is_active_patient(&patient),
+                Error::<T>::PatientNotActive
+            );
 
             ensure!(
                 !HealthRecords::<T>::contains_key(&patient),
                 Error::<T>::RecordAlreadyExists
             );
 
-            let bounded_hash: BoundedVec<u8, ConstU32<MAX_IPFS_HASH_LENGTH>> = ipfs_hash
+            let bounded_hash: BoundedVec<u8, T::MaxIpfsHashLength> = ipfs_hash
                 .try_into()
                 .map_err(|_| Error::<T>::InvalidIpfsHash)?;
 
             let current_block = <frame_system::Pallet<T>>::block_number();
+            let facility = T::AccessPermissions::active_facility(&provider);
 
             let record = HealthRecord {
                 patient: patient.clone(),
                 blood_type,
+                blood_type_verification: None,
                 ipfs_hash: bounded_hash.clone(),
                 alerts: BoundedVec::default(),
+                next_alert_id: 0,
                 created_at: current_block,
                 updated_at: current_block,
                 last_modified_by: provider.clone(),
+                created_at_facility: facility,
+                last_modified_facility: facility,
+                version: 1,
             };
 
             HealthRecords::<T>::insert(&patient, record);
+            Self::log_record_write(&patient, &provider);
 
             Self::deposit_event(Event::RecordCreated {
                 patient,
@@ -231,11 +456,12 @@ pub mod pallet {
         ///
         /// # Errors
         /// * `NotHealthcareProvider` - Caller is not authorized
+        /// * `PatientNotActive` - Patient's identity is inactive, deceased or merged
         /// * `RecordNotFound` - No health record for patient
-        /// * `TooManyAlerts` - Maximum 10 alerts reached
+        /// * `TooManyAlerts` - Record already holds `MaxAlerts` alerts
         /// * `InvalidSeverity` - Severity must be 1-5
         #[pallet::call_index(1)]
-        #[pallet::weight(Weight::from_parts(10_000, 0))]
+        #[pallet::weight(<T as Config>::WeightInfo::add_alert())]
         pub fn add_alert(
             origin: OriginFor<T>,
             patient: T::AccountId,
@@ -247,20 +473,30 @@ pub mod pallet {
 
             // CRITICAL: Only healthcare providers can add alerts
             ensure!(
-                pallet_access_control::Pallet::<T>::can_edit_medical_records(&provider),
+                T::AccessPermissions::has_permission(&provider, &patient, Permission::EditRecord),
                 Error::<T>::NotHealthcareProvider
             );
+            ensure!(
+                T::AccessPermissions::is_active_patient(&patient),
+                Error::<T>::PatientNotActive
+            );
 
             // Validate severity (Rule 6: check early)
-            ensure!(severity >= 1 && severity <= 5, Error::<T>::InvalidSeverity);
+            ensure!((1..=5).contains(&severity), Error::<T>::InvalidSeverity);
 
             HealthRecords::<T>::try_mutate(&patient, |maybe_record| -> DispatchResult {
                 let record = maybe_record.as_mut().ok_or(Error::<T>::RecordNotFound)?;
 
+                let current_block = <frame_system::Pallet<T>>::block_number();
+                let alert_id = record.next_alert_id;
                 let alert = MedicalAlert {
+                    id: alert_id,
                     alert_type: alert_type.clone(),
                     description_hash,
                     severity,
+                    onset: current_block,
+                    resolved_at: None,
+                    verified_by: provider.clone(),
                 };
 
                 record
@@ -268,11 +504,15 @@ pub mod pallet {
                     .try_push(alert)
                     .map_err(|_| Error::<T>::TooManyAlerts)?;
 
-                record.updated_at = <frame_system::Pallet<T>>::block_number();
+                record.next_alert_id = alert_id.saturating_add(1);
+                record.updated_at = current_block;
                 record.last_modified_by = provider.clone();
+                record.last_modified_facility = T::AccessPermissions::active_facility(&provider);
+                Self::log_record_write(&patient, &provider);
 
                 Self::deposit_event(Event::AlertAdded {
                     patient: patient.clone(),
+                    alert_id,
                     alert_type,
                     added_by: provider,
                 });
@@ -291,10 +531,11 @@ pub mod pallet {
         ///
         /// # Errors
         /// * `NotHealthcareProvider` - Caller is not authorized
+        /// * `PatientNotActive` - Patient's identity is inactive, deceased or merged
         /// * `RecordNotFound` - No health record for patient
         /// * `InvalidIpfsHash` - Hash exceeds maximum length
         #[pallet::call_index(2)]
-        #[pallet::weight(Weight::from_parts(10_000, 0))]
+        #[pallet::weight(<T as Config>::WeightInfo::update_ipfs_hash())]
         pub fn update_ipfs_hash(
             origin: OriginFor<T>,
             patient: T::AccountId,
@@ -304,29 +545,566 @@ pub mod pallet {
 
             // CRITICAL: Only healthcare providers can update records
             ensure!(
-                pallet_access_control::Pallet::<T>::can_edit_medical_records(&provider),
+                T::AccessPermissions::has_permission(&provider, &patient, Permission::EditRecord),
                 Error::<T>::NotHealthcareProvider
             );
+            ensure!(
+                T::AccessPermissions::is_active_patient(&patient),
+                Error::<T>::PatientNotActive
+            );
 
-            let bounded_hash: BoundedVec<u8, ConstU32<MAX_IPFS_HASH_LENGTH>> = new_hash
+            let bounded_hash: BoundedVec<u8, T::MaxIpfsHashLength> = new_hash
                 .try_into()
                 .map_err(|_| Error::<T>::InvalidIpfsHash)?;
 
             HealthRecords::<T>::try_mutate(&patient, |maybe_record| -> DispatchResult {
                 let record = maybe_record.as_mut().ok_or(Error::<T>::RecordNotFound)?;
+                let current_block = <frame_system::Pallet<T>>::block_number();
+                let version = record.version.saturating_add(1);
 
-                record.ipfs_hash = bounded_hash.clone();
-                record.updated_at = <frame_system::Pallet<T>>::block_number();
+                // Archive the replaced hash so earlier versions stay readable
+                let previous_hash = core::mem::replace(&mut record.ipfs_hash, bounded_hash.clone());
+                Self::archive_version(
+                    &patient,
+                    RecordVersion {
+                        version,
+                        previous_hash,
+                        modified_by: provider.clone(),
+                        modified_at: current_block,
+                    },
+                );
+
+                record.version = version;
+                record.updated_at = current_block;
                 record.last_modified_by = provider.clone();
+                record.last_modified_facility = T::AccessPermissions::active_facility(&provider);
+                Self::log_record_write(&patient, &provider);
 
                 Self::deposit_event(Event::IpfsHashUpdated {
                     patient: patient.clone(),
                     new_hash: bounded_hash,
                     updated_by: provider,
+                    version,
                 });
 
                 Ok(())
             })
         }
+
+        /// Add a document (lab result, imaging, prescription, ...) to the patient's index
+        ///
+        /// **IMPORTANT**: Lab results need `AddLabResult`; every other kind of
+        /// document needs `EditRecord`. Documents get sequential ids per patient,
+        /// starting at 0.
+        ///
+        /// # Arguments
+        /// * `patient` - Patient account
+        /// * `document_type` - Kind of document
+        /// * `content_cid` - IPFS CID of the encrypted document
+        /// * `metadata_cid` - IPFS CID of the encrypted metadata
+        /// * `checksum` - SHA-256 checksum of the encrypted content
+        ///
+        /// # Errors
+        /// * `NotHealthcareProvider` - Caller is not authorized
+        /// * `PatientNotActive` - Patient's identity is inactive, deceased or merged
+        /// * `InvalidIpfsHash` - A CID exceeds maximum length
+        /// * `TooManyDocuments` - Patient already has `MaxDocuments` documents
+        #[pallet::call_index(3)]
+        #[pallet::weight(<T as Config>::WeightInfo::add_document())]
+        pub fn add_document(
+            origin: OriginFor<T>,
+            patient: T::AccountId,
+            document_type: DocumentType,
+            content_cid: Vec<u8>,
+            metadata_cid: Vec<u8>,
+            checksum: [u8; 32],
+        ) -> DispatchResult {
+            let provider = ensure_signed(origin)?;
+
+            // CRITICAL: Lab technicians may add lab results, not other documents
+            let permission = match document_type {
+                DocumentType::LabResult => Permission::AddLabResult,
+                _ => Permission::EditRecord,
+            };
+            ensure!(
+                T::AccessPermissions::has_permission(&provider, &patient, permission),
+                Error::<T>::NotHealthcareProvider
+            );
+            ensure!(
+                T::AccessPermissions::is_active_patient(&patient),
+                Error::<T>::PatientNotActive
+            );
+
+            let content_cid: BoundedVec<u8, T::MaxIpfsHashLength> = content_cid
+                .try_into()
+                .map_err(|_| Error::<T>::InvalidIpfsHash)?;
+            let metadata_cid: BoundedVec<u8, T::MaxIpfsHashLength> = metadata_cid
+                .try_into()
+                .map_err(|_| Error::<T>::InvalidIpfsHash)?;
+
+            let document_id = DocumentCount::<T>::get(&patient);
+            ensure!(
+                document_id < T::MaxDocuments::get(),
+                Error::<T>::TooManyDocuments
+            );
+
+            let document = MedicalDocument {
+                content_cid,
+                metadata_cid,
+                document_type: document_type.clone(),
+                checksum,
+                uploaded_by: provider.clone(),
+                uploaded_at: <frame_system::Pallet<T>>::block_number(),
+            };
+
+            Documents::<T>::insert(&patient, document_id, document);
+            DocumentCount::<T>::insert(&patient, document_id.saturating_add(1));
+            Self::log_record_write(&patient, &provider);
+
+            Self::deposit_event(Event::DocumentAdded {
+                patient,
+                document_id,
+                document_type,
+                uploaded_by: provider,
+            });
+
+            Ok(())
+        }
+
+        /// Correct an existing alert (type, description, severity)
+        ///
+        /// **IMPORTANT**: Only healthcare providers can update alerts.
+        /// The caller becomes the alert's verifying provider.
+        ///
+        /// # Arguments
+        /// * `patient` - Patient account
+        /// * `alert_id` - Id of the alert to update
+        /// * `alert_type` - Corrected type of alert
+        /// * `description_hash` - Hash of corrected encrypted description
+        /// * `severity` - Corrected severity level (1-5)
+        ///
+        /// # Errors
+        /// * `NotHealthcareProvider` - Caller is not authorized
+        /// * `PatientNotActive` - Patient's identity is inactive, deceased or merged
+        /// * `InvalidSeverity` - Severity must be 1-5
+        /// * `RecordNotFound` - No health record for patient
+        /// * `AlertNotFound` - No alert with this id
+        #[pallet::call_index(4)]
+        #[pallet::weight(<T as Config>::WeightInfo::update_alert())]
+        pub fn update_alert(
+            origin: OriginFor<T>,
+            patient: T::AccountId,
+            alert_id: u32,
+            alert_type: AlertType,
+            description_hash: [u8; 32],
+            severity: u8,
+        ) -> DispatchResult {
+            let provider = ensure_signed(origin)?;
+
+            // CRITICAL: Only healthcare providers can update alerts
+            ensure!(
+                T::AccessPermissions::has_permission(&provider, &patient, Permission::EditRecord),
+                Error::<T>::NotHealthcareProvider
+            );
+            ensure!(
+                T::AccessPermissions::is_active_patient(&patient),
+                Error::<T>::PatientNotActive
+            );
+
+            // Validate severity (Rule 6: check early)
+            ensure!((1..=5).contains(&severity), Error::<T>::InvalidSeverity);
+
+            Self::mutate_alert(&patient, &provider, alert_id, |alert| {
+                alert.alert_type = alert_type;
+                alert.description_hash = description_hash;
+                alert.severity = severity;
+                alert.verified_by = provider.clone();
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::AlertUpdated {
+                patient,
+                alert_id,
+                updated_by: provider,
+            });
+
+            Ok(())
+        }
+
+        /// Mark an alert as resolved (e.g. a condition that has cleared)
+        ///
+        /// **IMPORTANT**: Only healthcare providers can resolve alerts.
+        /// Resolved alerts stay on the record but are hidden from emergency views.
+        ///
+        /// # Arguments
+        /// * `patient` - Patient account
+        /// * `alert_id` - Id of the alert to resolve
+        ///
+        /// # Errors
+        /// * `NotHealthcareProvider` - Caller is not authorized
+        /// * `PatientNotActive` - Patient's identity is inactive, deceased or merged
+        /// * `RecordNotFound` - No health record for patient
+        /// * `AlertNotFound` - No alert with this id
+        /// * `AlertAlreadyResolved` - Alert was resolved before
+        #[pallet::call_index(5)]
+        #[pallet::weight(<T as Config>::WeightInfo::resolve_alert())]
+        pub fn resolve_alert(
+            origin: OriginFor<T>,
+            patient: T::AccountId,
+            alert_id: u32,
+        ) -> DispatchResult {
+            let provider = ensure_signed(origin)?;
+
+            // CRITICAL: Only healthcare providers can resolve alerts
+            ensure!(
+                T::AccessPermissions::has_permission(&provider, &patient, Permission::EditRecord),
+                Error::<T>::NotHealthcareProvider
+            );
+            ensure!(
+                T::AccessPermissions::is_active_patient(&patient),
+                Error::<T>::PatientNotActive
+            );
+
+            let current_block = <frame_system::Pallet<T>>::block_number();
+            Self::mutate_alert(&patient, &provider, alert_id, |alert| {
+                ensure!(alert.is_active(), Error::<T>::AlertAlreadyResolved);
+                alert.resolved_at = Some(current_block);
+                alert.verified_by = provider.clone();
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::AlertResolved {
+                patient,
+                alert_id,
+                resolved_by: provider,
+            });
+
+            Ok(())
+        }
+
+        /// Remove an alert entered in error, freeing its slot
+        ///
+        /// **IMPORTANT**: Only healthcare providers can remove alerts.
+        ///
+        /// # Arguments
+        /// * `patient` - Patient account
+        /// * `alert_id` - Id of the alert to remove
+        ///
+        /// # Errors
+        /// * `NotHealthcareProvider` - Caller is not authorized
+        /// * `PatientNotActive` - Patient's identity is inactive, deceased or merged
+        /// * `RecordNotFound` - No health record for patient
+        /// * `AlertNotFound` - No alert with this id
+        #[pallet::call_index(6)]
+        #[pallet::weight(<T as Config>::WeightInfo::remove_alert())]
+        pub fn remove_alert(
+            origin: OriginFor<T>,
+            patient: T::AccountId,
+            alert_id: u32,
+        ) -> DispatchResult {
+            let provider = ensure_signed(origin)?;
+
+            // CRITICAL: Only healthcare providers can remove alerts
+            ensure!(
+                T::AccessPermissions::has_permission(&provider, &patient, Permission::EditRecord),
+                Error::<T>::NotHealthcareProvider
+            );
+            ensure!(
+                T::AccessPermissions::is_active_patient(&patient),
+                Error::<T>::PatientNotActive
+            );
+
+            HealthRecords::<T>::try_mutate(&patient, |maybe_record| -> DispatchResult {
+                let record = maybe_record.as_mut().ok_or(Error::<T>::RecordNotFound)?;
+
+                // Rule 2: bounded by MaxAlerts
+                let index = record
+                    .alerts
+                    .iter()
+                    .position(|alert| alert.id == alert_id)
+                    .ok_or(Error::<T>::AlertNotFound)?;
+                record.alerts.remove(index);
+
+                record.updated_at = <frame_system::Pallet<T>>::block_number();
+                record.last_modified_by = provider.clone();
+                record.last_modified_facility = T::AccessPermissions::active_facility(&provider);
+                Ok(())
+            })?;
+            Self::log_record_write(&patient, &provider);
+
+            Self::deposit_event(Event::AlertRemoved {
+                patient,
+                alert_id,
+                removed_by: provider,
+            });
+
+            Ok(())
+        }
+
+        /// Set a patient's blood type from a verified lab result or test
+        ///
+        /// **IMPORTANT**: Only Doctors and LabTechnicians can update blood types,
+        /// since transfusion decisions depend on this field.
+        ///
+        /// # Arguments
+        /// * `patient` - Patient account
+        /// * `blood_type` - Verified blood type
+        /// * `evidence_hash` - Hash of the lab submission or test that verified it
+        ///
+        /// # Errors
+        /// * `NotAuthorizedForBloodType` - Caller is not a Doctor or LabTechnician
+        /// * `PatientNotActive` - Patient's identity is inactive, deceased or merged
+        /// * `InvalidBloodType` - Blood type is `Unknown`
+        /// * `RecordNotFound` - No health record for patient
+        #[pallet::call_index(7)]
+        #[pallet::weight(<T as Config>::WeightInfo::update_blood_type())]
+        pub fn update_blood_type(
+            origin: OriginFor<T>,
+            patient: T::AccountId,
+            blood_type: BloodType,
+            evidence_hash: [u8; 32],
+        ) -> DispatchResult {
+            let provider = ensure_signed(origin)?;
+
+            // CRITICAL: Only Doctors and LabTechnicians can verify blood types
+            ensure!(
+                T::AccessPermissions::has_permission(
+                    &provider,
+                    &patient,
+                    Permission::VerifyBloodType
+                ),
+                Error::<T>::NotAuthorizedForBloodType
+            );
+            ensure!(
+                T::AccessPermissions::is_active_patient(&patient),
+                Error::<T>::PatientNotActive
+            );
+            ensure!(
+                blood_type != BloodType::Unknown,
+                Error::<T>::InvalidBloodType
+            );
+
+            let previous = HealthRecords::<T>::try_mutate(
+                &patient,
+                |maybe_record| -> Result<_, DispatchError> {
+                    let record = maybe_record.as_mut().ok_or(Error::<T>::RecordNotFound)?;
+                    let current_block = <frame_system::Pallet<T>>::block_number();
+
+                    record.blood_type_verification = Some(BloodTypeVerification {
+                        verified_by: provider.clone(),
+                        verified_at: current_block,
+                        evidence_hash,
+                    });
+                    record.updated_at = current_block;
+                    record.last_modified_by = provider.clone();
+                    record.last_modified_facility =
+                        T::AccessPermissions::active_facility(&provider);
+                    Ok(core::mem::replace(
+                        &mut record.blood_type,
+                        blood_type.clone(),
+                    ))
+                },
+            )?;
+            Self::log_record_write(&patient, &provider);
+
+            Self::deposit_event(Event::BloodTypeUpdated {
+                patient,
+                previous,
+                blood_type,
+                updated_by: provider,
+                evidence_hash,
+            });
+
+            Ok(())
+        }
+
+        /// Record a read of the patient's records in their audit log
+        ///
+        /// Clients call this before fetching the encrypted record from IPFS,
+        /// making read authorization provable on-chain.
+        ///
+        /// # Arguments
+        /// * `patient` - Patient whose records are read
+        ///
+        /// # Errors
+        /// * `NoRoleAssigned` - Caller has no role and is not a guardian of the patient
+        /// * `NotHealthcareProvider` - Caller's role lacks `ViewRecords`
+        /// * `NoValidAccess` - Caller is not the patient and has no valid access grant
+        /// * `PatientNotActive` - Patient's identity is inactive, deceased or merged
+        /// * `RecordNotFound` - No health record for patient
+        /// * `ConsentWithheld` - Patient's directives withhold full records from the caller
+        #[pallet::call_index(8)]
+        #[pallet::weight(<T as Config>::WeightInfo::record_access())]
+        pub fn record_access(origin: OriginFor<T>, patient: T::AccountId) -> DispatchResult {
+            let accessor = ensure_signed(origin)?;
+
+            // Guardians read on the patient's behalf and need no role of their own
+            let is_guardian =
+                T::AccessPermissions::is_guardian(&patient, &accessor, GuardianScope::ViewRecords);
+            ensure!(
+                is_guardian || T::AccessPermissions::role(&accessor, &patient).is_some(),
+                Error::<T>::NoRoleAssigned
+            );
+            // Other readers need a role that may view records at all
+            ensure!(
+                accessor == patient
+                    || is_guardian
+                    || T::AccessPermissions::has_permission(
+                        &accessor,
+                        &patient,
+                        Permission::ViewRecords
+                    ),
+                Error::<T>::NotHealthcareProvider
+            );
+            // Patients read their own records; everyone else needs a grant
+            ensure!(
+                accessor == patient
+                    || is_guardian
+                    || T::AccessPermissions::has_valid_access(&patient, &accessor),
+                Error::<T>::NoValidAccess
+            );
+            ensure!(
+                T::AccessPermissions::is_active_patient(&patient),
+                Error::<T>::PatientNotActive
+            );
+            ensure!(
+                HealthRecords::<T>::contains_key(&patient),
+                Error::<T>::RecordNotFound
+            );
+            // Directives may have changed since an emergency grant was taken
+            ensure!(
+                T::AccessPermissions::disclosure(&patient, &accessor).full_records,
+                Error::<T>::ConsentWithheld
+            );
+
+            T::AccessPermissions::log_audit(
+                &patient,
+                &accessor,
+                AuditAction::RecordRead,
+                [0u8; 32],
+            );
+
+            Self::deposit_event(Event::RecordAccessed { patient, accessor });
+
+            Ok(())
+        }
+    }
+
+    // ========================================================================
+    // HELPER FUNCTIONS
+    // ========================================================================
+
+    impl<T: Config> Pallet<T> {
+        /// IPFS hash the patient's record pointed to at `version`
+        ///
+        /// Returns `None` for unknown versions and versions pruned from history.
+        pub fn ipfs_hash_at(
+            patient: &T::AccountId,
+            version: u32,
+        ) -> Option<BoundedVec<u8, T::MaxIpfsHashLength>> {
+            let record = HealthRecords::<T>::get(patient)?;
+            if version == record.version {
+                return Some(record.ipfs_hash);
+            }
+            // Version `v` was replaced by the update that produced `v + 1`
+            RecordHistory::<T>::get(patient, version.checked_add(1)?)
+                .map(|update| update.previous_hash)
+        }
+
+        /// Page of the patient's documents, `limit` capped at `MaxDocuments`
+        pub fn patient_documents(
+            patient: &T::AccountId,
+            start: u32,
+            limit: u32,
+        ) -> Vec<(u32, MedicalDocument<T>)> {
+            let end = start
+                .saturating_add(limit.min(T::MaxDocuments::get()))
+                .min(DocumentCount::<T>::get(patient));
+
+            // Rule 2: bounded by MaxDocuments
+            (start..end)
+                .filter_map(|id| Documents::<T>::get(patient, id).map(|doc| (id, doc)))
+                .collect()
+        }
+
+        /// Alerts still in effect, as shown in emergency views (resolved ones omitted)
+        pub fn active_alerts(patient: &T::AccountId) -> Vec<MedicalAlert<T>> {
+            HealthRecords::<T>::get(patient)
+                .map(|record| {
+                    // Rule 2: bounded by MaxAlerts
+                    record
+                        .alerts
+                        .into_iter()
+                        .filter(MedicalAlert::is_active)
+                        .collect()
+                })
+                .unwrap_or_default()
+        }
+
+        /// Apply `f` to one alert of the patient's record and log the write
+        fn mutate_alert(
+            patient: &T::AccountId,
+            provider: &T::AccountId,
+            alert_id: u32,
+            f: impl FnOnce(&mut MedicalAlert<T>) -> DispatchResult,
+        ) -> DispatchResult {
+            HealthRecords::<T>::try_mutate(patient, |maybe_record| -> DispatchResult {
+                let record = maybe_record.as_mut().ok_or(Error::<T>::RecordNotFound)?;
+
+                // Rule 2: bounded by MaxAlerts
+                let alert = record
+                    .alerts
+                    .iter_mut()
+                    .find(|alert| alert.id == alert_id)
+                    .ok_or(Error::<T>::AlertNotFound)?;
+                f(alert)?;
+
+                record.updated_at = <frame_system::Pallet<T>>::block_number();
+                record.last_modified_by = provider.clone();
+                record.last_modified_facility = T::AccessPermissions::active_facility(provider);
+                Ok(())
+            })?;
+            Self::log_record_write(patient, provider);
+            Ok(())
+        }
+
+        /// Store an update in the patient's history, pruning the oldest beyond the limit
+        fn archive_version(patient: &T::AccountId, update: RecordVersion<T>) {
+            let version = update.version;
+            RecordHistory::<T>::insert(patient, version, update);
+            Self::deposit_event(Event::RecordVersionArchived {
+                patient: patient.clone(),
+                version: version.saturating_sub(1),
+            });
+
+            // Updates start at version 2 (version 1 is the created record)
+            let pruned = version.saturating_sub(T::MaxRecordVersions::get());
+            if pruned >= 2 {
+                RecordHistory::<T>::remove(patient, pruned);
+                Self::deposit_event(Event::RecordVersionPruned {
+                    patient: patient.clone(),
+                    version: pruned.saturating_sub(1),
+                });
+            }
+        }
+
+        /// Record a write to the patient's records in the access-control audit log
+        fn log_record_write(patient: &T::AccountId, provider: &T::AccountId) {
+            T::AccessPermissions::log_audit(
+                patient,
+                provider,
+                AuditAction::RecordWritten,
+                [0u8; 32],
+            );
+        }
+    }
+
+    impl<T: Config> pallet_access_control::PatientFacilities<T::AccountId> for Pallet<T> {
+        fn is_treated_at(patient: &T::AccountId, facility_id: FacilityId) -> bool {
+            HealthRecords::<T>::get(patient).is_none_or(|record| {
+                record.created_at_facility == Some(facility_id)
+                    || record.last_modified_facility == Some(facility_id)
+            })
+        }
     }
 }
@@ -0,0 +1,308 @@
+//! Storage migrations for the medical-records pallet
+//!
+//! Add new migrations as `vN` modules and list them in the runtime's
+//! `Migrations` tuple; `VersionedMigration` makes each one run exactly once.
+//! The chain starts from the layout of the first release (storage version 0).
+
+use crate::{
+    BloodType, BloodTypeVerification, Config, HealthRecord, HealthRecords, MedicalAlert, Pallet,
+};
+use frame_support::{
+    migrations::VersionedMigration, pallet_prelude::*, traits::UncheckedOnRuntimeUpgrade,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_std::vec::Vec;
+
+/// v1: records carry the version of their IPFS hash
+pub mod v1 {
+    use super::*;
+
+    /// Medical alert as stored before v1
+    #[derive(Encode, Decode)]
+    pub(crate) struct OldMedicalAlert {
+        pub alert_type: crate::AlertType,
+        pub description_hash: [u8; 32],
+        pub severity: u8,
+    }
+
+    /// Health record as stored before v1
+    #[derive(Encode, Decode)]
+    pub(crate) struct OldHealthRecord<T: Config> {
+        pub patient: T::AccountId,
+        pub blood_type: BloodType,
+        pub ipfs_hash: BoundedVec<u8, T::MaxIpfsHashLength>,
+        pub alerts: BoundedVec<OldMedicalAlert, T::MaxAlerts>,
+        pub created_at: BlockNumberFor<T>,
+        pub updated_at: BlockNumberFor<T>,
+        pub last_modified_by: T::AccountId,
+    }
+
+    /// Health record as stored at v1
+    #[derive(Encode, Decode)]
+    pub(crate) struct HealthRecordV1<T: Config> {
+        pub patient: T::AccountId,
+        pub blood_type: BloodType,
+        pub ipfs_hash: BoundedVec<u8, T::MaxIpfsHashLength>,
+        pub alerts: BoundedVec<OldMedicalAlert, T::MaxAlerts>,
+        pub created_at: BlockNumberFor<T>,
+        pub updated_at: BlockNumberFor<T>,
+        pub last_modified_by: T::AccountId,
+        pub version: u32,
+    }
+
+    /// `HealthRecords` with its v1 value type
+    #[frame_support::storage_alias]
+    pub(crate) type HealthRecords<T: Config> = StorageMap<
+        Pallet<T>,
+        Blake2_128Concat,
+        <T as frame_system::Config>::AccountId,
+        HealthRecordV1<T>,
+        OptionQuery,
+    >;
+
+    /// Give every existing record version 1
+    ///
+    /// Replaced hashes were never kept before v1, so the current hash becomes
+    /// the first version and `RecordHistory` starts out empty.
+    pub struct InnerMigrateV0ToV1<T>(core::marker::PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV0ToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated: u64 = 0;
+
+            // Rule 2: bounded by the number of health records (one-off upgrade)
+            HealthRecords::<T>::translate::<OldHealthRecord<T>, _>(|_, old| {
+                translated = translated.saturating_add(1);
+                Some(HealthRecordV1 {
+                    patient: old.patient,
+                    blood_type: old.blood_type,
+                    ipfs_hash: old.ipfs_hash,
+                    alerts: old.alerts,
+                    created_at: old.created_at,
+                    updated_at: old.updated_at,
+                    last_modified_by: old.last_modified_by,
+                    version: 1,
+                })
+            });
+
+            T::DbWeight::get().reads_writes(translated, translated)
+        }
+    }
+
+    /// Runs `InnerMigrateV0ToV1` once, when the on-chain storage version is 0
+    pub type MigrateToV1<T> = VersionedMigration<
+        0,
+        1,
+        InnerMigrateV0ToV1<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// v2: alerts carry an id, onset, resolution and the provider who verified them
+pub mod v2 {
+    use super::*;
+
+    /// Health record as stored at v2
+    #[derive(Encode, Decode)]
+    pub(crate) struct HealthRecordV2<T: Config> {
+        pub patient: T::AccountId,
+        pub blood_type: BloodType,
+        pub ipfs_hash: BoundedVec<u8, T::MaxIpfsHashLength>,
+        pub alerts: BoundedVec<MedicalAlert<T>, T::MaxAlerts>,
+        pub next_alert_id: u32,
+        pub created_at: BlockNumberFor<T>,
+        pub updated_at: BlockNumberFor<T>,
+        pub last_modified_by: T::AccountId,
+        pub version: u32,
+    }
+
+    /// `HealthRecords` with its v2 value type
+    #[frame_support::storage_alias]
+    pub(crate) type HealthRecords<T: Config> = StorageMap<
+        Pallet<T>,
+        Blake2_128Concat,
+        <T as frame_system::Config>::AccountId,
+        HealthRecordV2<T>,
+        OptionQuery,
+    >;
+
+    /// Number existing alerts in order and mark them all active
+    ///
+    /// When each alert was added and by whom was not recorded before v2; the
+    /// record's last update is the closest known block and provider, so it
+    /// stands in for `onset` and `verified_by`.
+    pub struct InnerMigrateV1ToV2<T>(core::marker::PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV1ToV2<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated: u64 = 0;
+
+            // Rule 2: bounded by the number of health records (one-off upgrade)
+            HealthRecords::<T>::translate::<v1::HealthRecordV1<T>, _>(|_, old| {
+                translated = translated.saturating_add(1);
+                let onset = old.updated_at;
+                let verified_by = old.last_modified_by.clone();
+
+                // Rule 2: bounded by MaxAlerts
+                let alerts: Vec<MedicalAlert<T>> = old
+                    .alerts
+                    .into_inner()
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, alert)| MedicalAlert {
+                        id: index as u32,
+                        alert_type: alert.alert_type,
+                        description_hash: alert.description_hash,
+                        severity: alert.severity,
+                        onset,
+                        resolved_at: None,
+                        verified_by: verified_by.clone(),
+                    })
+                    .collect();
+                let next_alert_id = alerts.len() as u32;
+
+                Some(HealthRecordV2 {
+                    patient: old.patient,
+                    blood_type: old.blood_type,
+                    ipfs_hash: old.ipfs_hash,
+                    // Same bound as before, so nothing is truncated
+                    alerts: BoundedVec::truncate_from(alerts),
+                    next_alert_id,
+                    created_at: old.created_at,
+                    updated_at: old.updated_at,
+                    last_modified_by: old.last_modified_by,
+                    version: old.version,
+                })
+            });
+
+            T::DbWeight::get().reads_writes(translated, translated)
+        }
+    }
+
+    /// Runs `InnerMigrateV1ToV2` once, when the on-chain storage version is 1
+    pub type MigrateToV2<T> = VersionedMigration<
+        1,
+        2,
+        InnerMigrateV1ToV2<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// v3: records carry the provenance of a verified blood type
+pub mod v3 {
+    use super::*;
+
+    /// Health record as stored at v3
+    #[derive(Encode, Decode)]
+    pub(crate) struct HealthRecordV3<T: Config> {
+        pub patient: T::AccountId,
+        pub blood_type: BloodType,
+        pub blood_type_verification: Option<BloodTypeVerification<T>>,
+        pub ipfs_hash: BoundedVec<u8, T::MaxIpfsHashLength>,
+        pub alerts: BoundedVec<MedicalAlert<T>, T::MaxAlerts>,
+        pub next_alert_id: u32,
+        pub created_at: BlockNumberFor<T>,
+        pub updated_at: BlockNumberFor<T>,
+        pub last_modified_by: T::AccountId,
+        pub version: u32,
+    }
+
+    /// `HealthRecords` with its v3 value type
+    #[frame_support::storage_alias]
+    pub(crate) type HealthRecords<T: Config> = StorageMap<
+        Pallet<T>,
+        Blake2_128Concat,
+        <T as frame_system::Config>::AccountId,
+        HealthRecordV3<T>,
+        OptionQuery,
+    >;
+
+    /// Mark every existing blood type as unverified
+    ///
+    /// No lab result or test was recorded behind blood types set before v3;
+    /// `update_blood_type` records one.
+    pub struct InnerMigrateV2ToV3<T>(core::marker::PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV2ToV3<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated: u64 = 0;
+
+            // Rule 2: bounded by the number of health records (one-off upgrade)
+            HealthRecords::<T>::translate::<v2::HealthRecordV2<T>, _>(|_, old| {
+                translated = translated.saturating_add(1);
+                Some(HealthRecordV3 {
+                    patient: old.patient,
+                    blood_type: old.blood_type,
+                    blood_type_verification: None,
+                    ipfs_hash: old.ipfs_hash,
+                    alerts: old.alerts,
+                    next_alert_id: old.next_alert_id,
+                    created_at: old.created_at,
+                    updated_at: old.updated_at,
+                    last_modified_by: old.last_modified_by,
+                    version: old.version,
+                })
+            });
+
+            T::DbWeight::get().reads_writes(translated, translated)
+        }
+    }
+
+    /// Runs `InnerMigrateV2ToV3` once, when the on-chain storage version is 2
+    pub type MigrateToV3<T> = VersionedMigration<
+        2,
+        3,
+        InnerMigrateV2ToV3<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// v4: records track the facility that created and last modified them
+pub mod v4 {
+    use super::*;
+
+    /// Give every existing record an unknown (None) creating and modifying facility
+    ///
+    /// Facilities were not recorded before v4, so there is nothing to backfill;
+    /// the next update fills in `last_modified_facility`.
+    pub struct InnerMigrateV3ToV4<T>(core::marker::PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV3ToV4<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated: u64 = 0;
+
+            // Rule 2: bounded by the number of health records (one-off upgrade)
+            HealthRecords::<T>::translate::<v3::HealthRecordV3<T>, _>(|_, old| {
+                translated = translated.saturating_add(1);
+                Some(HealthRecord {
+                    patient: old.patient,
+                    blood_type: old.blood_type,
+                    blood_type_verification: old.blood_type_verification,
+                    ipfs_hash: old.ipfs_hash,
+                    alerts: old.alerts,
+                    next_alert_id: old.next_alert_id,
+                    created_at: old.created_at,
+                    updated_at: old.updated_at,
+                    last_modified_by: old.last_modified_by,
+                    created_at_facility: None,
+                    last_modified_facility: None,
+                    version: old.version,
+                })
+            });
+
+            T::DbWeight::get().reads_writes(translated, translated)
+        }
+    }
+
+    /// Runs `InnerMigrateV3ToV4` once, when the on-chain storage version is 3
+    pub type MigrateToV4<T> = VersionedMigration<
+        3,
+        4,
+        InnerMigrateV3ToV4<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
@@ -7,8 +7,9 @@
 use crate as pallet_medical_records;
 use frame_support::{
     derive_impl,
-    traits::{ConstU16, ConstU64},
+    traits::{ConstU16, ConstU32, ConstU64},
 };
+use frame_system::EnsureRoot;
 use sp_core::H256;
 use sp_runtime::{
     traits::{BlakeTwo256, IdentityLookup},
@@ -50,22 +51,67 @@ impl frame_system::Config for Test {
     type SystemWeightInfo = ();
     type SS58Prefix = ConstU16<42>;
     type OnSetCode = ();
-    type MaxConsumers = frame_support::traits::ConstU32<16>;
+    type MaxConsumers = ConstU32<16>;
+}
+
+/// Patient whose identity is no longer active (e.g. deceased)
+pub const INACTIVE_PATIENT: u64 = 101;
+
+/// Identity registry stand-in: only `INACTIVE_PATIENT` is inactive
+pub struct MockPatientStatus;
+
+impl pallet_access_control::PatientStatus<u64> for MockPatientStatus {
+    fn is_active(patient: &u64) -> bool {
+        *patient != INACTIVE_PATIENT
+    }
 }
 
 impl pallet_access_control::Config for Test {
     type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = ();
+    type AdminOrigin = EnsureRoot<u64>;
+    type PatientStatus = MockPatientStatus;
+    type PatientFacilities = MedicalRecords;
+    type DefaultAccessDuration = ConstU64<150>;
+    type DoctorAccessDuration = ConstU64<300>;
+    type NurseAccessDuration = ConstU64<100>;
+    type MaxReasonLength = ConstU32<256>;
+    type MaxActiveAccesses = ConstU32<10>;
+    type MaxExpiriesPerBlock = ConstU32<64>;
+    type MaxQueueSpill = ConstU32<4>;
+    type MaxAuditEntries = ConstU32<100>;
+    type MaxGuardians = ConstU32<4>;
+    type JustificationPeriod = ConstU64<1_000>;
+    type CredentialWarningPeriod = ConstU64<100>;
+    type MaxFacilityNameLength = ConstU32<64>;
 }
 
+/// Maximum alerts per record (Rule 2: bounded)
+pub const MAX_ALERTS: u32 = 10;
+/// Archived record versions retained per patient (Rule 2: bounded)
+pub const MAX_RECORD_VERSIONS: u32 = 3;
+/// Maximum documents per patient (Rule 2: bounded)
+pub const MAX_DOCUMENTS: u32 = 3;
+
 impl pallet_medical_records::Config for Test {
     type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = ();
+    type AccessPermissions = AccessControl;
+    type MaxAlerts = ConstU32<MAX_ALERTS>;
+    type MaxIpfsHashLength = ConstU32<64>;
+    type MaxRecordVersions = ConstU32<MAX_RECORD_VERSIONS>;
+    type MaxDocuments = ConstU32<MAX_DOCUMENTS>;
 }
 
 /// Test account constants for RBAC testing
 pub const ADMIN: u64 = 1;
 pub const DOCTOR: u64 = 2;
 pub const NURSE: u64 = 3;
+pub const LAB_TECH: u64 = 4;
+pub const PHARMACIST: u64 = 5;
+pub const PRIVACY_OFFICER: u64 = 6;
 pub const PATIENT: u64 = 100;
+pub const GUARDIAN: u64 = 200;
 pub const UNAUTHORIZED: u64 = 999;
 
 /// Build genesis storage for testing with RBAC setup
@@ -82,6 +128,36 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
             pallet_access_control::Role::Doctor,
         );
         pallet_access_control::UserRoles::<Test>::insert(NURSE, pallet_access_control::Role::Nurse);
+        pallet_access_control::UserRoles::<Test>::insert(
+            LAB_TECH,
+            pallet_access_control::Role::LabTechnician,
+        );
+        pallet_access_control::UserRoles::<Test>::insert(
+            PHARMACIST,
+            pallet_access_control::Role::Pharmacist,
+        );
+        pallet_access_control::UserRoles::<Test>::insert(
+            PRIVACY_OFFICER,
+            pallet_access_control::Role::PrivacyOfficer,
+        );
+        // Rule 2: bounded by the four licensed providers
+        for provider in [DOCTOR, NURSE, LAB_TECH, PHARMACIST] {
+            license(provider);
+        }
     });
     ext
 }
+
+/// Record an unexpired credential, without which a licensed role grants nothing
+pub fn license(provider: u64) {
+    pallet_access_control::Credentials::<Test>::insert(
+        provider,
+        pallet_access_control::Credential {
+            licence_id_hash: [0u8; 32],
+            issuing_body: pallet_access_control::IssuingBody::MedicalCouncil,
+            valid_until: u64::MAX,
+            facility_id: 0,
+            registered_at: 0,
+        },
+    );
+}
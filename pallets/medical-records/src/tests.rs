@@ -4,7 +4,7 @@
 
 #![cfg(test)]
 
-use crate::{mock::*, AlertType, BloodType, Error};
+use crate::{mock::*, AlertType, BloodType, DocumentType, Error, Event};
 use frame_support::{assert_noop, assert_ok};
 
 /// Test successful health record creation by doctor
@@ -82,6 +82,243 @@ fn unauthorized_cannot_create_health_record() {
     });
 }
 
+/// Test a doctor whose licence has lapsed cannot create health records
+#[test]
+fn lapsed_credential_cannot_create_health_record() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(AccessControl::register_credential(
+            RuntimeOrigin::signed(ADMIN),
+            DOCTOR,
+            [1u8; 32],
+            pallet_access_control::IssuingBody::MedicalCouncil,
+            10,
+            1,
+        ));
+        System::set_block_number(11);
+
+        assert_noop!(
+            MedicalRecords::create_health_record(
+                RuntimeOrigin::signed(DOCTOR),
+                PATIENT,
+                BloodType::OPositive,
+                b"QmLapsedLicence1234567890".to_vec(),
+            ),
+            Error::<Test>::NotHealthcareProvider
+        );
+    });
+}
+
+/// Test records track the facility that created and last modified them, and
+/// facility staff act only for their facility's patients
+#[test]
+fn health_record_tracks_facility() {
+    use pallet_access_control::{FacilityKind, IssuingBody, Role};
+    const HOSPITAL_DOCTOR: u64 = 20;
+    const CLINIC_NURSE: u64 = 21;
+
+    new_test_ext().execute_with(|| {
+        // Rule 2: two facilities, one staff member each
+        for (kind, staff, role) in [
+            (FacilityKind::Hospital, HOSPITAL_DOCTOR, Role::Doctor),
+            (FacilityKind::Clinic, CLINIC_NURSE, Role::Nurse),
+        ] {
+            let facility_id = AccessControl::next_facility_id();
+            assert_ok!(AccessControl::register_facility(
+                RuntimeOrigin::root(),
+                kind,
+                b"Facility".to_vec(),
+                ADMIN,
+            ));
+            assert_ok!(AccessControl::register_credential(
+                RuntimeOrigin::root(),
+                staff,
+                [7u8; 32],
+                IssuingBody::MedicalCouncil,
+                1_000,
+                facility_id,
+            ));
+            assert_ok!(AccessControl::add_facility_staff(
+                RuntimeOrigin::root(),
+                facility_id,
+                staff,
+                role,
+            ));
+        }
+
+        assert_ok!(MedicalRecords::create_health_record(
+            RuntimeOrigin::signed(HOSPITAL_DOCTOR),
+            PATIENT,
+            BloodType::APositive,
+            b"QmFacilityRecord1234567890".to_vec(),
+        ));
+        let record = MedicalRecords::health_records(PATIENT).unwrap();
+        assert_eq!(record.created_at_facility, Some(0));
+        assert_eq!(record.last_modified_facility, Some(0));
+
+        // The clinic's staff act for the patient only once the patient grants them access
+        assert_noop!(
+            MedicalRecords::add_alert(
+                RuntimeOrigin::signed(CLINIC_NURSE),
+                PATIENT,
+                AlertType::Allergy,
+                [1u8; 32],
+                3,
+            ),
+            Error::<Test>::NotHealthcareProvider
+        );
+        pallet_access_control::UserRoles::<Test>::insert(PATIENT, Role::Patient);
+        assert_ok!(AccessControl::grant_access(
+            RuntimeOrigin::signed(PATIENT),
+            CLINIC_NURSE,
+            pallet_access_control::AccessType::Regular,
+            None,
+        ));
+        assert_ok!(MedicalRecords::add_alert(
+            RuntimeOrigin::signed(CLINIC_NURSE),
+            PATIENT,
+            AlertType::Allergy,
+            [1u8; 32],
+            3,
+        ));
+        let record = MedicalRecords::health_records(PATIENT).unwrap();
+        assert_eq!(record.created_at_facility, Some(0));
+        assert_eq!(record.last_modified_facility, Some(1));
+        assert_eq!(record.last_modified_by, CLINIC_NURSE);
+
+        // Providers with a global role act for no facility
+        assert_ok!(MedicalRecords::add_alert(
+            RuntimeOrigin::signed(DOCTOR),
+            PATIENT,
+            AlertType::ChronicCondition,
+            [2u8; 32],
+            2,
+        ));
+        let record = MedicalRecords::health_records(PATIENT).unwrap();
+        assert_eq!(record.last_modified_facility, None);
+    });
+}
+
+/// Test the v4 storage migration leaves the facilities of v3 records unknown
+#[test]
+fn storage_migration_adds_record_facilities() {
+    use crate::migrations::{
+        v3::{HealthRecordV3, HealthRecords as HealthRecordsV3},
+        v4::MigrateToV4,
+    };
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+    new_test_ext().execute_with(|| {
+        let old = HealthRecordV3::<Test> {
+            patient: PATIENT,
+            blood_type: BloodType::BNegative,
+            blood_type_verification: None,
+            ipfs_hash: frame_support::BoundedVec::truncate_from(
+                b"QmLegacyRecord1234567890".to_vec(),
+            ),
+            alerts: frame_support::BoundedVec::default(),
+            next_alert_id: 0,
+            created_at: 3,
+            updated_at: 5,
+            last_modified_by: DOCTOR,
+            version: 2,
+        };
+        HealthRecordsV3::<Test>::insert(PATIENT, old);
+        StorageVersion::new(3).put::<MedicalRecords>();
+
+        MigrateToV4::<Test>::on_runtime_upgrade();
+
+        let record = MedicalRecords::health_records(PATIENT).unwrap();
+        assert_eq!(record.blood_type, BloodType::BNegative);
+        assert_eq!(record.updated_at, 5);
+        assert_eq!(record.last_modified_by, DOCTOR);
+        assert_eq!(record.version, 2);
+        assert_eq!(record.created_at_facility, None);
+        assert_eq!(record.last_modified_facility, None);
+        assert_eq!(
+            MedicalRecords::on_chain_storage_version(),
+            StorageVersion::new(4)
+        );
+    });
+}
+
+/// Test the full migration chain upgrades a record written by the first release
+#[test]
+fn storage_migrations_upgrade_first_release_records() {
+    use crate::{
+        migrations::{v1, v2, v3, v4},
+        HealthRecords,
+    };
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+    new_test_ext().execute_with(|| {
+        let old = v1::OldHealthRecord::<Test> {
+            patient: PATIENT,
+            blood_type: BloodType::ABNegative,
+            ipfs_hash: frame_support::BoundedVec::truncate_from(b"QmFirstRelease".to_vec()),
+            alerts: frame_support::BoundedVec::truncate_from(vec![v1::OldMedicalAlert {
+                alert_type: AlertType::Allergy,
+                description_hash: [9u8; 32],
+                severity: 5,
+            }]),
+            created_at: 2,
+            updated_at: 4,
+            last_modified_by: DOCTOR,
+        };
+        frame_support::storage::unhashed::put(
+            &HealthRecords::<Test>::hashed_key_for(PATIENT),
+            &old,
+        );
+        StorageVersion::new(0).put::<MedicalRecords>();
+
+        v1::MigrateToV1::<Test>::on_runtime_upgrade();
+        v2::MigrateToV2::<Test>::on_runtime_upgrade();
+        v3::MigrateToV3::<Test>::on_runtime_upgrade();
+        v4::MigrateToV4::<Test>::on_runtime_upgrade();
+
+        let record = MedicalRecords::health_records(PATIENT).unwrap();
+        assert_eq!(record.blood_type, BloodType::ABNegative);
+        assert!(record.blood_type_verification.is_none());
+        assert_eq!(record.version, 1);
+        assert_eq!(record.next_alert_id, 1);
+        assert_eq!(record.alerts[0].severity, 5);
+        assert_eq!(record.alerts[0].onset, 4);
+        assert_eq!(record.created_at_facility, None);
+        assert_eq!(
+            MedicalRecords::on_chain_storage_version(),
+            StorageVersion::new(4)
+        );
+    });
+}
+
+/// Test records of a patient whose identity is no longer active cannot be read or written
+#[test]
+fn inactive_patient_records_are_closed() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            MedicalRecords::create_health_record(
+                RuntimeOrigin::signed(DOCTOR),
+                INACTIVE_PATIENT,
+                BloodType::APositive,
+                b"QmTest".to_vec(),
+            ),
+            Error::<Test>::PatientNotActive
+        );
+
+        pallet_access_control::UserRoles::<Test>::insert(
+            INACTIVE_PATIENT,
+            pallet_access_control::Role::Patient,
+        );
+        assert_noop!(
+            MedicalRecords::record_access(
+                RuntimeOrigin::signed(INACTIVE_PATIENT),
+                INACTIVE_PATIENT
+            ),
+            Error::<Test>::PatientNotActive
+        );
+    });
+}
+
 /// Test duplicate record creation fails
 #[test]
 fn create_health_record_fails_if_exists() {
@@ -133,6 +370,9 @@ fn add_alert_works() {
         let record = MedicalRecords::health_records(PATIENT).unwrap();
         assert_eq!(record.alerts.len(), 1);
         assert_eq!(record.alerts[0].severity, 5);
+        assert_eq!(record.alerts[0].id, 0);
+        assert_eq!(record.alerts[0].verified_by, DOCTOR);
+        assert!(record.alerts[0].is_active());
         assert_eq!(record.last_modified_by, DOCTOR);
     });
 }
@@ -280,10 +520,10 @@ fn add_alert_respects_max_limit() {
             ipfs_hash,
         ));
 
-        // Add maximum alerts (10)
-        for i in 0..10 {
+        // Add maximum alerts (MaxAlerts)
+        for i in 0..MAX_ALERTS {
             let mut desc_hash = [0u8; 32];
-            desc_hash[0] = i;
+            desc_hash[0] = i as u8;
             assert_ok!(MedicalRecords::add_alert(
                 RuntimeOrigin::signed(DOCTOR),
                 PATIENT,
@@ -293,7 +533,7 @@ fn add_alert_respects_max_limit() {
             ));
         }
 
-        // 11th alert should fail
+        // One more alert should fail
         assert_noop!(
             MedicalRecords::add_alert(
                 RuntimeOrigin::signed(DOCTOR),
@@ -345,3 +585,1034 @@ fn multiple_providers_can_update_record() {
         assert_eq!(record.last_modified_by, DOCTOR);
     });
 }
+
+/// Test updates bump the version and archive the replaced hash
+#[test]
+fn update_ipfs_hash_archives_previous_version() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let v1 = b"QmVersionOne".to_vec();
+        let v2 = b"QmVersionTwo".to_vec();
+
+        assert_ok!(MedicalRecords::create_health_record(
+            RuntimeOrigin::signed(DOCTOR),
+            PATIENT,
+            BloodType::OPositive,
+            v1.clone(),
+        ));
+        assert_eq!(MedicalRecords::health_records(PATIENT).unwrap().version, 1);
+
+        System::set_block_number(5);
+        assert_ok!(MedicalRecords::update_ipfs_hash(
+            RuntimeOrigin::signed(NURSE),
+            PATIENT,
+            v2.clone(),
+        ));
+
+        let record = MedicalRecords::health_records(PATIENT).unwrap();
+        assert_eq!(record.version, 2);
+        System::assert_has_event(
+            Event::RecordVersionArchived {
+                patient: PATIENT,
+                version: 1,
+            }
+            .into(),
+        );
+
+        let update = MedicalRecords::record_history(PATIENT, 2).unwrap();
+        assert_eq!(update.previous_hash.to_vec(), v1);
+        assert_eq!(update.modified_by, NURSE);
+        assert_eq!(update.modified_at, 5);
+
+        // Each version can be read back as it was
+        assert_eq!(
+            MedicalRecords::ipfs_hash_at(&PATIENT, 1).unwrap().to_vec(),
+            v1
+        );
+        assert_eq!(
+            MedicalRecords::ipfs_hash_at(&PATIENT, 2).unwrap().to_vec(),
+            v2
+        );
+        assert!(MedicalRecords::ipfs_hash_at(&PATIENT, 3).is_none());
+    });
+}
+
+/// Test the v1 storage migration gives records from the first release version 1
+#[test]
+fn storage_migration_versions_v0_health_records() {
+    use crate::migrations::v1::{
+        HealthRecords as HealthRecordsV1, MigrateToV1, OldHealthRecord, OldMedicalAlert,
+    };
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+    new_test_ext().execute_with(|| {
+        let old = OldHealthRecord::<Test> {
+            patient: PATIENT,
+            blood_type: BloodType::OPositive,
+            ipfs_hash: frame_support::BoundedVec::truncate_from(b"QmFirstRelease".to_vec()),
+            alerts: frame_support::BoundedVec::truncate_from(vec![OldMedicalAlert {
+                alert_type: AlertType::Allergy,
+                description_hash: [1u8; 32],
+                severity: 4,
+            }]),
+            created_at: 3,
+            updated_at: 5,
+            last_modified_by: NURSE,
+        };
+        frame_support::storage::unhashed::put(
+            &HealthRecordsV1::<Test>::hashed_key_for(PATIENT),
+            &old,
+        );
+        StorageVersion::new(0).put::<MedicalRecords>();
+
+        MigrateToV1::<Test>::on_runtime_upgrade();
+
+        let record = HealthRecordsV1::<Test>::get(PATIENT).unwrap();
+        assert_eq!(record.version, 1);
+        assert_eq!(record.ipfs_hash.to_vec(), b"QmFirstRelease".to_vec());
+        assert_eq!(record.alerts.len(), 1);
+        assert_eq!(record.last_modified_by, NURSE);
+        assert_eq!(
+            MedicalRecords::on_chain_storage_version(),
+            StorageVersion::new(1)
+        );
+    });
+}
+
+/// Test record history keeps only the latest MaxRecordVersions updates
+#[test]
+fn record_history_is_bounded() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(MedicalRecords::create_health_record(
+            RuntimeOrigin::signed(DOCTOR),
+            PATIENT,
+            BloodType::OPositive,
+            vec![0u8],
+        ));
+
+        let updates = MAX_RECORD_VERSIONS + 1;
+        // Rule 2: bounded by updates
+        for i in 1..=updates {
+            assert_ok!(MedicalRecords::update_ipfs_hash(
+                RuntimeOrigin::signed(DOCTOR),
+                PATIENT,
+                vec![i as u8],
+            ));
+        }
+
+        let current = MedicalRecords::health_records(PATIENT).unwrap().version;
+        assert_eq!(current, updates + 1);
+        System::assert_has_event(
+            Event::RecordVersionPruned {
+                patient: PATIENT,
+                version: 1,
+            }
+            .into(),
+        );
+
+        // Oldest version is gone, the last MaxRecordVersions are still readable
+        assert!(MedicalRecords::ipfs_hash_at(&PATIENT, 1).is_none());
+        // Rule 2: bounded by MAX_RECORD_VERSIONS
+        for version in (current - MAX_RECORD_VERSIONS)..=current {
+            assert_eq!(
+                MedicalRecords::ipfs_hash_at(&PATIENT, version)
+                    .unwrap()
+                    .to_vec(),
+                vec![(version - 1) as u8]
+            );
+        }
+    });
+}
+
+/// Test record writes are appended to the patient's audit log
+#[test]
+fn record_writes_are_audited() {
+    use pallet_access_control::AuditAction;
+
+    new_test_ext().execute_with(|| {
+        System::set_block_number(3);
+        assert_ok!(MedicalRecords::create_health_record(
+            RuntimeOrigin::signed(DOCTOR),
+            PATIENT,
+            BloodType::BPositive,
+            b"QmYwAPJzv5CZsnAzt8auVTLFa".to_vec(),
+        ));
+        assert_ok!(MedicalRecords::add_alert(
+            RuntimeOrigin::signed(NURSE),
+            PATIENT,
+            AlertType::Allergy,
+            [1u8; 32],
+            4,
+        ));
+        assert_ok!(MedicalRecords::update_ipfs_hash(
+            RuntimeOrigin::signed(DOCTOR),
+            PATIENT,
+            b"QmNewHash0987654321098765".to_vec(),
+        ));
+
+        let log = AccessControl::audit_log(&PATIENT, 0, 10);
+        let writers: Vec<u64> = log.iter().map(|(_, entry)| entry.who).collect();
+        assert_eq!(writers, vec![DOCTOR, NURSE, DOCTOR]);
+        assert!(log
+            .iter()
+            .all(|(_, entry)| { entry.action == AuditAction::RecordWritten && entry.block == 3 }));
+    });
+}
+
+// ============================================================================
+// Alert Lifecycle Tests
+// ============================================================================
+
+/// Create a record for PATIENT holding `count` allergy alerts (ids 0..count)
+fn setup_alerts(count: u32) {
+    assert_ok!(MedicalRecords::create_health_record(
+        RuntimeOrigin::signed(DOCTOR),
+        PATIENT,
+        BloodType::OPositive,
+        b"QmYwAPJzv5CZsnAzt8auVTLFa".to_vec(),
+    ));
+    // Rule 2: bounded by the caller's count (at most MAX_ALERTS)
+    for _ in 0..count {
+        assert_ok!(MedicalRecords::add_alert(
+            RuntimeOrigin::signed(DOCTOR),
+            PATIENT,
+            AlertType::Allergy,
+            [1u8; 32],
+            3,
+        ));
+    }
+}
+
+/// Test update_alert corrects the alert and records the verifying provider
+#[test]
+fn update_alert_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        setup_alerts(2);
+
+        System::set_block_number(5);
+        assert_ok!(MedicalRecords::update_alert(
+            RuntimeOrigin::signed(NURSE),
+            PATIENT,
+            1,
+            AlertType::Medication,
+            [9u8; 32],
+            5,
+        ));
+
+        let record = MedicalRecords::health_records(PATIENT).unwrap();
+        let alert = &record.alerts[1];
+        assert_eq!(alert.alert_type, AlertType::Medication);
+        assert_eq!(alert.description_hash, [9u8; 32]);
+        assert_eq!(alert.severity, 5);
+        assert_eq!(alert.onset, 1);
+        assert_eq!(alert.verified_by, NURSE);
+        assert_eq!(record.updated_at, 5);
+        System::assert_last_event(
+            Event::AlertUpdated {
+                patient: PATIENT,
+                alert_id: 1,
+                updated_by: NURSE,
+            }
+            .into(),
+        );
+    });
+}
+
+/// Test update_alert validates severity, alert id and caller role
+#[test]
+fn update_alert_fails_for_invalid_input() {
+    new_test_ext().execute_with(|| {
+        setup_alerts(1);
+
+        assert_noop!(
+            MedicalRecords::update_alert(
+                RuntimeOrigin::signed(DOCTOR),
+                PATIENT,
+                0,
+                AlertType::Allergy,
+                [1u8; 32],
+                6,
+            ),
+            Error::<Test>::InvalidSeverity
+        );
+        assert_noop!(
+            MedicalRecords::update_alert(
+                RuntimeOrigin::signed(DOCTOR),
+                PATIENT,
+                7,
+                AlertType::Allergy,
+                [1u8; 32],
+                2,
+            ),
+            Error::<Test>::AlertNotFound
+        );
+        assert_noop!(
+            MedicalRecords::update_alert(
+                RuntimeOrigin::signed(PATIENT),
+                PATIENT,
+                0,
+                AlertType::Allergy,
+                [1u8; 32],
+                2,
+            ),
+            Error::<Test>::NotHealthcareProvider
+        );
+    });
+}
+
+/// Test resolved alerts stay on the record but leave the emergency view
+#[test]
+fn resolve_alert_hides_alert_from_emergency_view() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        setup_alerts(2);
+
+        System::set_block_number(8);
+        assert_ok!(MedicalRecords::resolve_alert(
+            RuntimeOrigin::signed(DOCTOR),
+            PATIENT,
+            0
+        ));
+
+        let record = MedicalRecords::health_records(PATIENT).unwrap();
+        assert_eq!(record.alerts.len(), 2);
+        assert_eq!(record.alerts[0].resolved_at, Some(8));
+
+        let active = MedicalRecords::active_alerts(&PATIENT);
+        assert_eq!(active.len(), 1);
+        assert_eq!(active[0].id, 1);
+
+        assert_noop!(
+            MedicalRecords::resolve_alert(RuntimeOrigin::signed(DOCTOR), PATIENT, 0),
+            Error::<Test>::AlertAlreadyResolved
+        );
+    });
+}
+
+/// Test removing an alert frees a slot once MaxAlerts is reached
+#[test]
+fn remove_alert_frees_slot() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        setup_alerts(MAX_ALERTS);
+
+        assert_ok!(MedicalRecords::remove_alert(
+            RuntimeOrigin::signed(DOCTOR),
+            PATIENT,
+            3
+        ));
+        System::assert_last_event(
+            Event::AlertRemoved {
+                patient: PATIENT,
+                alert_id: 3,
+                removed_by: DOCTOR,
+            }
+            .into(),
+        );
+        assert_noop!(
+            MedicalRecords::remove_alert(RuntimeOrigin::signed(DOCTOR), PATIENT, 3),
+            Error::<Test>::AlertNotFound
+        );
+
+        // Freed slot takes a new alert with a fresh id
+        assert_ok!(MedicalRecords::add_alert(
+            RuntimeOrigin::signed(DOCTOR),
+            PATIENT,
+            AlertType::Allergy,
+            [2u8; 32],
+            5,
+        ));
+        let record = MedicalRecords::health_records(PATIENT).unwrap();
+        assert_eq!(record.alerts.len() as u32, MAX_ALERTS);
+        assert_eq!(record.alerts.last().unwrap().id, MAX_ALERTS);
+    });
+}
+
+/// Test alert changes need a record and a healthcare provider
+#[test]
+fn alert_changes_fail_without_record_or_role() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            MedicalRecords::resolve_alert(RuntimeOrigin::signed(DOCTOR), PATIENT, 0),
+            Error::<Test>::RecordNotFound
+        );
+        assert_noop!(
+            MedicalRecords::remove_alert(RuntimeOrigin::signed(DOCTOR), PATIENT, 0),
+            Error::<Test>::RecordNotFound
+        );
+
+        setup_alerts(1);
+        assert_noop!(
+            MedicalRecords::resolve_alert(RuntimeOrigin::signed(UNAUTHORIZED), PATIENT, 0),
+            Error::<Test>::NotHealthcareProvider
+        );
+        assert_noop!(
+            MedicalRecords::remove_alert(RuntimeOrigin::signed(UNAUTHORIZED), PATIENT, 0),
+            Error::<Test>::NotHealthcareProvider
+        );
+    });
+}
+
+/// Test the v2 storage migration numbers existing alerts and keeps them active
+#[test]
+fn storage_migration_numbers_existing_alerts() {
+    use crate::migrations::{
+        v1::{HealthRecordV1, HealthRecords as HealthRecordsV1, OldMedicalAlert},
+        v2::{HealthRecords as HealthRecordsV2, MigrateToV2},
+    };
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+    new_test_ext().execute_with(|| {
+        let old_alert = |alert_type, severity| OldMedicalAlert {
+            alert_type,
+            description_hash: [severity; 32],
+            severity,
+        };
+        HealthRecordsV1::<Test>::insert(
+            PATIENT,
+            HealthRecordV1::<Test> {
+                patient: PATIENT,
+                blood_type: BloodType::APositive,
+                ipfs_hash: frame_support::BoundedVec::truncate_from(b"QmV1Record".to_vec()),
+                alerts: frame_support::BoundedVec::truncate_from(vec![
+                    old_alert(AlertType::Allergy, 5),
+                    old_alert(AlertType::Medication, 2),
+                ]),
+                created_at: 3,
+                updated_at: 7,
+                last_modified_by: NURSE,
+                version: 1,
+            },
+        );
+        StorageVersion::new(1).put::<MedicalRecords>();
+
+        MigrateToV2::<Test>::on_runtime_upgrade();
+
+        let record = HealthRecordsV2::<Test>::get(PATIENT).unwrap();
+        assert_eq!(record.next_alert_id, 2);
+        assert_eq!(record.alerts[0].id, 0);
+        assert_eq!(record.alerts[1].id, 1);
+        assert_eq!(record.alerts[1].alert_type, AlertType::Medication);
+        assert!(record
+            .alerts
+            .iter()
+            .all(|alert| alert.is_active() && alert.onset == 7 && alert.verified_by == NURSE));
+        assert_eq!(
+            MedicalRecords::on_chain_storage_version(),
+            StorageVersion::new(2)
+        );
+    });
+}
+
+// ============================================================================
+// Blood Type Tests
+// ============================================================================
+
+/// Test a lab technician sets the blood type with its verifying evidence
+#[test]
+fn update_blood_type_records_provenance() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(2);
+        assert_ok!(MedicalRecords::create_health_record(
+            RuntimeOrigin::signed(DOCTOR),
+            PATIENT,
+            BloodType::Unknown,
+            b"QmYwAPJzv5CZsnAzt8auVTLFa".to_vec(),
+        ));
+        assert!(MedicalRecords::health_records(PATIENT)
+            .unwrap()
+            .blood_type_verification
+            .is_none());
+
+        System::set_block_number(6);
+        let evidence_hash = [6u8; 32];
+        assert_ok!(MedicalRecords::update_blood_type(
+            RuntimeOrigin::signed(LAB_TECH),
+            PATIENT,
+            BloodType::ONegative,
+            evidence_hash,
+        ));
+
+        let record = MedicalRecords::health_records(PATIENT).unwrap();
+        assert_eq!(record.blood_type, BloodType::ONegative);
+        let verification = record.blood_type_verification.unwrap();
+        assert_eq!(verification.verified_by, LAB_TECH);
+        assert_eq!(verification.verified_at, 6);
+        assert_eq!(verification.evidence_hash, evidence_hash);
+        assert_eq!(record.last_modified_by, LAB_TECH);
+        System::assert_last_event(
+            Event::BloodTypeUpdated {
+                patient: PATIENT,
+                previous: BloodType::Unknown,
+                blood_type: BloodType::ONegative,
+                updated_by: LAB_TECH,
+                evidence_hash,
+            }
+            .into(),
+        );
+    });
+}
+
+/// Test only Doctors and LabTechnicians can update blood types
+#[test]
+fn update_blood_type_fails_for_other_roles() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(MedicalRecords::create_health_record(
+            RuntimeOrigin::signed(DOCTOR),
+            PATIENT,
+            BloodType::APositive,
+            b"QmYwAPJzv5CZsnAzt8auVTLFa".to_vec(),
+        ));
+
+        for caller in [NURSE, ADMIN, PATIENT, UNAUTHORIZED] {
+            assert_noop!(
+                MedicalRecords::update_blood_type(
+                    RuntimeOrigin::signed(caller),
+                    PATIENT,
+                    BloodType::BPositive,
+                    [1u8; 32],
+                ),
+                Error::<Test>::NotAuthorizedForBloodType
+            );
+        }
+    });
+}
+
+/// Test blood type updates need a record and a known blood type
+#[test]
+fn update_blood_type_fails_for_invalid_input() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            MedicalRecords::update_blood_type(
+                RuntimeOrigin::signed(DOCTOR),
+                PATIENT,
+                BloodType::BPositive,
+                [1u8; 32],
+            ),
+            Error::<Test>::RecordNotFound
+        );
+        assert_noop!(
+            MedicalRecords::update_blood_type(
+                RuntimeOrigin::signed(DOCTOR),
+                PATIENT,
+                BloodType::Unknown,
+                [1u8; 32],
+            ),
+            Error::<Test>::InvalidBloodType
+        );
+    });
+}
+
+/// Test the v3 storage migration marks existing blood types unverified
+#[test]
+fn storage_migration_leaves_blood_types_unverified() {
+    use crate::migrations::{
+        v2::{HealthRecordV2, HealthRecords as HealthRecordsV2},
+        v3::{HealthRecords as HealthRecordsV3, MigrateToV3},
+    };
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+    new_test_ext().execute_with(|| {
+        HealthRecordsV2::<Test>::insert(
+            PATIENT,
+            HealthRecordV2::<Test> {
+                patient: PATIENT,
+                blood_type: BloodType::ONegative,
+                ipfs_hash: frame_support::BoundedVec::truncate_from(b"QmV2Record".to_vec()),
+                alerts: frame_support::BoundedVec::default(),
+                next_alert_id: 0,
+                created_at: 3,
+                updated_at: 3,
+                last_modified_by: DOCTOR,
+                version: 1,
+            },
+        );
+        StorageVersion::new(2).put::<MedicalRecords>();
+
+        MigrateToV3::<Test>::on_runtime_upgrade();
+
+        let record = HealthRecordsV3::<Test>::get(PATIENT).unwrap();
+        assert_eq!(record.blood_type, BloodType::ONegative);
+        assert!(record.blood_type_verification.is_none());
+        assert_eq!(
+            MedicalRecords::on_chain_storage_version(),
+            StorageVersion::new(3)
+        );
+    });
+}
+
+// ============================================================================
+// Record Access Tests
+// ============================================================================
+
+/// Test a provider with a valid grant can read, and the read is audited
+#[test]
+fn record_access_with_valid_grant_is_audited() {
+    use pallet_access_control::AuditAction;
+
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(MedicalRecords::create_health_record(
+            RuntimeOrigin::signed(DOCTOR),
+            PATIENT,
+            BloodType::APositive,
+            b"QmYwAPJzv5CZsnAzt8auVTLFa".to_vec(),
+        ));
+        assert_ok!(AccessControl::grant_emergency_access(
+            RuntimeOrigin::signed(NURSE),
+            PATIENT,
+            [3u8; 32],
+        ));
+
+        assert_ok!(MedicalRecords::record_access(
+            RuntimeOrigin::signed(NURSE),
+            PATIENT
+        ));
+        System::assert_last_event(
+            Event::RecordAccessed {
+                patient: PATIENT,
+                accessor: NURSE,
+            }
+            .into(),
+        );
+
+        let log = AccessControl::audit_log(&PATIENT, 0, 10);
+        let (_, last) = log.last().unwrap();
+        assert_eq!(last.who, NURSE);
+        assert_eq!(last.action, AuditAction::RecordRead);
+    });
+}
+
+/// Test lab technicians and pharmacists read records under a grant, while
+/// roles without ViewRecords cannot
+#[test]
+fn record_access_follows_view_records_permission() {
+    use pallet_access_control::{AccessType, Role};
+
+    new_test_ext().execute_with(|| {
+        assert_ok!(MedicalRecords::create_health_record(
+            RuntimeOrigin::signed(DOCTOR),
+            PATIENT,
+            BloodType::APositive,
+            b"QmYwAPJzv5CZsnAzt8auVTLFa".to_vec(),
+        ));
+        pallet_access_control::UserRoles::<Test>::insert(PATIENT, Role::Patient);
+
+        // Rule 2: bounded by the two roles
+        for reader in [LAB_TECH, PHARMACIST] {
+            assert_ok!(AccessControl::grant_access(
+                RuntimeOrigin::signed(PATIENT),
+                reader,
+                AccessType::Regular,
+                None,
+            ));
+            assert_ok!(MedicalRecords::record_access(
+                RuntimeOrigin::signed(reader),
+                PATIENT
+            ));
+        }
+
+        assert_noop!(
+            MedicalRecords::record_access(RuntimeOrigin::signed(PRIVACY_OFFICER), PATIENT),
+            Error::<Test>::NotHealthcareProvider
+        );
+    });
+}
+
+/// Test patients can record reads of their own records
+#[test]
+fn record_access_by_patient_works() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(MedicalRecords::create_health_record(
+            RuntimeOrigin::signed(DOCTOR),
+            PATIENT,
+            BloodType::APositive,
+            b"QmYwAPJzv5CZsnAzt8auVTLFa".to_vec(),
+        ));
+        pallet_access_control::UserRoles::<Test>::insert(
+            PATIENT,
+            pallet_access_control::Role::Patient,
+        );
+
+        assert_ok!(MedicalRecords::record_access(
+            RuntimeOrigin::signed(PATIENT),
+            PATIENT
+        ));
+    });
+}
+
+/// Test guardians with the ViewRecords scope read without a role or grant
+#[test]
+fn record_access_by_guardian_works() {
+    use pallet_access_control::{GuardianRelationship, GuardianScopes, Role};
+
+    new_test_ext().execute_with(|| {
+        assert_ok!(MedicalRecords::create_health_record(
+            RuntimeOrigin::signed(DOCTOR),
+            PATIENT,
+            BloodType::APositive,
+            b"QmYwAPJzv5CZsnAzt8auVTLFa".to_vec(),
+        ));
+        pallet_access_control::UserRoles::<Test>::insert(PATIENT, Role::Patient);
+        assert_noop!(
+            MedicalRecords::record_access(RuntimeOrigin::signed(GUARDIAN), PATIENT),
+            Error::<Test>::NoRoleAssigned
+        );
+
+        let view_only = GuardianScopes {
+            view_records: true,
+            ..Default::default()
+        };
+        assert_ok!(AccessControl::appoint_guardian(
+            RuntimeOrigin::root(),
+            PATIENT,
+            GUARDIAN,
+            GuardianRelationship::Parent,
+            view_only,
+            None,
+        ));
+        assert_ok!(MedicalRecords::record_access(
+            RuntimeOrigin::signed(GUARDIAN),
+            PATIENT
+        ));
+
+        // Without the scope the guardian is treated like anyone else
+        assert_ok!(AccessControl::remove_guardian(
+            RuntimeOrigin::signed(PATIENT),
+            PATIENT,
+            GUARDIAN,
+        ));
+        assert_ok!(AccessControl::appoint_guardian(
+            RuntimeOrigin::signed(PATIENT),
+            PATIENT,
+            GUARDIAN,
+            GuardianRelationship::NextOfKin,
+            GuardianScopes {
+                revoke_access: true,
+                ..Default::default()
+            },
+            None,
+        ));
+        assert_noop!(
+            MedicalRecords::record_access(RuntimeOrigin::signed(GUARDIAN), PATIENT),
+            Error::<Test>::NoRoleAssigned
+        );
+    });
+}
+
+/// Test reads under an earlier emergency grant fail once consent is withheld
+#[test]
+fn record_access_respects_consent_directives() {
+    use pallet_access_control::{AccessType, ConsentDirectives, Role};
+
+    new_test_ext().execute_with(|| {
+        assert_ok!(MedicalRecords::create_health_record(
+            RuntimeOrigin::signed(DOCTOR),
+            PATIENT,
+            BloodType::APositive,
+            b"QmYwAPJzv5CZsnAzt8auVTLFa".to_vec(),
+        ));
+        pallet_access_control::UserRoles::<Test>::insert(PATIENT, Role::Patient);
+        assert_ok!(AccessControl::grant_emergency_access(
+            RuntimeOrigin::signed(NURSE),
+            PATIENT,
+            [3u8; 32],
+        ));
+        assert_ok!(AccessControl::grant_access(
+            RuntimeOrigin::signed(PATIENT),
+            DOCTOR,
+            AccessType::Full,
+            None,
+        ));
+
+        assert_ok!(AccessControl::set_consent_directives(
+            RuntimeOrigin::signed(PATIENT),
+            PATIENT,
+            ConsentDirectives {
+                share_emergency_subset: false,
+                ..Default::default()
+            },
+        ));
+        assert_noop!(
+            MedicalRecords::record_access(RuntimeOrigin::signed(NURSE), PATIENT),
+            Error::<Test>::ConsentWithheld
+        );
+        // Treating providers and the patient still read
+        assert_ok!(MedicalRecords::record_access(
+            RuntimeOrigin::signed(DOCTOR),
+            PATIENT
+        ));
+        assert_ok!(MedicalRecords::record_access(
+            RuntimeOrigin::signed(PATIENT),
+            PATIENT
+        ));
+    });
+}
+
+/// Test providers held back by consent directives cannot become guardians to read past them
+#[test]
+fn record_access_consent_cannot_be_bypassed_through_guardianship() {
+    use pallet_access_control::{ConsentDirectives, GuardianRelationship, GuardianScopes, Role};
+
+    new_test_ext().execute_with(|| {
+        assert_ok!(MedicalRecords::create_health_record(
+            RuntimeOrigin::signed(DOCTOR),
+            PATIENT,
+            BloodType::APositive,
+            b"QmYwAPJzv5CZsnAzt8auVTLFa".to_vec(),
+        ));
+        pallet_access_control::UserRoles::<Test>::insert(PATIENT, Role::Patient);
+        assert_ok!(AccessControl::grant_emergency_access(
+            RuntimeOrigin::signed(NURSE),
+            PATIENT,
+            [3u8; 32],
+        ));
+        assert_ok!(AccessControl::set_consent_directives(
+            RuntimeOrigin::signed(PATIENT),
+            PATIENT,
+            ConsentDirectives {
+                share_emergency_subset: false,
+                ..Default::default()
+            },
+        ));
+        assert_noop!(
+            MedicalRecords::record_access(RuntimeOrigin::signed(NURSE), PATIENT),
+            Error::<Test>::ConsentWithheld
+        );
+
+        // A signed provider cannot appoint, and no one can appoint a provider
+        assert_noop!(
+            AccessControl::appoint_guardian(
+                RuntimeOrigin::signed(DOCTOR),
+                PATIENT,
+                GUARDIAN,
+                GuardianRelationship::NextOfKin,
+                GuardianScopes::ALL,
+                None,
+            ),
+            pallet_access_control::Error::<Test>::NotAuthorized
+        );
+        for origin in [RuntimeOrigin::root(), RuntimeOrigin::signed(PATIENT)] {
+            assert_noop!(
+                AccessControl::appoint_guardian(
+                    origin,
+                    PATIENT,
+                    NURSE,
+                    GuardianRelationship::NextOfKin,
+                    GuardianScopes::ALL,
+                    None,
+                ),
+                pallet_access_control::Error::<Test>::GuardianIsProvider
+            );
+        }
+        assert_noop!(
+            MedicalRecords::record_access(RuntimeOrigin::signed(NURSE), PATIENT),
+            Error::<Test>::ConsentWithheld
+        );
+    });
+}
+
+/// Test reads fail without a role or without a valid grant
+#[test]
+fn record_access_fails_without_role_or_grant() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(MedicalRecords::create_health_record(
+            RuntimeOrigin::signed(DOCTOR),
+            PATIENT,
+            BloodType::APositive,
+            b"QmYwAPJzv5CZsnAzt8auVTLFa".to_vec(),
+        ));
+
+        // Patient without a role
+        assert_noop!(
+            MedicalRecords::record_access(RuntimeOrigin::signed(PATIENT), PATIENT),
+            Error::<Test>::NoRoleAssigned
+        );
+        assert_noop!(
+            MedicalRecords::record_access(RuntimeOrigin::signed(UNAUTHORIZED), PATIENT),
+            Error::<Test>::NoRoleAssigned
+        );
+        // Even the record's author needs a grant to read it
+        assert_noop!(
+            MedicalRecords::record_access(RuntimeOrigin::signed(DOCTOR), PATIENT),
+            Error::<Test>::NoValidAccess
+        );
+    });
+}
+
+/// Test reads fail once the grant is revoked, and for missing records
+#[test]
+fn record_access_fails_after_revocation() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(AccessControl::grant_emergency_access(
+            RuntimeOrigin::signed(DOCTOR),
+            PATIENT,
+            [3u8; 32],
+        ));
+        assert_noop!(
+            MedicalRecords::record_access(RuntimeOrigin::signed(DOCTOR), PATIENT),
+            Error::<Test>::RecordNotFound
+        );
+
+        assert_ok!(MedicalRecords::create_health_record(
+            RuntimeOrigin::signed(DOCTOR),
+            PATIENT,
+            BloodType::APositive,
+            b"QmYwAPJzv5CZsnAzt8auVTLFa".to_vec(),
+        ));
+        assert_ok!(AccessControl::revoke_access(
+            RuntimeOrigin::signed(DOCTOR),
+            PATIENT,
+            DOCTOR,
+        ));
+        assert_noop!(
+            MedicalRecords::record_access(RuntimeOrigin::signed(DOCTOR), PATIENT),
+            Error::<Test>::NoValidAccess
+        );
+    });
+}
+
+// ============================================================================
+// Document Index Tests
+// ============================================================================
+
+/// Test documents get sequential ids per patient and keep their metadata
+#[test]
+fn add_document_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(4);
+        assert_ok!(MedicalRecords::add_document(
+            RuntimeOrigin::signed(DOCTOR),
+            PATIENT,
+            DocumentType::LabResult,
+            b"QmLabContent".to_vec(),
+            b"QmLabMetadata".to_vec(),
+            [7u8; 32],
+        ));
+        assert_ok!(MedicalRecords::add_document(
+            RuntimeOrigin::signed(NURSE),
+            PATIENT,
+            DocumentType::Imaging,
+            b"QmScanContent".to_vec(),
+            b"QmScanMetadata".to_vec(),
+            [8u8; 32],
+        ));
+
+        let lab = MedicalRecords::documents(PATIENT, 0).unwrap();
+        assert_eq!(lab.content_cid.to_vec(), b"QmLabContent".to_vec());
+        assert_eq!(lab.metadata_cid.to_vec(), b"QmLabMetadata".to_vec());
+        assert_eq!(lab.document_type, DocumentType::LabResult);
+        assert_eq!(lab.checksum, [7u8; 32]);
+        assert_eq!(lab.uploaded_by, DOCTOR);
+        assert_eq!(lab.uploaded_at, 4);
+        assert_eq!(MedicalRecords::document_count(PATIENT), 2);
+
+        System::assert_last_event(
+            Event::DocumentAdded {
+                patient: PATIENT,
+                document_id: 1,
+                document_type: DocumentType::Imaging,
+                uploaded_by: NURSE,
+            }
+            .into(),
+        );
+
+        let page = MedicalRecords::patient_documents(&PATIENT, 1, 10);
+        assert_eq!(page.len(), 1);
+        assert_eq!(page[0].0, 1);
+        assert_eq!(page[0].1.document_type, DocumentType::Imaging);
+    });
+}
+
+/// Test only healthcare providers can add documents
+#[test]
+fn add_document_fails_for_non_provider() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            MedicalRecords::add_document(
+                RuntimeOrigin::signed(UNAUTHORIZED),
+                PATIENT,
+                DocumentType::Prescription,
+                b"QmContent".to_vec(),
+                b"QmMetadata".to_vec(),
+                [1u8; 32],
+            ),
+            Error::<Test>::NotHealthcareProvider
+        );
+    });
+}
+
+/// Test lab technicians add lab results but no other kind of document
+#[test]
+fn add_document_lab_result_needs_add_lab_result() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(MedicalRecords::add_document(
+            RuntimeOrigin::signed(LAB_TECH),
+            PATIENT,
+            DocumentType::LabResult,
+            b"QmLabContent".to_vec(),
+            b"QmLabMetadata".to_vec(),
+            [3u8; 32],
+        ));
+        assert_eq!(
+            MedicalRecords::documents(PATIENT, 0).unwrap().uploaded_by,
+            LAB_TECH
+        );
+
+        assert_noop!(
+            MedicalRecords::add_document(
+                RuntimeOrigin::signed(LAB_TECH),
+                PATIENT,
+                DocumentType::ClinicalNote,
+                b"QmContent".to_vec(),
+                b"QmMetadata".to_vec(),
+                [1u8; 32],
+            ),
+            Error::<Test>::NotHealthcareProvider
+        );
+        // Pharmacists hold neither permission
+        assert_noop!(
+            MedicalRecords::add_document(
+                RuntimeOrigin::signed(PHARMACIST),
+                PATIENT,
+                DocumentType::LabResult,
+                b"QmContent".to_vec(),
+                b"QmMetadata".to_vec(),
+                [1u8; 32],
+            ),
+            Error::<Test>::NotHealthcareProvider
+        );
+    });
+}
+
+/// Test a patient cannot hold more than MaxDocuments documents
+#[test]
+fn add_document_fails_when_full() {
+    new_test_ext().execute_with(|| {
+        // Rule 2: bounded by MAX_DOCUMENTS
+        for _ in 0..MAX_DOCUMENTS {
+            assert_ok!(MedicalRecords::add_document(
+                RuntimeOrigin::signed(DOCTOR),
+                PATIENT,
+                DocumentType::ClinicalNote,
+                b"QmContent".to_vec(),
+                b"QmMetadata".to_vec(),
+                [1u8; 32],
+            ));
+        }
+
+        assert_noop!(
+            MedicalRecords::add_document(
+                RuntimeOrigin::signed(DOCTOR),
+                PATIENT,
+                DocumentType::ClinicalNote,
+                b"QmContent".to_vec(),
+                b"QmMetadata".to_vec(),
+                [1u8; 32],
+            ),
+            Error::<Test>::TooManyDocuments
+        );
+    });
+}
@@ -0,0 +1,496 @@
+//! Weights for `pallet_medical_records`
+//!
+//! Layout follows the `frame-benchmarking-cli` output so the file can be
+//! regenerated in place from `benchmarking.rs`:
+//!
+//! ```text
+//! frame-omni-bencher v1 benchmark pallet \
+//!     --runtime target/release/wbuild/medichain-runtime/medichain_runtime.compact.compressed.wasm \
+//!     --pallet pallet_medical_records --extrinsic "*" \
+//!     --output pallets/medical-records/src/weights.rs
+//! ```
+//!
+//! Storage reads/writes per call match the accesses in `lib.rs`
+//! (NASA Power of 10: Rule 2 - every call has a fixed upper bound).
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_medical_records`.
+pub trait WeightInfo {
+	fn create_health_record() -> Weight;
+	fn add_alert() -> Weight;
+	fn update_ipfs_hash() -> Weight;
+	fn add_document() -> Weight;
+	fn update_alert() -> Weight;
+	fn resolve_alert() -> Weight;
+	fn remove_alert() -> Weight;
+	fn update_blood_type() -> Weight;
+	fn record_access() -> Weight;
+}
+
+/// Weights for `pallet_medical_records` using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `AccessControl::UserRoles` (r:1 w:0)
+	/// Proof: `AccessControl::UserRoles` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::Credentials` (r:1 w:0)
+	/// Proof: `AccessControl::Credentials` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::ActiveFacility` (r:1 w:0)
+	/// Proof: `AccessControl::ActiveFacility` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::FacilityStaff` (r:1 w:0)
+	/// Proof: `AccessControl::FacilityStaff` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `MedicalRecords::HealthRecords` (r:1 w:1)
+	/// Proof: `MedicalRecords::HealthRecords` (`max_values`: None, `max_size`: Some(1129), added: 3604, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLogLength` (r:1 w:1)
+	/// Proof: `AccessControl::AuditLogLength` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLog` (r:0 w:2)
+	/// Proof: `AccessControl::AuditLog` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	/// Storage: `PatientIdentity::Identities` (r:1 w:0)
+	/// Proof: `PatientIdentity::Identities` (`max_values`: None, `max_size`: Some(499), added: 2974, mode: `MaxEncodedLen`)
+	fn create_health_record() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `126`
+		//  Estimated: `4590`
+		Weight::from_parts(25_000_000, 4590)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `AccessControl::UserRoles` (r:1 w:0)
+	/// Proof: `AccessControl::UserRoles` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::Credentials` (r:1 w:0)
+	/// Proof: `AccessControl::Credentials` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::ActiveFacility` (r:1 w:0)
+	/// Proof: `AccessControl::ActiveFacility` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::FacilityStaff` (r:1 w:0)
+	/// Proof: `AccessControl::FacilityStaff` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `MedicalRecords::HealthRecords` (r:1 w:1)
+	/// Proof: `MedicalRecords::HealthRecords` (`max_values`: None, `max_size`: Some(1129), added: 3604, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLogLength` (r:1 w:1)
+	/// Proof: `AccessControl::AuditLogLength` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLog` (r:0 w:2)
+	/// Proof: `AccessControl::AuditLog` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	/// Storage: `PatientIdentity::Identities` (r:1 w:0)
+	/// Proof: `PatientIdentity::Identities` (`max_values`: None, `max_size`: Some(499), added: 2974, mode: `MaxEncodedLen`)
+	fn add_alert() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1001`
+		//  Estimated: `4590`
+		Weight::from_parts(27_000_000, 4590)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `AccessControl::UserRoles` (r:1 w:0)
+	/// Proof: `AccessControl::UserRoles` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::Credentials` (r:1 w:0)
+	/// Proof: `AccessControl::Credentials` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::ActiveFacility` (r:1 w:0)
+	/// Proof: `AccessControl::ActiveFacility` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::FacilityStaff` (r:1 w:0)
+	/// Proof: `AccessControl::FacilityStaff` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `MedicalRecords::HealthRecords` (r:1 w:1)
+	/// Proof: `MedicalRecords::HealthRecords` (`max_values`: None, `max_size`: Some(1129), added: 3604, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLogLength` (r:1 w:1)
+	/// Proof: `AccessControl::AuditLogLength` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLog` (r:0 w:2)
+	/// Proof: `AccessControl::AuditLog` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	/// Storage: `MedicalRecords::RecordHistory` (r:0 w:2)
+	/// Proof: `MedicalRecords::RecordHistory` (`max_values`: None, `max_size`: Some(165), added: 2640, mode: `MaxEncodedLen`)
+	/// Storage: `PatientIdentity::Identities` (r:1 w:0)
+	/// Proof: `PatientIdentity::Identities` (`max_values`: None, `max_size`: Some(499), added: 2974, mode: `MaxEncodedLen`)
+	fn update_ipfs_hash() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1046`
+		//  Estimated: `4590`
+		Weight::from_parts(30_000_000, 4590)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `AccessControl::UserRoles` (r:1 w:0)
+	/// Proof: `AccessControl::UserRoles` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::Credentials` (r:1 w:0)
+	/// Proof: `AccessControl::Credentials` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::ActiveFacility` (r:1 w:0)
+	/// Proof: `AccessControl::ActiveFacility` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::FacilityStaff` (r:1 w:0)
+	/// Proof: `AccessControl::FacilityStaff` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `MedicalRecords::DocumentCount` (r:1 w:1)
+	/// Proof: `MedicalRecords::DocumentCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLogLength` (r:1 w:1)
+	/// Proof: `AccessControl::AuditLogLength` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLog` (r:0 w:2)
+	/// Proof: `AccessControl::AuditLog` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	/// Storage: `MedicalRecords::Documents` (r:0 w:1)
+	/// Proof: `MedicalRecords::Documents` (`max_values`: None, `max_size`: Some(259), added: 2734, mode: `MaxEncodedLen`)
+	/// Storage: `PatientIdentity::Identities` (r:1 w:0)
+	/// Proof: `PatientIdentity::Identities` (`max_values`: None, `max_size`: Some(499), added: 2974, mode: `MaxEncodedLen`)
+	fn add_document() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `126`
+		//  Estimated: `3590`
+		Weight::from_parts(26_000_000, 3590)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `AccessControl::UserRoles` (r:1 w:0)
+	/// Proof: `AccessControl::UserRoles` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::Credentials` (r:1 w:0)
+	/// Proof: `AccessControl::Credentials` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::ActiveFacility` (r:1 w:0)
+	/// Proof: `AccessControl::ActiveFacility` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::FacilityStaff` (r:1 w:0)
+	/// Proof: `AccessControl::FacilityStaff` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `MedicalRecords::HealthRecords` (r:1 w:1)
+	/// Proof: `MedicalRecords::HealthRecords` (`max_values`: None, `max_size`: Some(1129), added: 3604, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLogLength` (r:1 w:1)
+	/// Proof: `AccessControl::AuditLogLength` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLog` (r:0 w:2)
+	/// Proof: `AccessControl::AuditLog` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	/// Storage: `PatientIdentity::Identities` (r:1 w:0)
+	/// Proof: `PatientIdentity::Identities` (`max_values`: None, `max_size`: Some(499), added: 2974, mode: `MaxEncodedLen`)
+	fn update_alert() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1046`
+		//  Estimated: `4590`
+		Weight::from_parts(28_000_000, 4590)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `AccessControl::UserRoles` (r:1 w:0)
+	/// Proof: `AccessControl::UserRoles` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::Credentials` (r:1 w:0)
+	/// Proof: `AccessControl::Credentials` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::ActiveFacility` (r:1 w:0)
+	/// Proof: `AccessControl::ActiveFacility` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::FacilityStaff` (r:1 w:0)
+	/// Proof: `AccessControl::FacilityStaff` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `MedicalRecords::HealthRecords` (r:1 w:1)
+	/// Proof: `MedicalRecords::HealthRecords` (`max_values`: None, `max_size`: Some(1129), added: 3604, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLogLength` (r:1 w:1)
+	/// Proof: `AccessControl::AuditLogLength` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLog` (r:0 w:2)
+	/// Proof: `AccessControl::AuditLog` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	/// Storage: `PatientIdentity::Identities` (r:1 w:0)
+	/// Proof: `PatientIdentity::Identities` (`max_values`: None, `max_size`: Some(499), added: 2974, mode: `MaxEncodedLen`)
+	fn resolve_alert() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1046`
+		//  Estimated: `4590`
+		Weight::from_parts(27_000_000, 4590)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `AccessControl::UserRoles` (r:1 w:0)
+	/// Proof: `AccessControl::UserRoles` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::Credentials` (r:1 w:0)
+	/// Proof: `AccessControl::Credentials` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::ActiveFacility` (r:1 w:0)
+	/// Proof: `AccessControl::ActiveFacility` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::FacilityStaff` (r:1 w:0)
+	/// Proof: `AccessControl::FacilityStaff` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `MedicalRecords::HealthRecords` (r:1 w:1)
+	/// Proof: `MedicalRecords::HealthRecords` (`max_values`: None, `max_size`: Some(1129), added: 3604, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLogLength` (r:1 w:1)
+	/// Proof: `AccessControl::AuditLogLength` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLog` (r:0 w:2)
+	/// Proof: `AccessControl::AuditLog` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	/// Storage: `PatientIdentity::Identities` (r:1 w:0)
+	/// Proof: `PatientIdentity::Identities` (`max_values`: None, `max_size`: Some(499), added: 2974, mode: `MaxEncodedLen`)
+	fn remove_alert() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1046`
+		//  Estimated: `4590`
+		Weight::from_parts(27_000_000, 4590)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `AccessControl::UserRoles` (r:1 w:0)
+	/// Proof: `AccessControl::UserRoles` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::Credentials` (r:1 w:0)
+	/// Proof: `AccessControl::Credentials` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::ActiveFacility` (r:1 w:0)
+	/// Proof: `AccessControl::ActiveFacility` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::FacilityStaff` (r:1 w:0)
+	/// Proof: `AccessControl::FacilityStaff` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `MedicalRecords::HealthRecords` (r:1 w:1)
+	/// Proof: `MedicalRecords::HealthRecords` (`max_values`: None, `max_size`: Some(1129), added: 3604, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLogLength` (r:1 w:1)
+	/// Proof: `AccessControl::AuditLogLength` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLog` (r:0 w:2)
+	/// Proof: `AccessControl::AuditLog` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	/// Storage: `PatientIdentity::Identities` (r:1 w:0)
+	/// Proof: `PatientIdentity::Identities` (`max_values`: None, `max_size`: Some(499), added: 2974, mode: `MaxEncodedLen`)
+	fn update_blood_type() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1046`
+		//  Estimated: `4590`
+		Weight::from_parts(26_000_000, 4590)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `AccessControl::Guardians` (r:1 w:0)
+	/// Proof: `AccessControl::Guardians` (`max_values`: None, `max_size`: Some(141), added: 2616, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::UserRoles` (r:1 w:0)
+	/// Proof: `AccessControl::UserRoles` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::Credentials` (r:1 w:0)
+	/// Proof: `AccessControl::Credentials` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::ActiveFacility` (r:1 w:0)
+	/// Proof: `AccessControl::ActiveFacility` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::FacilityStaff` (r:1 w:0)
+	/// Proof: `AccessControl::FacilityStaff` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::ActiveAccess` (r:1 w:0)
+	/// Proof: `AccessControl::ActiveAccess` (`max_values`: None, `max_size`: Some(179), added: 2654, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::Consents` (r:1 w:0)
+	/// Proof: `AccessControl::Consents` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `MedicalRecords::HealthRecords` (r:1 w:0)
+	/// Proof: `MedicalRecords::HealthRecords` (`max_values`: None, `max_size`: Some(1129), added: 3604, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLogLength` (r:1 w:1)
+	/// Proof: `AccessControl::AuditLogLength` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLog` (r:0 w:2)
+	/// Proof: `AccessControl::AuditLog` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	/// Storage: `PatientIdentity::Identities` (r:1 w:0)
+	/// Proof: `PatientIdentity::Identities` (`max_values`: None, `max_size`: Some(499), added: 2974, mode: `MaxEncodedLen`)
+	fn record_access() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `4590`
+		Weight::from_parts(25_000_000, 4590)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `AccessControl::UserRoles` (r:1 w:0)
+	/// Proof: `AccessControl::UserRoles` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::Credentials` (r:1 w:0)
+	/// Proof: `AccessControl::Credentials` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::ActiveFacility` (r:1 w:0)
+	/// Proof: `AccessControl::ActiveFacility` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::FacilityStaff` (r:1 w:0)
+	/// Proof: `AccessControl::FacilityStaff` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `MedicalRecords::HealthRecords` (r:1 w:1)
+	/// Proof: `MedicalRecords::HealthRecords` (`max_values`: None, `max_size`: Some(1129), added: 3604, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLogLength` (r:1 w:1)
+	/// Proof: `AccessControl::AuditLogLength` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLog` (r:0 w:2)
+	/// Proof: `AccessControl::AuditLog` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	/// Storage: `PatientIdentity::Identities` (r:1 w:0)
+	/// Proof: `PatientIdentity::Identities` (`max_values`: None, `max_size`: Some(499), added: 2974, mode: `MaxEncodedLen`)
+	fn create_health_record() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `126`
+		//  Estimated: `4590`
+		Weight::from_parts(25_000_000, 4590)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `AccessControl::UserRoles` (r:1 w:0)
+	/// Proof: `AccessControl::UserRoles` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::Credentials` (r:1 w:0)
+	/// Proof: `AccessControl::Credentials` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::ActiveFacility` (r:1 w:0)
+	/// Proof: `AccessControl::ActiveFacility` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::FacilityStaff` (r:1 w:0)
+	/// Proof: `AccessControl::FacilityStaff` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `MedicalRecords::HealthRecords` (r:1 w:1)
+	/// Proof: `MedicalRecords::HealthRecords` (`max_values`: None, `max_size`: Some(1129), added: 3604, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLogLength` (r:1 w:1)
+	/// Proof: `AccessControl::AuditLogLength` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLog` (r:0 w:2)
+	/// Proof: `AccessControl::AuditLog` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	/// Storage: `PatientIdentity::Identities` (r:1 w:0)
+	/// Proof: `PatientIdentity::Identities` (`max_values`: None, `max_size`: Some(499), added: 2974, mode: `MaxEncodedLen`)
+	fn add_alert() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1001`
+		//  Estimated: `4590`
+		Weight::from_parts(27_000_000, 4590)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `AccessControl::UserRoles` (r:1 w:0)
+	/// Proof: `AccessControl::UserRoles` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::Credentials` (r:1 w:0)
+	/// Proof: `AccessControl::Credentials` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::ActiveFacility` (r:1 w:0)
+	/// Proof: `AccessControl::ActiveFacility` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::FacilityStaff` (r:1 w:0)
+	/// Proof: `AccessControl::FacilityStaff` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `MedicalRecords::HealthRecords` (r:1 w:1)
+	/// Proof: `MedicalRecords::HealthRecords` (`max_values`: None, `max_size`: Some(1129), added: 3604, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLogLength` (r:1 w:1)
+	/// Proof: `AccessControl::AuditLogLength` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLog` (r:0 w:2)
+	/// Proof: `AccessControl::AuditLog` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	/// Storage: `MedicalRecords::RecordHistory` (r:0 w:2)
+	/// Proof: `MedicalRecords::RecordHistory` (`max_values`: None, `max_size`: Some(165), added: 2640, mode: `MaxEncodedLen`)
+	/// Storage: `PatientIdentity::Identities` (r:1 w:0)
+	/// Proof: `PatientIdentity::Identities` (`max_values`: None, `max_size`: Some(499), added: 2974, mode: `MaxEncodedLen`)
+	fn update_ipfs_hash() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1046`
+		//  Estimated: `4590`
+		Weight::from_parts(30_000_000, 4590)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `AccessControl::UserRoles` (r:1 w:0)
+	/// Proof: `AccessControl::UserRoles` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::Credentials` (r:1 w:0)
+	/// Proof: `AccessControl::Credentials` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::ActiveFacility` (r:1 w:0)
+	/// Proof: `AccessControl::ActiveFacility` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::FacilityStaff` (r:1 w:0)
+	/// Proof: `AccessControl::FacilityStaff` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `MedicalRecords::DocumentCount` (r:1 w:1)
+	/// Proof: `MedicalRecords::DocumentCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLogLength` (r:1 w:1)
+	/// Proof: `AccessControl::AuditLogLength` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLog` (r:0 w:2)
+	/// Proof: `AccessControl::AuditLog` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	/// Storage: `MedicalRecords::Documents` (r:0 w:1)
+	/// Proof: `MedicalRecords::Documents` (`max_values`: None, `max_size`: Some(259), added: 2734, mode: `MaxEncodedLen`)
+	/// Storage: `PatientIdentity::Identities` (r:1 w:0)
+	/// Proof: `PatientIdentity::Identities` (`max_values`: None, `max_size`: Some(499), added: 2974, mode: `MaxEncodedLen`)
+	fn add_document() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `126`
+		//  Estimated: `3590`
+		Weight::from_parts(26_000_000, 3590)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `AccessControl::UserRoles` (r:1 w:0)
+	/// Proof: `AccessControl::UserRoles` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::Credentials` (r:1 w:0)
+	/// Proof: `AccessControl::Credentials` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::ActiveFacility` (r:1 w:0)
+	/// Proof: `AccessControl::ActiveFacility` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::FacilityStaff` (r:1 w:0)
+	/// Proof: `AccessControl::FacilityStaff` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `MedicalRecords::HealthRecords` (r:1 w:1)
+	/// Proof: `MedicalRecords::HealthRecords` (`max_values`: None, `max_size`: Some(1129), added: 3604, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLogLength` (r:1 w:1)
+	/// Proof: `AccessControl::AuditLogLength` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLog` (r:0 w:2)
+	/// Proof: `AccessControl::AuditLog` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	/// Storage: `PatientIdentity::Identities` (r:1 w:0)
+	/// Proof: `PatientIdentity::Identities` (`max_values`: None, `max_size`: Some(499), added: 2974, mode: `MaxEncodedLen`)
+	fn update_alert() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1046`
+		//  Estimated: `4590`
+		Weight::from_parts(28_000_000, 4590)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `AccessControl::UserRoles` (r:1 w:0)
+	/// Proof: `AccessControl::UserRoles` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::Credentials` (r:1 w:0)
+	/// Proof: `AccessControl::Credentials` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::ActiveFacility` (r:1 w:0)
+	/// Proof: `AccessControl::ActiveFacility` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::FacilityStaff` (r:1 w:0)
+	/// Proof: `AccessControl::FacilityStaff` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `MedicalRecords::HealthRecords` (r:1 w:1)
+	/// Proof: `MedicalRecords::HealthRecords` (`max_values`: None, `max_size`: Some(1129), added: 3604, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLogLength` (r:1 w:1)
+	/// Proof: `AccessControl::AuditLogLength` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLog` (r:0 w:2)
+	/// Proof: `AccessControl::AuditLog` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	/// Storage: `PatientIdentity::Identities` (r:1 w:0)
+	/// Proof: `PatientIdentity::Identities` (`max_values`: None, `max_size`: Some(499), added: 2974, mode: `MaxEncodedLen`)
+	fn resolve_alert() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1046`
+		//  Estimated: `4590`
+		Weight::from_parts(27_000_000, 4590)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `AccessControl::UserRoles` (r:1 w:0)
+	/// Proof: `AccessControl::UserRoles` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::Credentials` (r:1 w:0)
+	/// Proof: `AccessControl::Credentials` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::ActiveFacility` (r:1 w:0)
+	/// Proof: `AccessControl::ActiveFacility` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::FacilityStaff` (r:1 w:0)
+	/// Proof: `AccessControl::FacilityStaff` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `MedicalRecords::HealthRecords` (r:1 w:1)
+	/// Proof: `MedicalRecords::HealthRecords` (`max_values`: None, `max_size`: Some(1129), added: 3604, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLogLength` (r:1 w:1)
+	/// Proof: `AccessControl::AuditLogLength` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLog` (r:0 w:2)
+	/// Proof: `AccessControl::AuditLog` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	/// Storage: `PatientIdentity::Identities` (r:1 w:0)
+	/// Proof: `PatientIdentity::Identities` (`max_values`: None, `max_size`: Some(499), added: 2974, mode: `MaxEncodedLen`)
+	fn remove_alert() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1046`
+		//  Estimated: `4590`
+		Weight::from_parts(27_000_000, 4590)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `AccessControl::UserRoles` (r:1 w:0)
+	/// Proof: `AccessControl::UserRoles` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::Credentials` (r:1 w:0)
+	/// Proof: `AccessControl::Credentials` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::ActiveFacility` (r:1 w:0)
+	/// Proof: `AccessControl::ActiveFacility` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::FacilityStaff` (r:1 w:0)
+	/// Proof: `AccessControl::FacilityStaff` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `MedicalRecords::HealthRecords` (r:1 w:1)
+	/// Proof: `MedicalRecords::HealthRecords` (`max_values`: None, `max_size`: Some(1129), added: 3604, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLogLength` (r:1 w:1)
+	/// Proof: `AccessControl::AuditLogLength` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLog` (r:0 w:2)
+	/// Proof: `AccessControl::AuditLog` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	/// Storage: `PatientIdentity::Identities` (r:1 w:0)
+	/// Proof: `PatientIdentity::Identities` (`max_values`: None, `max_size`: Some(499), added: 2974, mode: `MaxEncodedLen`)
+	fn update_blood_type() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1046`
+		//  Estimated: `4590`
+		Weight::from_parts(26_000_000, 4590)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `AccessControl::Guardians` (r:1 w:0)
+	/// Proof: `AccessControl::Guardians` (`max_values`: None, `max_size`: Some(141), added: 2616, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::UserRoles` (r:1 w:0)
+	/// Proof: `AccessControl::UserRoles` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::Credentials` (r:1 w:0)
+	/// Proof: `AccessControl::Credentials` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::ActiveFacility` (r:1 w:0)
+	/// Proof: `AccessControl::ActiveFacility` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::FacilityStaff` (r:1 w:0)
+	/// Proof: `AccessControl::FacilityStaff` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::ActiveAccess` (r:1 w:0)
+	/// Proof: `AccessControl::ActiveAccess` (`max_values`: None, `max_size`: Some(179), added: 2654, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::Consents` (r:1 w:0)
+	/// Proof: `AccessControl::Consents` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `MedicalRecords::HealthRecords` (r:1 w:0)
+	/// Proof: `MedicalRecords::HealthRecords` (`max_values`: None, `max_size`: Some(1129), added: 3604, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLogLength` (r:1 w:1)
+	/// Proof: `AccessControl::AuditLogLength` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::AuditLog` (r:0 w:2)
+	/// Proof: `AccessControl::AuditLog` (`max_values`: None, `max_size`: Some(130), added: 2605, mode: `MaxEncodedLen`)
+	/// Storage: `PatientIdentity::Identities` (r:1 w:0)
+	/// Proof: `PatientIdentity::Identities` (`max_values`: None, `max_size`: Some(499), added: 2974, mode: `MaxEncodedLen`)
+	fn record_access() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `4590`
+		Weight::from_parts(25_000_000, 4590)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+}
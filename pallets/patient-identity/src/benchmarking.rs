@@ -0,0 +1,243 @@
+//! Benchmarks for patient-identity pallet
+//!
+//! Each benchmark exercises the worst-case storage path of its extrinsic.
+//! Run with `cargo test -p pallet-patient-identity --features runtime-benchmarks`.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet as PatientIdentity;
+use frame_benchmarking::v2::*;
+use frame_support::traits::Get;
+use frame_system::RawOrigin;
+use pallet_access_control::{AccessPermissions, Role};
+
+/// Create a doctor and register `patient` with `id_hash`
+fn setup_identity<T: Config>(patient: &T::AccountId, id_hash: [u8; 32]) -> T::AccountId {
+    let doctor: T::AccountId = whitelisted_caller();
+    T::AccessPermissions::set_role(&doctor, Role::Doctor);
+    PatientIdentity::<T>::register_patient(
+        RawOrigin::Signed(doctor.clone()).into(),
+        patient.clone(),
+        NationalIdType::FaydaID,
+        id_hash,
+    )
+    .expect("doctor can register patient");
+    doctor
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn register_patient() {
+        let doctor: T::AccountId = whitelisted_caller();
+        T::AccessPermissions::set_role(&doctor, Role::Doctor);
+        let patient: T::AccountId = account("patient", 0, 0);
+        let id_hash = [7u8; 32];
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(doctor),
+            patient.clone(),
+            NationalIdType::FaydaID,
+            id_hash,
+        );
+
+        assert_eq!(
+            IdToAccount::<T>::get(NationalIdType::FaydaID, id_hash),
+            Some(patient.clone())
+        );
+        assert_eq!(
+            T::AccessPermissions::role(&patient, &patient),
+            Some(Role::Patient)
+        );
+    }
+
+    #[benchmark]
+    fn verify_identity() {
+        let doctor: T::AccountId = whitelisted_caller();
+        T::AccessPermissions::set_role(&doctor, Role::Doctor);
+        let patient: T::AccountId = account("patient", 0, 0);
+        PatientIdentity::<T>::register_patient(
+            RawOrigin::Signed(doctor.clone()).into(),
+            patient.clone(),
+            NationalIdType::FaydaID,
+            [7u8; 32],
+        )
+        .expect("doctor can register patient");
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(doctor),
+            patient.clone(),
+            VerificationLevel::Biometric,
+            [9u8; 32],
+        );
+
+        assert_eq!(
+            PatientIdentity::<T>::verification_level(&patient),
+            Some(VerificationLevel::Biometric)
+        );
+    }
+
+    #[benchmark]
+    fn update_id_hash() {
+        let patient: T::AccountId = account("patient", 0, 0);
+        let doctor = setup_identity::<T>(&patient, [7u8; 32]);
+        let new_id_hash = [8u8; 32];
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(doctor),
+            patient.clone(),
+            NationalIdType::FaydaID,
+            new_id_hash,
+        );
+
+        assert_eq!(
+            IdToAccount::<T>::get(NationalIdType::FaydaID, new_id_hash),
+            Some(patient)
+        );
+        assert!(IdToAccount::<T>::get(NationalIdType::FaydaID, [7u8; 32]).is_none());
+    }
+
+    #[benchmark]
+    fn merge_identities(n: Linear<1, { T::MaxLinkedIds::get() }>) {
+        let duplicate: T::AccountId = account("patient", 0, 0);
+        let survivor: T::AccountId = account("patient", 1, 0);
+        let doctor = setup_identity::<T>(&duplicate, [7u8; 32]);
+        setup_identity::<T>(&survivor, [8u8; 32]);
+        // Worst case: the duplicate holds n IDs (one per type, after the Fayda ID)
+        for id_type in NationalIdType::ALL.iter().skip(1).take(n as usize - 1) {
+            PatientIdentity::<T>::link_national_id(
+                RawOrigin::Signed(doctor.clone()).into(),
+                duplicate.clone(),
+                *id_type,
+                [7u8; 32],
+            )
+            .expect("doctor can link IDs");
+        }
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(doctor),
+            duplicate.clone(),
+            survivor.clone(),
+        );
+
+        assert_eq!(
+            IdToAccount::<T>::get(NationalIdType::FaydaID, [7u8; 32]),
+            Some(survivor)
+        );
+    }
+
+    #[benchmark]
+    fn deactivate_identity() {
+        let patient: T::AccountId = account("patient", 0, 0);
+        let doctor = setup_identity::<T>(&patient, [7u8; 32]);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(doctor), patient.clone());
+
+        assert!(Identities::<T>::get(&patient)
+            .is_some_and(|identity| identity.status == IdentityStatus::Inactive));
+    }
+
+    #[benchmark]
+    fn register_death() {
+        let patient: T::AccountId = account("patient", 0, 0);
+        let doctor = setup_identity::<T>(&patient, [7u8; 32]);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(doctor), patient.clone());
+
+        assert!(Identities::<T>::get(&patient)
+            .is_some_and(|identity| identity.status == IdentityStatus::Deceased));
+    }
+
+    #[benchmark]
+    fn link_national_id() {
+        let patient: T::AccountId = account("patient", 0, 0);
+        let doctor = setup_identity::<T>(&patient, [7u8; 32]);
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(doctor),
+            patient.clone(),
+            NationalIdType::Passport,
+            [7u8; 32],
+        );
+
+        assert_eq!(
+            IdToAccount::<T>::get(NationalIdType::Passport, [7u8; 32]),
+            Some(patient)
+        );
+    }
+
+    #[benchmark]
+    fn migrate_id_hash() {
+        let patient: T::AccountId = account("patient", 0, 0);
+        let doctor = setup_identity::<T>(&patient, [7u8; 32]);
+        // The ID still carries the legacy scheme version
+        Identities::<T>::mutate(&patient, |identity| {
+            if let Some(identity) = identity {
+                identity.national_ids[0].hash_version = LEGACY_HASH_VERSION;
+            }
+        });
+        LegacyIdCount::<T>::put(1);
+
+        #[extrinsic_call]
+        _(
+            RawOrigin::Signed(doctor),
+            patient.clone(),
+            NationalIdType::FaydaID,
+            [8u8; 32],
+        );
+
+        assert_eq!(
+            IdToAccount::<T>::get(NationalIdType::FaydaID, [8u8; 32]),
+            Some(patient)
+        );
+    }
+
+    #[benchmark]
+    fn purge_legacy_ids(n: Linear<1, { T::MaxLinkedIds::get() }>) -> Result<(), BenchmarkError> {
+        let patient: T::AccountId = account("patient", 0, 0);
+        let doctor = setup_identity::<T>(&patient, [7u8; 32]);
+        // Worst case: n IDs, all still on the legacy scheme
+        for id_type in NationalIdType::ALL.iter().skip(1).take(n as usize - 1) {
+            PatientIdentity::<T>::link_national_id(
+                RawOrigin::Signed(doctor.clone()).into(),
+                patient.clone(),
+                *id_type,
+                [7u8; 32],
+            )
+            .expect("doctor can link IDs");
+        }
+        Identities::<T>::mutate(&patient, |identity| {
+            if let Some(identity) = identity {
+                for id in identity.national_ids.iter_mut() {
+                    id.hash_version = LEGACY_HASH_VERSION;
+                }
+            }
+        });
+        LegacyIdCount::<T>::put(n);
+        let origin =
+            T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, patient.clone());
+
+        assert_eq!(LegacyIdCount::<T>::get(), 0);
+        assert!(IdToAccount::<T>::get(NationalIdType::FaydaID, [7u8; 32]).is_none());
+        Ok(())
+    }
+
+    impl_benchmark_test_suite!(
+        PatientIdentity,
+        crate::mock::new_test_ext(),
+        crate::mock::Test
+    );
+}
@@ -1,12 +1,30 @@
 //! # Patient Identity Pallet
 //!
 //! MediChain National Health ID registration and verification.
-//! Supports: Fayda ID (Ethiopia), Ghana Card, NIN (Nigeria), Smart ID (South Africa)
+//! Supports: Fayda ID (Ethiopia), Ghana Card, NIN (Nigeria), Smart ID (South Africa),
+//! Huduma Namba (Kenya), passports, NHIS numbers and refugee IDs.
+//!
+//! A patient can hold up to `MaxLinkedIds` IDs, at most one per `NationalIdType`.
+//! `IdToAccount` is keyed by `(id_type, id_hash)`, so a cross-border patient
+//! presenting any of their linked IDs resolves to the same account.
+//!
+//! ## ID Hashing
+//! `id_hash` is derived off-chain with `medichain_crypto::hash_national_id`
+//! (HMAC-SHA256 keyed with a registry pepper that never touches the chain).
+//! Each linked ID records the scheme version it was hashed with; IDs still on
+//! the legacy scheme (unkeyed SHA-256, version 1) are re-hashed with
+//! `migrate_id_hash` the next time the patient presents the ID. `LegacyIdCount`
+//! tracks how many remain; `AdminOrigin` purges the rest with `purge_legacy_ids`.
 //!
 //! ## IMPORTANT: Access Control
 //! - Patients CANNOT self-register
 //! - Only healthcare providers (Doctor, Nurse, Admin) can register patients
 //! - This ensures patients are registered in clinical settings
+//! - Lifecycle changes (ID rotation, merge, deactivation, death) need `VerifyIdentity`
+//! - Inactive, deceased and merged patients cannot be granted access or have
+//!   their records read or written (checked through `PatientStatus`)
+//! - Verification is attested per `VerificationLevel`; which roles may attest which
+//!   level is fixed by `VerificationLevel::attestable_by`
 //!
 //! ## NASA Power of 10 Compliance
 //! - Rule 1: No recursion
@@ -16,25 +34,57 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+pub mod migrations;
 pub mod mock;
 pub mod tests;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+
 pub use pallet::*;
+pub use weights::WeightInfo;
 
 #[frame_support::pallet]
 pub mod pallet {
+    use crate::WeightInfo;
     use frame_support::pallet_prelude::*;
     use frame_system::pallet_prelude::*;
+    use pallet_access_control::{AccessPermissions, Permission, Role};
 
-    /// Maximum length for national ID (Rule 2: bounded)
-    pub const MAX_ID_LENGTH: u32 = 64;
     /// Maximum length for name fields
     pub const MAX_NAME_LENGTH: u32 = 128;
 
+    /// Current storage version
+    /// (1: identities carry a lifecycle status, 2: multiple linked IDs keyed by type,
+    /// 3: linked IDs record their hash scheme version, 4: verification levels)
+    pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(4);
+
+    /// Hash scheme of every ID registered before hash versioning: unkeyed SHA-256
+    /// (`medichain_crypto::NATIONAL_ID_HASH_V1`)
+    pub const LEGACY_HASH_VERSION: u8 = 1;
+
     /// Supported national ID types across Africa
-    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    ///
+    /// This is the canonical list shared by the API and `client/wasm-crypto`;
+    /// `code()` is the domain string mixed into the ID hash.
+    #[derive(
+        Clone,
+        Copy,
+        Encode,
+        Decode,
+        Eq,
+        PartialEq,
+        RuntimeDebug,
+        TypeInfo,
+        MaxEncodedLen,
+        Default,
+        serde::Serialize,
+        serde::Deserialize,
+    )]
     pub enum NationalIdType {
         /// Ethiopia's Fayda Digital ID
+        #[default]
         FaydaID,
         /// Ghana's National ID Card
         GhanaCard,
@@ -42,39 +92,257 @@ pub mod pallet {
         NIN,
         /// South Africa's Smart ID Card
         SmartID,
+        /// Kenya's Huduma Namba
+        KenyaHuduma,
+        /// Passport number
+        Passport,
+        /// National Health Insurance Scheme number
+        NHIS,
+        /// UNHCR / host-country refugee ID
+        RefugeeID,
+        /// Any other government-issued ID
+        Other,
+    }
+
+    impl NationalIdType {
+        /// Every supported type, in encoding order
+        pub const ALL: [NationalIdType; 9] = [
+            NationalIdType::FaydaID,
+            NationalIdType::GhanaCard,
+            NationalIdType::NIN,
+            NationalIdType::SmartID,
+            NationalIdType::KenyaHuduma,
+            NationalIdType::Passport,
+            NationalIdType::NHIS,
+            NationalIdType::RefugeeID,
+            NationalIdType::Other,
+        ];
+
+        /// Legacy names accepted by `from_code` besides the canonical codes
+        const ALIASES: [(&'static str, NationalIdType); 8] = [
+            ("faydaid", NationalIdType::FaydaID),
+            ("ethiopia", NationalIdType::FaydaID),
+            ("ghana", NationalIdType::GhanaCard),
+            ("ghanacard", NationalIdType::GhanaCard),
+            ("nigeria", NationalIdType::NIN),
+            ("smartid", NationalIdType::SmartID),
+            ("southafrica", NationalIdType::SmartID),
+            ("kenya", NationalIdType::KenyaHuduma),
+        ];
+
+        /// Stable lowercase code, used as the hashing domain for `id_hash`
+        pub fn code(&self) -> &'static str {
+            match self {
+                NationalIdType::FaydaID => "fayda",
+                NationalIdType::GhanaCard => "ghana_card",
+                NationalIdType::NIN => "nin",
+                NationalIdType::SmartID => "smart_id",
+                NationalIdType::KenyaHuduma => "huduma",
+                NationalIdType::Passport => "passport",
+                NationalIdType::NHIS => "nhis",
+                NationalIdType::RefugeeID => "refugee_id",
+                NationalIdType::Other => "other",
+            }
+        }
+
+        /// Parse a canonical code or legacy alias (ASCII case-insensitive)
+        pub fn from_code(code: &str) -> Option<Self> {
+            // Rule 2: bounded by ALL.len() + ALIASES.len()
+            Self::ALL
+                .iter()
+                .map(|id_type| (id_type.code(), *id_type))
+                .chain(Self::ALIASES.iter().copied())
+                .find(|(name, _)| name.eq_ignore_ascii_case(code))
+                .map(|(_, id_type)| id_type)
+        }
+
+        /// ISO 3166-1 alpha-3 code of the issuing country (`XXX` if not country-specific)
+        pub fn country_code(&self) -> &'static str {
+            match self {
+                NationalIdType::FaydaID => "ETH",
+                NationalIdType::GhanaCard => "GHA",
+                NationalIdType::NIN => "NGA",
+                NationalIdType::SmartID => "ZAF",
+                NationalIdType::KenyaHuduma => "KEN",
+                NationalIdType::Passport
+                | NationalIdType::NHIS
+                | NationalIdType::RefugeeID
+                | NationalIdType::Other => "XXX",
+            }
+        }
+
+        /// Human-readable name
+        pub fn display_name(&self) -> &'static str {
+            match self {
+                NationalIdType::FaydaID => "Fayda ID (Ethiopia)",
+                NationalIdType::GhanaCard => "Ghana Card",
+                NationalIdType::NIN => "NIN (Nigeria)",
+                NationalIdType::SmartID => "Smart ID (South Africa)",
+                NationalIdType::KenyaHuduma => "Huduma Namba (Kenya)",
+                NationalIdType::Passport => "Passport",
+                NationalIdType::NHIS => "NHIS Number",
+                NationalIdType::RefugeeID => "Refugee ID",
+                NationalIdType::Other => "Other ID",
+            }
+        }
+    }
+
+    /// One ID linked to an identity
+    #[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct NationalId {
+        /// Type of the ID
+        pub id_type: NationalIdType,
+        /// Keyed hash of the ID (never store plaintext)
+        pub id_hash: [u8; 32],
+        /// Hash scheme `id_hash` was derived with (see `Config::IdHashVersion`)
+        pub hash_version: u8,
+    }
+
+    /// Lifecycle status of an identity
+    #[derive(
+        Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default,
+    )]
+    pub enum IdentityStatus {
+        /// In use
+        #[default]
+        Active,
+        /// Deactivated (e.g. emigrated, lost to follow-up)
+        Inactive,
+        /// Patient has died (terminal)
+        Deceased,
+        /// Duplicate merged into another identity (terminal, see `merged_into`)
+        Merged,
     }
 
-    impl Default for NationalIdType {
-        fn default() -> Self {
-            NationalIdType::FaydaID
+    /// How strongly an identity has been established (ordered, lowest first)
+    #[derive(
+        Clone,
+        Copy,
+        Encode,
+        Decode,
+        Eq,
+        PartialEq,
+        Ord,
+        PartialOrd,
+        RuntimeDebug,
+        TypeInfo,
+        MaxEncodedLen,
+        Default,
+        serde::Serialize,
+        serde::Deserialize,
+    )]
+    pub enum VerificationLevel {
+        /// Only the details given at registration
+        #[default]
+        SelfAsserted,
+        /// Physical ID document inspected by a provider
+        DocumentChecked,
+        /// Biometric match (fingerprint, face) against the ID
+        Biometric,
+        /// Confirmed against the issuing national registry
+        RegistryConfirmed,
+    }
+
+    impl VerificationLevel {
+        /// Attestation policy: whether `role` may attest this level
+        ///
+        /// | Level             | Admin | Doctor | Nurse |
+        /// |-------------------|-------|--------|-------|
+        /// | DocumentChecked   | ✓     | ✓      | ✓     |
+        /// | Biometric         | ✓     | ✓      |       |
+        /// | RegistryConfirmed | ✓     |        |       |
+        ///
+        /// `SelfAsserted` is the registration baseline and is never attested.
+        pub fn attestable_by(&self, role: Role) -> bool {
+            match self {
+                VerificationLevel::SelfAsserted => false,
+                VerificationLevel::DocumentChecked => {
+                    matches!(role, Role::Admin | Role::Doctor | Role::Nurse)
+                }
+                VerificationLevel::Biometric => matches!(role, Role::Admin | Role::Doctor),
+                VerificationLevel::RegistryConfirmed => matches!(role, Role::Admin),
+            }
         }
     }
 
+    /// Latest verifier attestation for an identity
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(T))]
+    pub struct Attestation<T: Config> {
+        /// Level attested
+        pub level: VerificationLevel,
+        /// Provider who attested it
+        pub verifier: T::AccountId,
+        /// Block of the attestation
+        pub attested_at: BlockNumberFor<T>,
+        /// Hash of the evidence kept off-chain (document scan, biometric template ref, ...)
+        pub evidence_hash: [u8; 32],
+    }
+
     /// Identity struct stored on-chain
     #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
     #[scale_info(skip_type_params(T))]
     pub struct Identity<T: Config> {
         /// Account that owns this identity
         pub owner: T::AccountId,
-        /// Type of national ID
-        pub id_type: NationalIdType,
-        /// Blake2_256 hash of national ID (never store plaintext)
-        pub id_hash: [u8; 32],
-        /// Whether identity has been verified
-        pub verified: bool,
+        /// Linked IDs, at most one per type (the first is the one registered with)
+        pub national_ids: BoundedVec<NationalId, T::MaxLinkedIds>,
+        /// Current verification level
+        pub verification_level: VerificationLevel,
+        /// Attestation that set `verification_level` (None while self-asserted,
+        /// or for verifications recorded before attestations existed)
+        pub attestation: Option<Attestation<T>>,
         /// Block number when registered
         pub registered_at: BlockNumberFor<T>,
         /// Who registered this patient (healthcare provider)
         pub registered_by: T::AccountId,
+        /// Lifecycle status
+        pub status: IdentityStatus,
+        /// Block when `status` last changed (None while never changed)
+        pub status_changed_at: Option<BlockNumberFor<T>>,
+        /// Surviving identity, once this duplicate has been merged
+        pub merged_into: Option<T::AccountId>,
+    }
+
+    impl<T: Config> Identity<T> {
+        /// The linked ID of `id_type`, if any
+        pub fn national_id(&self, id_type: NationalIdType) -> Option<&NationalId> {
+            self.national_ids.iter().find(|id| id.id_type == id_type)
+        }
     }
 
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     #[pallet::config]
-    pub trait Config: frame_system::Config + pallet_access_control::Config {
+    pub trait Config: frame_system::Config {
         /// The overarching event type
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// Weight information for extrinsics in this pallet
+        type WeightInfo: WeightInfo;
+
+        /// Role checks and Patient role assignment (the access-control pallet)
+        type AccessPermissions: AccessPermissions<Self::AccountId>;
+
+        /// Origin allowed to purge IDs still hashed with the legacy scheme
+        ///
+        /// Wire this to the ministry council in the runtime.
+        type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Maximum length for a plaintext national ID before hashing (Rule 2: bounded)
+        #[pallet::constant]
+        type MaxIdLength: Get<u32>;
+
+        /// Maximum number of IDs linked to one identity (Rule 2: bounded)
+        #[pallet::constant]
+        type MaxLinkedIds: Get<u32>;
+
+        /// Hash scheme version that newly submitted ID hashes are derived with
+        /// (`medichain_crypto::NATIONAL_ID_HASH_VERSION`)
+        #[pallet::constant]
+        type IdHashVersion: Get<u8>;
     }
 
     /// Storage: Map account to identity
@@ -83,11 +351,25 @@ pub mod pallet {
     pub type Identities<T: Config> =
         StorageMap<_, Blake2_128Concat, T::AccountId, Identity<T>, OptionQuery>;
 
-    /// Storage: Map ID hash to account (for reverse lookup)
+    /// Storage: Map (ID type, ID hash) to account (for reverse lookup)
     #[pallet::storage]
     #[pallet::getter(fn id_to_account)]
-    pub type IdToAccount<T: Config> =
-        StorageMap<_, Blake2_128Concat, [u8; 32], T::AccountId, OptionQuery>;
+    pub type IdToAccount<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        NationalIdType,
+        Blake2_128Concat,
+        [u8; 32],
+        T::AccountId,
+        OptionQuery,
+    >;
+
+    /// Storage: Number of linked IDs still hashed with `LEGACY_HASH_VERSION`
+    ///
+    /// Reaches zero once every legacy ID has been migrated or purged.
+    #[pallet::storage]
+    #[pallet::getter(fn legacy_id_count)]
+    pub type LegacyIdCount<T: Config> = StorageValue<_, u32, ValueQuery>;
 
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
@@ -99,11 +381,54 @@ pub mod pallet {
             id_hash: [u8; 32],
             registered_by: T::AccountId,
         },
-        /// Identity verified [who, verifier]
+        /// Identity verified at a level [who, verifier, level, evidence_hash]
         IdentityVerified {
             who: T::AccountId,
             verifier: T::AccountId,
+            level: VerificationLevel,
+            evidence_hash: [u8; 32],
         },
+        /// Additional ID linked to an identity [who, id_type, id_hash, linked_by]
+        NationalIdLinked {
+            who: T::AccountId,
+            id_type: NationalIdType,
+            id_hash: [u8; 32],
+            linked_by: T::AccountId,
+        },
+        /// National ID re-issued, reverse lookup moved [who, id_type, old_id_hash, new_id_hash, rotated_by]
+        IdHashRotated {
+            who: T::AccountId,
+            id_type: NationalIdType,
+            old_id_hash: [u8; 32],
+            new_id_hash: [u8; 32],
+            rotated_by: T::AccountId,
+        },
+        /// ID re-hashed with the current scheme [who, id_type, from_version, to_version, migrated_by]
+        IdHashMigrated {
+            who: T::AccountId,
+            id_type: NationalIdType,
+            from_version: u8,
+            to_version: u8,
+            migrated_by: T::AccountId,
+        },
+        /// Duplicate identity merged into the surviving one [duplicate, survivor, merged_by]
+        IdentitiesMerged {
+            duplicate: T::AccountId,
+            survivor: T::AccountId,
+            merged_by: T::AccountId,
+        },
+        /// Identity deactivated [who, deactivated_by]
+        IdentityDeactivated {
+            who: T::AccountId,
+            deactivated_by: T::AccountId,
+        },
+        /// Patient's death registered [who, registered_by]
+        DeathRegistered {
+            who: T::AccountId,
+            registered_by: T::AccountId,
+        },
+        /// IDs still on the legacy hash scheme removed from an identity [who, count]
+        LegacyIdsPurged { who: T::AccountId, count: u32 },
     }
 
     #[pallet::error]
@@ -120,8 +445,32 @@ pub mod pallet {
         NotAuthorizedToVerify,
         /// Invalid ID format
         InvalidIdFormat,
-        /// ID too long (exceeds MAX_ID_LENGTH)
+        /// ID too long (exceeds MaxIdLength)
         IdTooLong,
+        /// Identity is inactive, deceased or merged
+        IdentityNotActive,
+        /// Status change not allowed from the current status
+        InvalidStatusTransition,
+        /// An identity cannot be merged into itself
+        CannotMergeIntoSelf,
+        /// Identity already has an ID of this type
+        IdTypeAlreadyLinked,
+        /// Identity has no ID of this type
+        IdTypeNotLinked,
+        /// Identity already has MaxLinkedIds IDs
+        TooManyLinkedIds,
+        /// ID is not hashed with the legacy scheme
+        IdHashAlreadyCurrent,
+        /// Old hash does not resolve to the identity being migrated
+        IdLookupMismatch,
+        /// Identity has no IDs on the legacy hash scheme
+        NoLegacyIds,
+        /// Caller's role may not attest this verification level
+        LevelNotAllowedForRole,
+        /// Identity is already verified at this level or higher
+        VerificationLevelNotHigher,
+        /// Providers cannot attest their own identity
+        CannotAttestOwnIdentity,
     }
 
     #[pallet::call]
@@ -135,14 +484,14 @@ pub mod pallet {
         /// # Arguments
         /// * `patient` - Account to register as patient
         /// * `id_type` - Type of national ID (FaydaID, GhanaCard, etc.)
-        /// * `id_hash` - Pre-computed Blake2_256 hash of the national ID
+        /// * `id_hash` - Keyed hash of the national ID (current `IdHashVersion` scheme)
         ///
         /// # Errors
         /// * `NotHealthcareProvider` - Caller is not a Doctor, Nurse, or Admin
         /// * `AlreadyRegistered` - Account already has an identity
-        /// * `IdAlreadyLinked` - ID hash linked to another account
+        /// * `IdAlreadyLinked` - ID of this type and hash linked to another account
         #[pallet::call_index(0)]
-        #[pallet::weight(Weight::from_parts(10_000, 0))]
+        #[pallet::weight(<T as Config>::WeightInfo::register_patient())]
         pub fn register_patient(
             origin: OriginFor<T>,
             patient: T::AccountId,
@@ -153,7 +502,11 @@ pub mod pallet {
 
             // CRITICAL: Only healthcare providers can register patients
             ensure!(
-                pallet_access_control::Pallet::<T>::can_register_patients(&registrar),
+                T::AccessPermissions::has_permission(
+                    &registrar,
+                    &patient,
+                    Permission::RegisterPatient
+                ),
                 Error::<T>::NotHealthcareProvider
             );
 
@@ -163,38 +516,39 @@ pub mod pallet {
                 Error::<T>::AlreadyRegistered
             );
             ensure!(
-                !IdToAccount::<T>::contains_key(&id_hash),
+                !IdToAccount::<T>::contains_key(id_type, id_hash),
                 Error::<T>::IdAlreadyLinked
             );
 
             let current_block = <frame_system::Pallet<T>>::block_number();
+            let mut national_ids = BoundedVec::new();
+            national_ids
+                .try_push(NationalId {
+                    id_type,
+                    id_hash,
+                    hash_version: T::IdHashVersion::get(),
+                })
+                .map_err(|_| Error::<T>::TooManyLinkedIds)?;
 
             let identity = Identity {
                 owner: patient.clone(),
-                id_type: id_type.clone(),
-                id_hash,
-                verified: false,
+                national_ids,
+                verification_level: VerificationLevel::SelfAsserted,
+                attestation: None,
                 registered_at: current_block,
                 registered_by: registrar.clone(),
+                status: IdentityStatus::Active,
+                status_changed_at: None,
+                merged_into: None,
             };
 
             // Store identity
             Identities::<T>::insert(&patient, identity);
-            IdToAccount::<T>::insert(&id_hash, &patient);
+            IdToAccount::<T>::insert(id_type, id_hash, &patient);
 
             // Also assign Patient role to the new patient account
-            // (This will fail if they already have a role, which is fine)
-            let _ = pallet_access_control::UserRoles::<T>::try_mutate(
-                &patient,
-                |maybe_role| -> Result<(), ()> {
-                    if maybe_role.is_none() {
-                        *maybe_role = Some(pallet_access_control::Role::Patient);
-                        Ok(())
-                    } else {
-                        Err(())
-                    }
-                },
-            );
+            // (accounts that already hold a role keep it)
+            T::AccessPermissions::ensure_patient_role(&patient);
 
             Self::deposit_event(Event::PatientRegistered {
                 patient,
@@ -206,42 +560,512 @@ pub mod pallet {
             Ok(())
         }
 
-        /// Verify an identity (authorized verifiers only)
+        /// Attest an identity at a verification level
         ///
-        /// Only healthcare providers can verify patient identities.
+        /// The level must be higher than the current one, so an identity cannot be
+        /// re-verified indefinitely at the same level. Which roles may attest which
+        /// level is set by `VerificationLevel::attestable_by`.
         ///
         /// # Arguments
         /// * `target` - Account to verify
+        /// * `level` - Level established by this check
+        /// * `evidence_hash` - Hash of the off-chain evidence
         ///
         /// # Errors
-        /// * `NotAuthorizedToVerify` - Caller is not a healthcare provider
+        /// * `NotAuthorizedToVerify` - Caller lacks the `VerifyIdentity` permission
+        /// * `LevelNotAllowedForRole` - Caller's role may not attest `level`
+        /// * `CannotAttestOwnIdentity` - Caller is `target`
         /// * `IdentityNotFound` - Target has no registered identity
+        /// * `IdentityNotActive` - Identity is inactive, deceased or merged
+        /// * `VerificationLevelNotHigher` - Identity is already at `level` or above
         #[pallet::call_index(1)]
-        #[pallet::weight(Weight::from_parts(10_000, 0))]
-        pub fn verify_identity(origin: OriginFor<T>, target: T::AccountId) -> DispatchResult {
-            let verifier = ensure_signed(origin)?;
+        #[pallet::weight(<T as Config>::WeightInfo::verify_identity())]
+        pub fn verify_identity(
+            origin: OriginFor<T>,
+            target: T::AccountId,
+            level: VerificationLevel,
+            evidence_hash: [u8; 32],
+        ) -> DispatchResult {
+            let verifier = Self::ensure_verifier(origin, &target)?;
+            let role = T::AccessPermissions::role(&verifier, &target)
+                .ok_or(Error::<T>::NotAuthorizedToVerify)?;
+            ensure!(
+                level.attestable_by(role),
+                Error::<T>::LevelNotAllowedForRole
+            );
+            ensure!(verifier != target, Error::<T>::CannotAttestOwnIdentity);
+
+            let current_block = <frame_system::Pallet<T>>::block_number();
+            Identities::<T>::try_mutate(&target, |maybe_identity| -> DispatchResult {
+                let identity = maybe_identity
+                    .as_mut()
+                    .ok_or(Error::<T>::IdentityNotFound)?;
+                ensure!(
+                    identity.status == IdentityStatus::Active,
+                    Error::<T>::IdentityNotActive
+                );
+                ensure!(
+                    level > identity.verification_level,
+                    Error::<T>::VerificationLevelNotHigher
+                );
+
+                identity.verification_level = level;
+                identity.attestation = Some(Attestation {
+                    level,
+                    verifier: verifier.clone(),
+                    attested_at: current_block,
+                    evidence_hash,
+                });
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::IdentityVerified {
+                who: target,
+                verifier,
+                level,
+                evidence_hash,
+            });
+
+            Ok(())
+        }
+
+        /// Rotate one of a patient's ID hashes after the ID is re-issued
+        ///
+        /// The reverse lookup moves from the old hash to the new one in the same call.
+        ///
+        /// # Arguments
+        /// * `target` - Patient whose ID was re-issued
+        /// * `id_type` - Type of the re-issued ID (must already be linked)
+        /// * `new_id_hash` - Keyed hash of the new ID (current `IdHashVersion` scheme)
+        ///
+        /// # Errors
+        /// * `NotAuthorizedToVerify` - Caller lacks the `VerifyIdentity` permission
+        /// * `IdentityNotFound` - Target has no registered identity
+        /// * `IdentityNotActive` - Identity is inactive, deceased or merged
+        /// * `IdTypeNotLinked` - Target has no ID of `id_type`
+        /// * `IdAlreadyLinked` - New hash is linked to an account
+        #[pallet::call_index(2)]
+        #[pallet::weight(<T as Config>::WeightInfo::update_id_hash())]
+        pub fn update_id_hash(
+            origin: OriginFor<T>,
+            target: T::AccountId,
+            id_type: NationalIdType,
+            new_id_hash: [u8; 32],
+        ) -> DispatchResult {
+            let verifier = Self::ensure_verifier(origin, &target)?;
+            ensure!(
+                !IdToAccount::<T>::contains_key(id_type, new_id_hash),
+                Error::<T>::IdAlreadyLinked
+            );
+
+            let old_id_hash = Identities::<T>::try_mutate(
+                &target,
+                |maybe_identity| -> Result<_, DispatchError> {
+                    let identity = maybe_identity
+                        .as_mut()
+                        .ok_or(Error::<T>::IdentityNotFound)?;
+                    ensure!(
+                        identity.status == IdentityStatus::Active,
+                        Error::<T>::IdentityNotActive
+                    );
 
-            // Only healthcare providers can verify
+                    // Rule 2: bounded by MaxLinkedIds
+                    let linked = identity
+                        .national_ids
+                        .iter_mut()
+                        .find(|id| id.id_type == id_type)
+                        .ok_or(Error::<T>::IdTypeNotLinked)?;
+                    // A re-issued ID is hashed afresh, so a legacy hash is gone
+                    if linked.hash_version == LEGACY_HASH_VERSION {
+                        LegacyIdCount::<T>::mutate(|count| *count = count.saturating_sub(1));
+                    }
+                    linked.hash_version = T::IdHashVersion::get();
+                    Ok(core::mem::replace(&mut linked.id_hash, new_id_hash))
+                },
+            )?;
+
+            IdToAccount::<T>::remove(id_type, old_id_hash);
+            IdToAccount::<T>::insert(id_type, new_id_hash, &target);
+
+            Self::deposit_event(Event::IdHashRotated {
+                who: target,
+                id_type,
+                old_id_hash,
+                new_id_hash,
+                rotated_by: verifier,
+            });
+
+            Ok(())
+        }
+
+        /// Merge a duplicate registration into the surviving identity
+        ///
+        /// The duplicate is kept as `Merged` for audit, and every ID linked to it
+        /// resolves to the survivor from then on. IDs of a type the survivor has
+        /// no ID of move to the survivor while it has room for them; the rest
+        /// stay listed on the duplicate.
+        ///
+        /// # Arguments
+        /// * `duplicate` - Identity registered twice by mistake
+        /// * `survivor` - Identity that remains in use
+        ///
+        /// # Errors
+        /// * `NotAuthorizedToVerify` - Caller lacks the `VerifyIdentity` permission
+        /// * `CannotMergeIntoSelf` - `duplicate` and `survivor` are the same account
+        /// * `IdentityNotFound` - Either account has no registered identity
+        /// * `IdentityNotActive` - Either identity is inactive, deceased or merged
+        #[pallet::call_index(3)]
+        #[pallet::weight(<T as Config>::WeightInfo::merge_identities(T::MaxLinkedIds::get()))]
+        pub fn merge_identities(
+            origin: OriginFor<T>,
+            duplicate: T::AccountId,
+            survivor: T::AccountId,
+        ) -> DispatchResult {
+            let verifier = Self::ensure_verifier(origin, &survivor)?;
             ensure!(
-                pallet_access_control::Pallet::<T>::is_healthcare_provider(&verifier),
+                T::AccessPermissions::has_permission(
+                    &verifier,
+                    &duplicate,
+                    Permission::VerifyIdentity
+                ),
                 Error::<T>::NotAuthorizedToVerify
             );
+            ensure!(duplicate != survivor, Error::<T>::CannotMergeIntoSelf);
+
+            let mut survivor_identity =
+                Identities::<T>::get(&survivor).ok_or(Error::<T>::IdentityNotFound)?;
+            let mut duplicate_identity =
+                Identities::<T>::get(&duplicate).ok_or(Error::<T>::IdentityNotFound)?;
+            ensure!(
+                survivor_identity.status == IdentityStatus::Active
+                    && duplicate_identity.status == IdentityStatus::Active,
+                Error::<T>::IdentityNotActive
+            );
+
+            // Lookups by any of the duplicate's IDs now resolve to the survivor
+            // Rule 2: bounded by MaxLinkedIds
+            for id in duplicate_identity.national_ids.iter() {
+                IdToAccount::<T>::insert(id.id_type, id.id_hash, &survivor);
+            }
+
+            // Rule 2: bounded by MaxLinkedIds
+            duplicate_identity.national_ids.retain(|id| {
+                survivor_identity.national_id(id.id_type).is_some()
+                    || survivor_identity.national_ids.try_push(*id).is_err()
+            });
+            duplicate_identity.status = IdentityStatus::Merged;
+            duplicate_identity.status_changed_at = Some(<frame_system::Pallet<T>>::block_number());
+            duplicate_identity.merged_into = Some(survivor.clone());
+
+            Identities::<T>::insert(&survivor, survivor_identity);
+            Identities::<T>::insert(&duplicate, duplicate_identity);
+
+            Self::deposit_event(Event::IdentitiesMerged {
+                duplicate,
+                survivor,
+                merged_by: verifier,
+            });
+
+            Ok(())
+        }
+
+        /// Deactivate an identity (e.g. patient emigrated)
+        ///
+        /// # Arguments
+        /// * `target` - Identity to deactivate
+        ///
+        /// # Errors
+        /// * `NotAuthorizedToVerify` - Caller lacks the `VerifyIdentity` permission
+        /// * `IdentityNotFound` - Target has no registered identity
+        /// * `InvalidStatusTransition` - Identity is not active
+        #[pallet::call_index(4)]
+        #[pallet::weight(<T as Config>::WeightInfo::deactivate_identity())]
+        pub fn deactivate_identity(origin: OriginFor<T>, target: T::AccountId) -> DispatchResult {
+            let verifier = Self::ensure_verifier(origin, &target)?;
+
+            Self::transition_status(&target, IdentityStatus::Inactive, |status| {
+                *status == IdentityStatus::Active
+            })?;
+
+            Self::deposit_event(Event::IdentityDeactivated {
+                who: target,
+                deactivated_by: verifier,
+            });
+
+            Ok(())
+        }
+
+        /// Register a patient's death
+        ///
+        /// Deceased is terminal: the identity can no longer be rotated, merged or deactivated.
+        ///
+        /// # Arguments
+        /// * `target` - Identity of the deceased patient
+        ///
+        /// # Errors
+        /// * `NotAuthorizedToVerify` - Caller lacks the `VerifyIdentity` permission
+        /// * `IdentityNotFound` - Target has no registered identity
+        /// * `InvalidStatusTransition` - Identity is already deceased or merged
+        #[pallet::call_index(5)]
+        #[pallet::weight(<T as Config>::WeightInfo::register_death())]
+        pub fn register_death(origin: OriginFor<T>, target: T::AccountId) -> DispatchResult {
+            let verifier = Self::ensure_verifier(origin, &target)?;
+
+            Self::transition_status(&target, IdentityStatus::Deceased, |status| {
+                matches!(status, IdentityStatus::Active | IdentityStatus::Inactive)
+            })?;
+
+            Self::deposit_event(Event::DeathRegistered {
+                who: target,
+                registered_by: verifier,
+            });
+
+            Ok(())
+        }
+
+        /// Link an additional ID (passport, NHIS number, refugee ID, ...) to an identity
+        ///
+        /// # Arguments
+        /// * `target` - Patient the ID belongs to
+        /// * `id_type` - Type of the ID (at most one per type)
+        /// * `id_hash` - Keyed hash of the ID (current `IdHashVersion` scheme)
+        ///
+        /// # Errors
+        /// * `NotAuthorizedToVerify` - Caller lacks the `VerifyIdentity` permission
+        /// * `IdAlreadyLinked` - ID of this type and hash linked to an account
+        /// * `IdentityNotFound` - Target has no registered identity
+        /// * `IdentityNotActive` - Identity is inactive, deceased or merged
+        /// * `IdTypeAlreadyLinked` - Target already has an ID of `id_type`
+        /// * `TooManyLinkedIds` - Target already has `MaxLinkedIds` IDs
+        #[pallet::call_index(6)]
+        #[pallet::weight(<T as Config>::WeightInfo::link_national_id())]
+        pub fn link_national_id(
+            origin: OriginFor<T>,
+            target: T::AccountId,
+            id_type: NationalIdType,
+            id_hash: [u8; 32],
+        ) -> DispatchResult {
+            let verifier = Self::ensure_verifier(origin, &target)?;
+            ensure!(
+                !IdToAccount::<T>::contains_key(id_type, id_hash),
+                Error::<T>::IdAlreadyLinked
+            );
 
-            // Get and update identity
             Identities::<T>::try_mutate(&target, |maybe_identity| -> DispatchResult {
                 let identity = maybe_identity
                     .as_mut()
                     .ok_or(Error::<T>::IdentityNotFound)?;
+                ensure!(
+                    identity.status == IdentityStatus::Active,
+                    Error::<T>::IdentityNotActive
+                );
+                ensure!(
+                    identity.national_id(id_type).is_none(),
+                    Error::<T>::IdTypeAlreadyLinked
+                );
 
-                identity.verified = true;
+                identity
+                    .national_ids
+                    .try_push(NationalId {
+                        id_type,
+                        id_hash,
+                        hash_version: T::IdHashVersion::get(),
+                    })
+                    .map_err(|_| Error::<T>::TooManyLinkedIds)?;
+                Ok(())
+            })?;
 
-                Self::deposit_event(Event::IdentityVerified {
-                    who: target.clone(),
-                    verifier,
-                });
+            IdToAccount::<T>::insert(id_type, id_hash, &target);
+
+            Self::deposit_event(Event::NationalIdLinked {
+                who: target,
+                id_type,
+                id_hash,
+                linked_by: verifier,
+            });
+
+            Ok(())
+        }
+
+        /// Re-hash a linked ID that was derived with the legacy scheme
+        ///
+        /// The verifier recomputes the hash from the ID the patient presents (the
+        /// old hash is found by looking up the legacy derivation), and the reverse
+        /// lookup moves to the new hash in the same call.
+        ///
+        /// # Arguments
+        /// * `target` - Patient the ID belongs to
+        /// * `id_type` - Type of the ID to migrate
+        /// * `new_id_hash` - Hash of the same ID under the current `IdHashVersion` scheme
+        ///
+        /// # Errors
+        /// * `NotAuthorizedToVerify` - Caller lacks the `VerifyIdentity` permission
+        /// * `IdAlreadyLinked` - New hash is linked to an account
+        /// * `IdentityNotFound` - Target has no registered identity
+        /// * `IdentityNotActive` - Identity is inactive, deceased or merged
+        /// * `IdTypeNotLinked` - Target has no ID of `id_type`
+        /// * `IdHashAlreadyCurrent` - The ID is not on `LEGACY_HASH_VERSION`
+        /// * `IdLookupMismatch` - The old hash does not resolve to `target`
+        #[pallet::call_index(7)]
+        #[pallet::weight(<T as Config>::WeightInfo::migrate_id_hash())]
+        pub fn migrate_id_hash(
+            origin: OriginFor<T>,
+            target: T::AccountId,
+            id_type: NationalIdType,
+            new_id_hash: [u8; 32],
+        ) -> DispatchResult {
+            let verifier = Self::ensure_verifier(origin, &target)?;
+            ensure!(
+                !IdToAccount::<T>::contains_key(id_type, new_id_hash),
+                Error::<T>::IdAlreadyLinked
+            );
+
+            let to_version = T::IdHashVersion::get();
+            let old_id_hash = Identities::<T>::try_mutate(
+                &target,
+                |maybe_identity| -> Result<_, DispatchError> {
+                    let identity = maybe_identity
+                        .as_mut()
+                        .ok_or(Error::<T>::IdentityNotFound)?;
+                    ensure!(
+                        identity.status == IdentityStatus::Active,
+                        Error::<T>::IdentityNotActive
+                    );
+
+                    // Rule 2: bounded by MaxLinkedIds
+                    let linked = identity
+                        .national_ids
+                        .iter_mut()
+                        .find(|id| id.id_type == id_type)
+                        .ok_or(Error::<T>::IdTypeNotLinked)?;
+                    ensure!(
+                        linked.hash_version == LEGACY_HASH_VERSION,
+                        Error::<T>::IdHashAlreadyCurrent
+                    );
+                    ensure!(
+                        IdToAccount::<T>::get(id_type, linked.id_hash).as_ref() == Some(&target),
+                        Error::<T>::IdLookupMismatch
+                    );
+
+                    linked.hash_version = to_version;
+                    Ok(core::mem::replace(&mut linked.id_hash, new_id_hash))
+                },
+            )?;
+
+            IdToAccount::<T>::remove(id_type, old_id_hash);
+            IdToAccount::<T>::insert(id_type, new_id_hash, &target);
+            LegacyIdCount::<T>::mutate(|count| *count = count.saturating_sub(1));
+
+            Self::deposit_event(Event::IdHashMigrated {
+                who: target,
+                id_type,
+                from_version: LEGACY_HASH_VERSION,
+                to_version,
+                migrated_by: verifier,
+            });
+
+            Ok(())
+        }
+
+        /// Remove every ID of an identity that is still on the legacy hash scheme
+        ///
+        /// Unkeyed legacy hashes can be brute-forced back to the ID, so once the
+        /// transition period ends the IDs nobody re-presented are purged along
+        /// with their reverse lookups. This applies to identities in any status,
+        /// including merged duplicates.
+        ///
+        /// # Arguments
+        /// * `target` - Identity to purge legacy IDs from
+        ///
+        /// # Errors
+        /// * `BadOrigin` - Origin is not `AdminOrigin`
+        /// * `IdentityNotFound` - Target has no registered identity
+        /// * `NoLegacyIds` - Target has no IDs on `LEGACY_HASH_VERSION`
+        #[pallet::call_index(8)]
+        #[pallet::weight(<T as Config>::WeightInfo::purge_legacy_ids(T::MaxLinkedIds::get()))]
+        pub fn purge_legacy_ids(origin: OriginFor<T>, target: T::AccountId) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            let count = Identities::<T>::try_mutate(
+                &target,
+                |maybe_identity| -> Result<u32, DispatchError> {
+                    let identity = maybe_identity
+                        .as_mut()
+                        .ok_or(Error::<T>::IdentityNotFound)?;
+
+                    let mut count: u32 = 0;
+                    // Rule 2: bounded by MaxLinkedIds
+                    identity.national_ids.retain(|id| {
+                        if id.hash_version != LEGACY_HASH_VERSION {
+                            return true;
+                        }
+                        IdToAccount::<T>::remove(id.id_type, id.id_hash);
+                        count = count.saturating_add(1);
+                        false
+                    });
+                    ensure!(count > 0, Error::<T>::NoLegacyIds);
+                    Ok(count)
+                },
+            )?;
 
+            LegacyIdCount::<T>::mutate(|remaining| *remaining = remaining.saturating_sub(count));
+
+            Self::deposit_event(Event::LegacyIdsPurged { who: target, count });
+
+            Ok(())
+        }
+    }
+
+    // ========================================================================
+    // HELPER FUNCTIONS
+    // ========================================================================
+
+    impl<T: Config> Pallet<T> {
+        /// Verification level of an identity (None if not registered)
+        pub fn verification_level(who: &T::AccountId) -> Option<VerificationLevel> {
+            Identities::<T>::get(who).map(|identity| identity.verification_level)
+        }
+
+        /// Ensure the origin is signed by an account with the `VerifyIdentity`
+        /// permission for `target`
+        fn ensure_verifier(
+            origin: OriginFor<T>,
+            target: &T::AccountId,
+        ) -> Result<T::AccountId, DispatchError> {
+            let verifier = ensure_signed(origin)?;
+            ensure!(
+                T::AccessPermissions::has_permission(&verifier, target, Permission::VerifyIdentity),
+                Error::<T>::NotAuthorizedToVerify
+            );
+            Ok(verifier)
+        }
+
+        /// Move an identity to `next` if `allowed` accepts its current status
+        fn transition_status(
+            target: &T::AccountId,
+            next: IdentityStatus,
+            allowed: impl FnOnce(&IdentityStatus) -> bool,
+        ) -> DispatchResult {
+            Identities::<T>::try_mutate(target, |maybe_identity| -> DispatchResult {
+                let identity = maybe_identity
+                    .as_mut()
+                    .ok_or(Error::<T>::IdentityNotFound)?;
+                ensure!(
+                    allowed(&identity.status),
+                    Error::<T>::InvalidStatusTransition
+                );
+
+                identity.status = next;
+                identity.status_changed_at = Some(<frame_system::Pallet<T>>::block_number());
                 Ok(())
             })
         }
     }
+
+    impl<T: Config> pallet_access_control::PatientStatus<T::AccountId> for Pallet<T> {
+        fn is_active(patient: &T::AccountId) -> bool {
+            Identities::<T>::get(patient)
+                .is_none_or(|identity| identity.status == IdentityStatus::Active)
+        }
+    }
 }
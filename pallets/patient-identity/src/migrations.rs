@@ -0,0 +1,321 @@
+//! Storage migrations for the patient-identity pallet
+//!
+//! Add new migrations as `vN` modules and list them in the runtime's
+//! `Migrations` tuple; `VersionedMigration` makes each one run exactly once.
+//! The chain starts from the layout of the first release (storage version 0).
+
+use crate::{
+    Config, IdToAccount, Identities, Identity, IdentityStatus, LegacyIdCount, NationalId,
+    NationalIdType, Pallet, VerificationLevel, LEGACY_HASH_VERSION,
+};
+use frame_support::{
+    migrations::VersionedMigration, pallet_prelude::*, traits::UncheckedOnRuntimeUpgrade,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_std::vec::Vec;
+
+/// v1: identities carry a lifecycle status
+pub mod v1 {
+    use super::*;
+
+    /// Identity as stored before v1 (first release)
+    #[derive(Encode, Decode)]
+    pub(crate) struct OldIdentity<T: Config> {
+        pub owner: T::AccountId,
+        pub id_type: NationalIdType,
+        pub id_hash: [u8; 32],
+        pub verified: bool,
+        pub registered_at: BlockNumberFor<T>,
+        pub registered_by: T::AccountId,
+    }
+
+    /// Identity as stored at v1
+    #[derive(Encode, Decode)]
+    pub(crate) struct IdentityV1<T: Config> {
+        pub owner: T::AccountId,
+        pub id_type: NationalIdType,
+        pub id_hash: [u8; 32],
+        pub verified: bool,
+        pub registered_at: BlockNumberFor<T>,
+        pub registered_by: T::AccountId,
+        pub status: IdentityStatus,
+        pub status_changed_at: Option<BlockNumberFor<T>>,
+        pub merged_into: Option<T::AccountId>,
+    }
+
+    /// `Identities` with its v1 value type
+    #[frame_support::storage_alias]
+    pub(crate) type Identities<T: Config> = StorageMap<
+        Pallet<T>,
+        Blake2_128Concat,
+        <T as frame_system::Config>::AccountId,
+        IdentityV1<T>,
+        OptionQuery,
+    >;
+
+    /// `IdToAccount` as stored up to v1, keyed by the ID hash alone
+    #[frame_support::storage_alias]
+    pub(crate) type IdToAccount<T: Config> = StorageMap<
+        Pallet<T>,
+        Blake2_128Concat,
+        [u8; 32],
+        <T as frame_system::Config>::AccountId,
+        OptionQuery,
+    >;
+
+    /// Mark every existing identity active
+    ///
+    /// Deactivation, death and merges could not be recorded before v1, so no
+    /// status change is known and `status_changed_at` stays empty.
+    pub struct InnerMigrateV0ToV1<T>(core::marker::PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV0ToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated: u64 = 0;
+
+            // Rule 2: bounded by the number of registered identities (one-off upgrade)
+            Identities::<T>::translate::<OldIdentity<T>, _>(|_, old| {
+                translated = translated.saturating_add(1);
+                Some(IdentityV1 {
+                    owner: old.owner,
+                    id_type: old.id_type,
+                    id_hash: old.id_hash,
+                    verified: old.verified,
+                    registered_at: old.registered_at,
+                    registered_by: old.registered_by,
+                    status: IdentityStatus::Active,
+                    status_changed_at: None,
+                    merged_into: None,
+                })
+            });
+
+            T::DbWeight::get().reads_writes(translated, translated)
+        }
+    }
+
+    /// Runs `InnerMigrateV0ToV1` once, when the on-chain storage version is 0
+    pub type MigrateToV1<T> = VersionedMigration<
+        0,
+        1,
+        InnerMigrateV0ToV1<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// v2: identities hold a list of linked IDs, looked up by `(id_type, id_hash)`
+pub mod v2 {
+    use super::*;
+
+    /// Linked ID as stored at v2
+    #[derive(Encode, Decode)]
+    pub(crate) struct OldNationalId {
+        pub id_type: NationalIdType,
+        pub id_hash: [u8; 32],
+    }
+
+    /// Identity as stored at v2
+    #[derive(Encode, Decode)]
+    pub(crate) struct IdentityV2<T: Config> {
+        pub owner: T::AccountId,
+        pub national_ids: BoundedVec<OldNationalId, T::MaxLinkedIds>,
+        pub verified: bool,
+        pub registered_at: BlockNumberFor<T>,
+        pub registered_by: T::AccountId,
+        pub status: IdentityStatus,
+        pub status_changed_at: Option<BlockNumberFor<T>>,
+        pub merged_into: Option<T::AccountId>,
+    }
+
+    /// `Identities` with its v2 value type
+    #[frame_support::storage_alias]
+    pub(crate) type Identities<T: Config> = StorageMap<
+        Pallet<T>,
+        Blake2_128Concat,
+        <T as frame_system::Config>::AccountId,
+        IdentityV2<T>,
+        OptionQuery,
+    >;
+
+    /// Turn each identity's single ID into its first linked ID and re-key its
+    /// reverse lookup by `(id_type, id_hash)`
+    ///
+    /// The lookup keeps the account it pointed to, so IDs of duplicates merged
+    /// before v2 still resolve to their survivor. Lookups are moved per identity
+    /// rather than by iterating `IdToAccount`, whose old and new keys share a prefix.
+    pub struct InnerMigrateV1ToV2<T>(core::marker::PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV1ToV2<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated: u64 = 0;
+
+            // Rule 2: bounded by the number of registered identities (one-off upgrade)
+            Identities::<T>::translate::<v1::IdentityV1<T>, _>(|_, old| {
+                translated = translated.saturating_add(1);
+                if let Some(account) = v1::IdToAccount::<T>::take(old.id_hash) {
+                    IdToAccount::<T>::insert(old.id_type, old.id_hash, account);
+                }
+
+                Some(IdentityV2 {
+                    owner: old.owner,
+                    // `MaxLinkedIds` is at least one, so nothing is truncated
+                    national_ids: BoundedVec::truncate_from(sp_std::vec![OldNationalId {
+                        id_type: old.id_type,
+                        id_hash: old.id_hash,
+                    }]),
+                    verified: old.verified,
+                    registered_at: old.registered_at,
+                    registered_by: old.registered_by,
+                    status: old.status,
+                    status_changed_at: old.status_changed_at,
+                    merged_into: old.merged_into,
+                })
+            });
+
+            T::DbWeight::get()
+                .reads_writes(translated.saturating_mul(2), translated.saturating_mul(3))
+        }
+    }
+
+    /// Runs `InnerMigrateV1ToV2` once, when the on-chain storage version is 1
+    pub type MigrateToV2<T> = VersionedMigration<
+        1,
+        2,
+        InnerMigrateV1ToV2<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// v3: linked IDs record the hash scheme they were derived with
+pub mod v3 {
+    use super::*;
+
+    /// Identity as stored at v3
+    #[derive(Encode, Decode)]
+    pub(crate) struct IdentityV3<T: Config> {
+        pub owner: T::AccountId,
+        pub national_ids: BoundedVec<NationalId, T::MaxLinkedIds>,
+        pub verified: bool,
+        pub registered_at: BlockNumberFor<T>,
+        pub registered_by: T::AccountId,
+        pub status: IdentityStatus,
+        pub status_changed_at: Option<BlockNumberFor<T>>,
+        pub merged_into: Option<T::AccountId>,
+    }
+
+    /// `Identities` with its v3 value type
+    #[frame_support::storage_alias]
+    pub(crate) type Identities<T: Config> = StorageMap<
+        Pallet<T>,
+        Blake2_128Concat,
+        <T as frame_system::Config>::AccountId,
+        IdentityV3<T>,
+        OptionQuery,
+    >;
+
+    /// Stamp every existing linked ID with `LEGACY_HASH_VERSION`
+    ///
+    /// The hashes themselves cannot be migrated on-chain (the plaintext IDs are
+    /// never stored); they stay resolvable under the legacy scheme until a
+    /// verifier re-hashes them with `migrate_id_hash`. `LegacyIdCount` starts
+    /// out as the number of IDs stamped.
+    pub struct InnerMigrateV2ToV3<T>(core::marker::PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV2ToV3<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated: u64 = 0;
+            let mut legacy_ids: u32 = 0;
+
+            // Rule 2: bounded by the number of registered identities (one-off upgrade)
+            Identities::<T>::translate::<v2::IdentityV2<T>, _>(|_, old| {
+                translated = translated.saturating_add(1);
+                let national_ids: Vec<NationalId> = old
+                    .national_ids
+                    .into_inner()
+                    .into_iter()
+                    .map(|id| NationalId {
+                        id_type: id.id_type,
+                        id_hash: id.id_hash,
+                        hash_version: LEGACY_HASH_VERSION,
+                    })
+                    .collect();
+                legacy_ids = legacy_ids.saturating_add(national_ids.len() as u32);
+
+                Some(IdentityV3 {
+                    owner: old.owner,
+                    // Same bound as before, so nothing is truncated
+                    national_ids: BoundedVec::truncate_from(national_ids),
+                    verified: old.verified,
+                    registered_at: old.registered_at,
+                    registered_by: old.registered_by,
+                    status: old.status,
+                    status_changed_at: old.status_changed_at,
+                    merged_into: old.merged_into,
+                })
+            });
+            LegacyIdCount::<T>::put(legacy_ids);
+
+            T::DbWeight::get().reads_writes(translated, translated.saturating_add(1))
+        }
+    }
+
+    /// Runs `InnerMigrateV2ToV3` once, when the on-chain storage version is 2
+    pub type MigrateToV3<T> = VersionedMigration<
+        2,
+        3,
+        InnerMigrateV2ToV3<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// v4: `verified: bool` becomes a `VerificationLevel` plus attestation
+pub mod v4 {
+    use super::*;
+
+    /// Map v3 `verified = true` to `DocumentChecked` without an attestation
+    ///
+    /// Who verified, when and on what evidence was never recorded, so no
+    /// attestation is invented; the next check must attest a higher level.
+    pub struct InnerMigrateV3ToV4<T>(core::marker::PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV3ToV4<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated: u64 = 0;
+
+            // Rule 2: bounded by the number of registered identities (one-off upgrade)
+            Identities::<T>::translate::<v3::IdentityV3<T>, _>(|_, old| {
+                translated = translated.saturating_add(1);
+                let verification_level = if old.verified {
+                    VerificationLevel::DocumentChecked
+                } else {
+                    VerificationLevel::SelfAsserted
+                };
+
+                Some(Identity {
+                    owner: old.owner,
+                    national_ids: old.national_ids,
+                    verification_level,
+                    attestation: None,
+                    registered_at: old.registered_at,
+                    registered_by: old.registered_by,
+                    status: old.status,
+                    status_changed_at: old.status_changed_at,
+                    merged_into: old.merged_into,
+                })
+            });
+
+            T::DbWeight::get().reads_writes(translated, translated)
+        }
+    }
+
+    /// Runs `InnerMigrateV3ToV4` once, when the on-chain storage version is 3
+    pub type MigrateToV4<T> = VersionedMigration<
+        3,
+        4,
+        InnerMigrateV3ToV4<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
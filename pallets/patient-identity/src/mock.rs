@@ -7,8 +7,9 @@
 use crate as pallet_patient_identity;
 use frame_support::{
     derive_impl,
-    traits::{ConstU16, ConstU64},
+    traits::{ConstU16, ConstU32, ConstU64, ConstU8},
 };
+use frame_system::EnsureRoot;
 use sp_core::H256;
 use sp_runtime::{
     traits::{BlakeTwo256, IdentityLookup},
@@ -50,15 +51,37 @@ impl frame_system::Config for Test {
     type SystemWeightInfo = ();
     type SS58Prefix = ConstU16<42>;
     type OnSetCode = ();
-    type MaxConsumers = frame_support::traits::ConstU32<16>;
+    type MaxConsumers = ConstU32<16>;
 }
 
 impl pallet_access_control::Config for Test {
     type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = ();
+    type AdminOrigin = EnsureRoot<u64>;
+    type PatientStatus = PatientIdentity;
+    type PatientFacilities = ();
+    type DefaultAccessDuration = ConstU64<150>;
+    type DoctorAccessDuration = ConstU64<300>;
+    type NurseAccessDuration = ConstU64<100>;
+    type MaxReasonLength = ConstU32<256>;
+    type MaxActiveAccesses = ConstU32<10>;
+    type MaxExpiriesPerBlock = ConstU32<64>;
+    type MaxQueueSpill = ConstU32<4>;
+    type MaxAuditEntries = ConstU32<100>;
+    type MaxGuardians = ConstU32<4>;
+    type JustificationPeriod = ConstU64<1_000>;
+    type CredentialWarningPeriod = ConstU64<100>;
+    type MaxFacilityNameLength = ConstU32<64>;
 }
 
 impl pallet_patient_identity::Config for Test {
     type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = ();
+    type AccessPermissions = AccessControl;
+    type AdminOrigin = EnsureRoot<u64>;
+    type MaxIdLength = ConstU32<64>;
+    type MaxLinkedIds = ConstU32<3>;
+    type IdHashVersion = ConstU8<2>;
 }
 
 /// Test account constants for RBAC testing
@@ -66,6 +89,7 @@ pub const ADMIN: u64 = 1;
 pub const DOCTOR: u64 = 2;
 pub const NURSE: u64 = 3;
 pub const PATIENT: u64 = 100;
+pub const PATIENT_2: u64 = 101;
 pub const UNAUTHORIZED: u64 = 999;
 
 /// Build genesis storage for testing with RBAC setup
@@ -83,6 +107,22 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
             pallet_access_control::Role::Doctor,
         );
         pallet_access_control::UserRoles::<Test>::insert(NURSE, pallet_access_control::Role::Nurse);
+        license(DOCTOR);
+        license(NURSE);
     });
     ext
 }
+
+/// Record an unexpired credential, without which a licensed role grants nothing
+pub fn license(provider: u64) {
+    pallet_access_control::Credentials::<Test>::insert(
+        provider,
+        pallet_access_control::Credential {
+            licence_id_hash: [0u8; 32],
+            issuing_body: pallet_access_control::IssuingBody::MedicalCouncil,
+            valid_until: u64::MAX,
+            facility_id: 0,
+            registered_at: 0,
+        },
+    );
+}
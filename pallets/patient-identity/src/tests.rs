@@ -4,7 +4,10 @@
 
 #![cfg(test)]
 
-use crate::{mock::*, Error, NationalIdType};
+use crate::{
+    mock::*, Error, Event, Identities, IdentityStatus, LegacyIdCount, NationalIdType,
+    VerificationLevel,
+};
 use frame_support::{assert_noop, assert_ok};
 
 /// Test successful patient registration by healthcare provider
@@ -23,8 +26,15 @@ fn register_patient_works() {
 
         // Verify identity was stored
         let identity = PatientIdentity::identities(PATIENT).unwrap();
-        assert_eq!(identity.id_hash, id_hash);
-        assert_eq!(identity.verified, false);
+        assert_eq!(
+            identity
+                .national_id(NationalIdType::FaydaID)
+                .unwrap()
+                .id_hash,
+            id_hash
+        );
+        assert_eq!(identity.verification_level, VerificationLevel::SelfAsserted);
+        assert!(identity.attestation.is_none());
         assert_eq!(identity.registered_by, DOCTOR);
 
         // Verify patient role was assigned
@@ -137,13 +147,32 @@ fn verify_identity_works() {
         ));
 
         // Verify by another healthcare provider (nurse)
+        System::set_block_number(3);
         assert_ok!(PatientIdentity::verify_identity(
             RuntimeOrigin::signed(NURSE),
             PATIENT,
+            VerificationLevel::DocumentChecked,
+            [9u8; 32],
         ));
 
         let identity = PatientIdentity::identities(PATIENT).unwrap();
-        assert_eq!(identity.verified, true);
+        assert_eq!(
+            identity.verification_level,
+            VerificationLevel::DocumentChecked
+        );
+        let attestation = identity.attestation.unwrap();
+        assert_eq!(attestation.verifier, NURSE);
+        assert_eq!(attestation.attested_at, 3);
+        assert_eq!(attestation.evidence_hash, [9u8; 32]);
+        System::assert_last_event(
+            Event::IdentityVerified {
+                who: PATIENT,
+                verifier: NURSE,
+                level: VerificationLevel::DocumentChecked,
+                evidence_hash: [9u8; 32],
+            }
+            .into(),
+        );
     });
 }
 
@@ -162,7 +191,12 @@ fn patient_cannot_verify_identity() {
 
         // Patient trying to verify another patient should fail
         assert_noop!(
-            PatientIdentity::verify_identity(RuntimeOrigin::signed(PATIENT), PATIENT),
+            PatientIdentity::verify_identity(
+                RuntimeOrigin::signed(PATIENT),
+                PATIENT,
+                VerificationLevel::DocumentChecked,
+                [9u8; 32],
+            ),
             Error::<Test>::NotAuthorizedToVerify
         );
     });
@@ -173,7 +207,12 @@ fn patient_cannot_verify_identity() {
 fn verify_identity_fails_if_not_found() {
     new_test_ext().execute_with(|| {
         assert_noop!(
-            PatientIdentity::verify_identity(RuntimeOrigin::signed(DOCTOR), 99),
+            PatientIdentity::verify_identity(
+                RuntimeOrigin::signed(DOCTOR),
+                99,
+                VerificationLevel::DocumentChecked,
+                [9u8; 32],
+            ),
             Error::<Test>::IdentityNotFound
         );
     });
@@ -192,9 +231,13 @@ fn id_to_account_lookup_works() {
             id_hash,
         ));
 
-        // Should be able to lookup account from ID hash
-        let account = PatientIdentity::id_to_account(id_hash).unwrap();
+        // Should be able to lookup account from ID type and hash
+        let account = PatientIdentity::id_to_account(NationalIdType::SmartID, id_hash).unwrap();
         assert_eq!(account, PATIENT);
+        assert_eq!(
+            PatientIdentity::id_to_account(NationalIdType::NIN, id_hash),
+            None
+        );
     });
 }
 
@@ -217,12 +260,879 @@ fn all_national_id_types_work() {
             assert_ok!(PatientIdentity::register_patient(
                 RuntimeOrigin::signed(DOCTOR),
                 *patient,
-                id_type.clone(),
+                *id_type,
                 id_hash,
             ));
 
             let identity = PatientIdentity::identities(patient).unwrap();
-            assert_eq!(identity.id_type, *id_type);
+            assert_eq!(identity.national_ids[0].id_type, *id_type);
         }
     });
 }
+
+// ============================================================================
+// Identity Lifecycle Tests
+// ============================================================================
+
+/// Register `patient` with `id_hash` through the doctor
+fn register(patient: u64, id_hash: [u8; 32]) {
+    assert_ok!(PatientIdentity::register_patient(
+        RuntimeOrigin::signed(DOCTOR),
+        patient,
+        NationalIdType::FaydaID,
+        id_hash,
+    ));
+}
+
+/// Test rotating the ID hash moves the reverse lookup atomically
+#[test]
+fn update_id_hash_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        register(PATIENT, [1u8; 32]);
+
+        assert_ok!(PatientIdentity::update_id_hash(
+            RuntimeOrigin::signed(NURSE),
+            PATIENT,
+            NationalIdType::FaydaID,
+            [2u8; 32],
+        ));
+
+        let identity = PatientIdentity::identities(PATIENT).unwrap();
+        assert_eq!(identity.national_ids.len(), 1);
+        assert_eq!(identity.national_ids[0].id_hash, [2u8; 32]);
+        assert_eq!(
+            PatientIdentity::id_to_account(NationalIdType::FaydaID, [2u8; 32]),
+            Some(PATIENT)
+        );
+        assert_eq!(
+            PatientIdentity::id_to_account(NationalIdType::FaydaID, [1u8; 32]),
+            None
+        );
+        System::assert_last_event(
+            Event::IdHashRotated {
+                who: PATIENT,
+                id_type: NationalIdType::FaydaID,
+                old_id_hash: [1u8; 32],
+                new_id_hash: [2u8; 32],
+                rotated_by: NURSE,
+            }
+            .into(),
+        );
+    });
+}
+
+/// Test ID rotation rejects linked hashes, unlinked types and non-verifiers
+#[test]
+fn update_id_hash_fails_for_invalid_input() {
+    new_test_ext().execute_with(|| {
+        register(PATIENT, [1u8; 32]);
+        register(PATIENT_2, [2u8; 32]);
+
+        assert_noop!(
+            PatientIdentity::update_id_hash(
+                RuntimeOrigin::signed(DOCTOR),
+                PATIENT,
+                NationalIdType::FaydaID,
+                [2u8; 32],
+            ),
+            Error::<Test>::IdAlreadyLinked
+        );
+        assert_noop!(
+            PatientIdentity::update_id_hash(
+                RuntimeOrigin::signed(DOCTOR),
+                PATIENT,
+                NationalIdType::Passport,
+                [3u8; 32],
+            ),
+            Error::<Test>::IdTypeNotLinked
+        );
+        assert_noop!(
+            PatientIdentity::update_id_hash(
+                RuntimeOrigin::signed(PATIENT),
+                PATIENT,
+                NationalIdType::FaydaID,
+                [3u8; 32],
+            ),
+            Error::<Test>::NotAuthorizedToVerify
+        );
+        assert_noop!(
+            PatientIdentity::update_id_hash(
+                RuntimeOrigin::signed(DOCTOR),
+                UNAUTHORIZED,
+                NationalIdType::FaydaID,
+                [3u8; 32],
+            ),
+            Error::<Test>::IdentityNotFound
+        );
+    });
+}
+
+/// Test merging marks the duplicate and points its ID at the survivor
+#[test]
+fn merge_identities_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(4);
+        register(PATIENT, [1u8; 32]);
+        register(PATIENT_2, [2u8; 32]);
+
+        assert_ok!(PatientIdentity::merge_identities(
+            RuntimeOrigin::signed(ADMIN),
+            PATIENT_2,
+            PATIENT,
+        ));
+
+        let duplicate = PatientIdentity::identities(PATIENT_2).unwrap();
+        assert_eq!(duplicate.status, IdentityStatus::Merged);
+        assert_eq!(duplicate.merged_into, Some(PATIENT));
+        assert_eq!(duplicate.status_changed_at, Some(4));
+        assert_eq!(
+            PatientIdentity::id_to_account(NationalIdType::FaydaID, [2u8; 32]),
+            Some(PATIENT)
+        );
+        assert_eq!(
+            PatientIdentity::id_to_account(NationalIdType::FaydaID, [1u8; 32]),
+            Some(PATIENT)
+        );
+        System::assert_last_event(
+            Event::IdentitiesMerged {
+                duplicate: PATIENT_2,
+                survivor: PATIENT,
+                merged_by: ADMIN,
+            }
+            .into(),
+        );
+
+        // A merged identity cannot be merged again or rotated
+        assert_noop!(
+            PatientIdentity::merge_identities(RuntimeOrigin::signed(ADMIN), PATIENT_2, PATIENT),
+            Error::<Test>::IdentityNotActive
+        );
+        assert_noop!(
+            PatientIdentity::update_id_hash(
+                RuntimeOrigin::signed(ADMIN),
+                PATIENT_2,
+                NationalIdType::FaydaID,
+                [3u8; 32],
+            ),
+            Error::<Test>::IdentityNotActive
+        );
+    });
+}
+
+/// Test merge rejects self-merges and missing identities
+#[test]
+fn merge_identities_fails_for_invalid_input() {
+    new_test_ext().execute_with(|| {
+        register(PATIENT, [1u8; 32]);
+
+        assert_noop!(
+            PatientIdentity::merge_identities(RuntimeOrigin::signed(DOCTOR), PATIENT, PATIENT),
+            Error::<Test>::CannotMergeIntoSelf
+        );
+        assert_noop!(
+            PatientIdentity::merge_identities(RuntimeOrigin::signed(DOCTOR), PATIENT_2, PATIENT),
+            Error::<Test>::IdentityNotFound
+        );
+        assert_noop!(
+            PatientIdentity::merge_identities(
+                RuntimeOrigin::signed(UNAUTHORIZED),
+                PATIENT_2,
+                PATIENT
+            ),
+            Error::<Test>::NotAuthorizedToVerify
+        );
+    });
+}
+
+/// Test deactivation, then death registration from the inactive state
+#[test]
+fn deactivate_then_register_death_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(2);
+        register(PATIENT, [1u8; 32]);
+
+        assert_ok!(PatientIdentity::deactivate_identity(
+            RuntimeOrigin::signed(NURSE),
+            PATIENT,
+        ));
+        System::assert_last_event(
+            Event::IdentityDeactivated {
+                who: PATIENT,
+                deactivated_by: NURSE,
+            }
+            .into(),
+        );
+        assert_eq!(
+            PatientIdentity::identities(PATIENT).unwrap().status,
+            IdentityStatus::Inactive
+        );
+        assert_noop!(
+            PatientIdentity::deactivate_identity(RuntimeOrigin::signed(NURSE), PATIENT),
+            Error::<Test>::InvalidStatusTransition
+        );
+
+        System::set_block_number(9);
+        assert_ok!(PatientIdentity::register_death(
+            RuntimeOrigin::signed(DOCTOR),
+            PATIENT,
+        ));
+        System::assert_last_event(
+            Event::DeathRegistered {
+                who: PATIENT,
+                registered_by: DOCTOR,
+            }
+            .into(),
+        );
+        let identity = PatientIdentity::identities(PATIENT).unwrap();
+        assert_eq!(identity.status, IdentityStatus::Deceased);
+        assert_eq!(identity.status_changed_at, Some(9));
+    });
+}
+
+/// Test deceased is terminal and status changes need a verifier
+#[test]
+fn register_death_is_terminal() {
+    new_test_ext().execute_with(|| {
+        register(PATIENT, [1u8; 32]);
+
+        assert_noop!(
+            PatientIdentity::register_death(RuntimeOrigin::signed(PATIENT), PATIENT),
+            Error::<Test>::NotAuthorizedToVerify
+        );
+        assert_ok!(PatientIdentity::register_death(
+            RuntimeOrigin::signed(DOCTOR),
+            PATIENT,
+        ));
+
+        assert_noop!(
+            PatientIdentity::register_death(RuntimeOrigin::signed(DOCTOR), PATIENT),
+            Error::<Test>::InvalidStatusTransition
+        );
+        assert_noop!(
+            PatientIdentity::deactivate_identity(RuntimeOrigin::signed(DOCTOR), PATIENT),
+            Error::<Test>::InvalidStatusTransition
+        );
+    });
+}
+
+/// Test access cannot be granted to patients whose identity is no longer active
+#[test]
+fn access_control_rejects_inactive_identities() {
+    new_test_ext().execute_with(|| {
+        register(PATIENT, [1u8; 32]);
+        register(PATIENT_2, [2u8; 32]);
+        assert_ok!(PatientIdentity::register_death(
+            RuntimeOrigin::signed(DOCTOR),
+            PATIENT,
+        ));
+        assert_ok!(PatientIdentity::deactivate_identity(
+            RuntimeOrigin::signed(DOCTOR),
+            PATIENT_2,
+        ));
+
+        assert_noop!(
+            AccessControl::grant_emergency_access(
+                RuntimeOrigin::signed(DOCTOR),
+                PATIENT,
+                [0u8; 32],
+            ),
+            pallet_access_control::Error::<Test>::PatientNotActive
+        );
+        assert_noop!(
+            AccessControl::grant_access(
+                RuntimeOrigin::signed(PATIENT_2),
+                DOCTOR,
+                pallet_access_control::AccessType::Regular,
+                None,
+            ),
+            pallet_access_control::Error::<Test>::PatientNotActive
+        );
+    });
+}
+
+// ============================================================================
+// Linked ID Tests
+// ============================================================================
+
+/// Test linking a second ID makes it resolve to the same account
+#[test]
+fn link_national_id_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        register(PATIENT, [1u8; 32]);
+
+        // Same hash under a different type is a different ID
+        assert_ok!(PatientIdentity::link_national_id(
+            RuntimeOrigin::signed(NURSE),
+            PATIENT,
+            NationalIdType::Passport,
+            [1u8; 32],
+        ));
+
+        let identity = PatientIdentity::identities(PATIENT).unwrap();
+        assert_eq!(identity.national_ids.len(), 2);
+        assert_eq!(
+            PatientIdentity::id_to_account(NationalIdType::Passport, [1u8; 32]),
+            Some(PATIENT)
+        );
+        assert_eq!(
+            PatientIdentity::id_to_account(NationalIdType::FaydaID, [1u8; 32]),
+            Some(PATIENT)
+        );
+        System::assert_last_event(
+            Event::NationalIdLinked {
+                who: PATIENT,
+                id_type: NationalIdType::Passport,
+                id_hash: [1u8; 32],
+                linked_by: NURSE,
+            }
+            .into(),
+        );
+    });
+}
+
+/// Test per-type uniqueness and the MaxLinkedIds bound
+#[test]
+fn link_national_id_enforces_uniqueness_and_bound() {
+    new_test_ext().execute_with(|| {
+        register(PATIENT, [1u8; 32]);
+        register(PATIENT_2, [2u8; 32]);
+        assert_ok!(PatientIdentity::link_national_id(
+            RuntimeOrigin::signed(DOCTOR),
+            PATIENT,
+            NationalIdType::Passport,
+            [3u8; 32],
+        ));
+
+        // One ID per type per patient
+        assert_noop!(
+            PatientIdentity::link_national_id(
+                RuntimeOrigin::signed(DOCTOR),
+                PATIENT,
+                NationalIdType::Passport,
+                [4u8; 32],
+            ),
+            Error::<Test>::IdTypeAlreadyLinked
+        );
+        // One account per (type, hash)
+        assert_noop!(
+            PatientIdentity::link_national_id(
+                RuntimeOrigin::signed(DOCTOR),
+                PATIENT_2,
+                NationalIdType::Passport,
+                [3u8; 32],
+            ),
+            Error::<Test>::IdAlreadyLinked
+        );
+        assert_noop!(
+            PatientIdentity::link_national_id(
+                RuntimeOrigin::signed(PATIENT),
+                PATIENT,
+                NationalIdType::NHIS,
+                [4u8; 32],
+            ),
+            Error::<Test>::NotAuthorizedToVerify
+        );
+
+        // MaxLinkedIds = 3 in the mock
+        assert_ok!(PatientIdentity::link_national_id(
+            RuntimeOrigin::signed(DOCTOR),
+            PATIENT,
+            NationalIdType::NHIS,
+            [4u8; 32],
+        ));
+        assert_noop!(
+            PatientIdentity::link_national_id(
+                RuntimeOrigin::signed(DOCTOR),
+                PATIENT,
+                NationalIdType::RefugeeID,
+                [5u8; 32],
+            ),
+            Error::<Test>::TooManyLinkedIds
+        );
+    });
+}
+
+/// Test merging re-points every ID linked to the duplicate
+#[test]
+fn merge_identities_moves_all_linked_ids() {
+    new_test_ext().execute_with(|| {
+        register(PATIENT, [1u8; 32]);
+        register(PATIENT_2, [2u8; 32]);
+        assert_ok!(PatientIdentity::link_national_id(
+            RuntimeOrigin::signed(DOCTOR),
+            PATIENT_2,
+            NationalIdType::RefugeeID,
+            [3u8; 32],
+        ));
+
+        assert_ok!(PatientIdentity::merge_identities(
+            RuntimeOrigin::signed(DOCTOR),
+            PATIENT_2,
+            PATIENT,
+        ));
+
+        assert_eq!(
+            PatientIdentity::id_to_account(NationalIdType::RefugeeID, [3u8; 32]),
+            Some(PATIENT)
+        );
+        assert_eq!(
+            PatientIdentity::id_to_account(NationalIdType::FaydaID, [2u8; 32]),
+            Some(PATIENT)
+        );
+
+        // The refugee ID moves to the survivor; its own Fayda ID stays in place
+        let survivor = PatientIdentity::identities(PATIENT).unwrap();
+        assert_eq!(
+            survivor
+                .national_id(NationalIdType::RefugeeID)
+                .unwrap()
+                .id_hash,
+            [3u8; 32]
+        );
+        assert_eq!(
+            survivor
+                .national_id(NationalIdType::FaydaID)
+                .unwrap()
+                .id_hash,
+            [1u8; 32]
+        );
+        let duplicate = PatientIdentity::identities(PATIENT_2).unwrap();
+        assert_eq!(duplicate.national_ids.len(), 1);
+        assert_eq!(duplicate.national_ids[0].id_type, NationalIdType::FaydaID);
+
+        assert_noop!(
+            PatientIdentity::link_national_id(
+                RuntimeOrigin::signed(DOCTOR),
+                PATIENT_2,
+                NationalIdType::Passport,
+                [4u8; 32],
+            ),
+            Error::<Test>::IdentityNotActive
+        );
+    });
+}
+
+/// Test ID type codes round-trip and legacy aliases parse
+#[test]
+fn national_id_type_codes_work() {
+    for id_type in NationalIdType::ALL.iter() {
+        assert_eq!(NationalIdType::from_code(id_type.code()), Some(*id_type));
+    }
+    assert_eq!(
+        NationalIdType::from_code("nigeria"),
+        Some(NationalIdType::NIN)
+    );
+    assert_eq!(
+        NationalIdType::from_code("GHANA"),
+        Some(NationalIdType::GhanaCard)
+    );
+    assert_eq!(NationalIdType::from_code("unknown"), None);
+    assert_eq!(NationalIdType::KenyaHuduma.country_code(), "KEN");
+    assert_eq!(NationalIdType::Passport.country_code(), "XXX");
+}
+
+// ============================================================================
+// ID Hash Migration Tests
+// ============================================================================
+
+/// Mark the linked ID of `patient` at `index` as hashed with the legacy scheme
+fn make_legacy(patient: u64, index: usize) {
+    Identities::<Test>::mutate(patient, |identity| {
+        identity.as_mut().unwrap().national_ids[index].hash_version = 1;
+    });
+    LegacyIdCount::<Test>::mutate(|count| *count += 1);
+}
+
+/// Test new IDs are stamped with the configured hash scheme
+#[test]
+fn new_ids_use_current_hash_version() {
+    new_test_ext().execute_with(|| {
+        register(PATIENT, [1u8; 32]);
+        assert_ok!(PatientIdentity::link_national_id(
+            RuntimeOrigin::signed(DOCTOR),
+            PATIENT,
+            NationalIdType::NHIS,
+            [2u8; 32],
+        ));
+
+        let identity = PatientIdentity::identities(PATIENT).unwrap();
+        assert!(identity.national_ids.iter().all(|id| id.hash_version == 2));
+    });
+}
+
+/// Test a legacy hash is replaced and its lookup moved
+#[test]
+fn migrate_id_hash_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        register(PATIENT, [1u8; 32]);
+        make_legacy(PATIENT, 0);
+        assert_eq!(PatientIdentity::legacy_id_count(), 1);
+
+        assert_ok!(PatientIdentity::migrate_id_hash(
+            RuntimeOrigin::signed(NURSE),
+            PATIENT,
+            NationalIdType::FaydaID,
+            [9u8; 32],
+        ));
+
+        let linked = PatientIdentity::identities(PATIENT).unwrap().national_ids[0];
+        assert_eq!(linked.id_hash, [9u8; 32]);
+        assert_eq!(linked.hash_version, 2);
+        assert_eq!(
+            PatientIdentity::id_to_account(NationalIdType::FaydaID, [9u8; 32]),
+            Some(PATIENT)
+        );
+        assert_eq!(
+            PatientIdentity::id_to_account(NationalIdType::FaydaID, [1u8; 32]),
+            None
+        );
+        assert_eq!(PatientIdentity::legacy_id_count(), 0);
+        System::assert_last_event(
+            Event::IdHashMigrated {
+                who: PATIENT,
+                id_type: NationalIdType::FaydaID,
+                from_version: 1,
+                to_version: 2,
+                migrated_by: NURSE,
+            }
+            .into(),
+        );
+
+        // Already on the current scheme
+        assert_noop!(
+            PatientIdentity::migrate_id_hash(
+                RuntimeOrigin::signed(NURSE),
+                PATIENT,
+                NationalIdType::FaydaID,
+                [10u8; 32],
+            ),
+            Error::<Test>::IdHashAlreadyCurrent
+        );
+    });
+}
+
+/// Test migration rejects non-verifiers, unlinked types, linked hashes and foreign lookups
+#[test]
+fn migrate_id_hash_fails_for_invalid_input() {
+    new_test_ext().execute_with(|| {
+        register(PATIENT, [1u8; 32]);
+        register(PATIENT_2, [2u8; 32]);
+        make_legacy(PATIENT, 0);
+
+        assert_noop!(
+            PatientIdentity::migrate_id_hash(
+                RuntimeOrigin::signed(PATIENT),
+                PATIENT,
+                NationalIdType::FaydaID,
+                [9u8; 32],
+            ),
+            Error::<Test>::NotAuthorizedToVerify
+        );
+        assert_noop!(
+            PatientIdentity::migrate_id_hash(
+                RuntimeOrigin::signed(DOCTOR),
+                PATIENT,
+                NationalIdType::FaydaID,
+                [2u8; 32],
+            ),
+            Error::<Test>::IdAlreadyLinked
+        );
+        assert_noop!(
+            PatientIdentity::migrate_id_hash(
+                RuntimeOrigin::signed(DOCTOR),
+                PATIENT,
+                NationalIdType::Passport,
+                [9u8; 32],
+            ),
+            Error::<Test>::IdTypeNotLinked
+        );
+
+        // The old hash must still resolve to the identity being migrated
+        crate::IdToAccount::<Test>::insert(NationalIdType::FaydaID, [1u8; 32], PATIENT_2);
+        assert_noop!(
+            PatientIdentity::migrate_id_hash(
+                RuntimeOrigin::signed(DOCTOR),
+                PATIENT,
+                NationalIdType::FaydaID,
+                [9u8; 32],
+            ),
+            Error::<Test>::IdLookupMismatch
+        );
+    });
+}
+
+/// Test a merged duplicate's legacy ID cannot be migrated, only purged
+#[test]
+fn merged_legacy_ids_are_purged() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        register(PATIENT, [1u8; 32]);
+        register(PATIENT_2, [2u8; 32]);
+        make_legacy(PATIENT_2, 0);
+        assert_ok!(PatientIdentity::merge_identities(
+            RuntimeOrigin::signed(DOCTOR),
+            PATIENT_2,
+            PATIENT,
+        ));
+
+        assert_noop!(
+            PatientIdentity::migrate_id_hash(
+                RuntimeOrigin::signed(DOCTOR),
+                PATIENT_2,
+                NationalIdType::FaydaID,
+                [9u8; 32],
+            ),
+            Error::<Test>::IdentityNotActive
+        );
+        assert_noop!(
+            PatientIdentity::purge_legacy_ids(RuntimeOrigin::signed(ADMIN), PATIENT_2),
+            sp_runtime::DispatchError::BadOrigin
+        );
+
+        assert_ok!(PatientIdentity::purge_legacy_ids(
+            RuntimeOrigin::root(),
+            PATIENT_2
+        ));
+
+        assert!(PatientIdentity::identities(PATIENT_2)
+            .unwrap()
+            .national_ids
+            .is_empty());
+        assert_eq!(
+            PatientIdentity::id_to_account(NationalIdType::FaydaID, [2u8; 32]),
+            None
+        );
+        assert_eq!(PatientIdentity::legacy_id_count(), 0);
+        System::assert_last_event(
+            Event::LegacyIdsPurged {
+                who: PATIENT_2,
+                count: 1,
+            }
+            .into(),
+        );
+
+        // Current-scheme IDs are never purged
+        assert_noop!(
+            PatientIdentity::purge_legacy_ids(RuntimeOrigin::root(), PATIENT),
+            Error::<Test>::NoLegacyIds
+        );
+    });
+}
+
+/// Test the v1 storage migration marks first-release identities active
+#[test]
+fn storage_migration_marks_identities_active() {
+    use crate::migrations::v1::{Identities as IdentitiesV1, MigrateToV1, OldIdentity};
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+    new_test_ext().execute_with(|| {
+        let old = OldIdentity::<Test> {
+            owner: PATIENT,
+            id_type: NationalIdType::NIN,
+            id_hash: [5u8; 32],
+            verified: true,
+            registered_at: 3,
+            registered_by: DOCTOR,
+        };
+        frame_support::storage::unhashed::put(&Identities::<Test>::hashed_key_for(PATIENT), &old);
+        StorageVersion::new(0).put::<PatientIdentity>();
+
+        MigrateToV1::<Test>::on_runtime_upgrade();
+
+        let identity = IdentitiesV1::<Test>::get(PATIENT).unwrap();
+        assert_eq!(identity.id_hash, [5u8; 32]);
+        assert!(identity.verified);
+        assert_eq!(identity.status, IdentityStatus::Active);
+        assert_eq!(identity.status_changed_at, None);
+        assert_eq!(identity.merged_into, None);
+        assert_eq!(
+            PatientIdentity::on_chain_storage_version(),
+            StorageVersion::new(1)
+        );
+    });
+}
+
+/// Test the full migration chain upgrades a first-release identity and its lookup
+#[test]
+fn storage_migrations_upgrade_first_release_identities() {
+    use crate::migrations::{
+        v1::{IdToAccount as IdToAccountV1, MigrateToV1, OldIdentity},
+        v2::MigrateToV2,
+        v3::MigrateToV3,
+        v4::MigrateToV4,
+    };
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+    new_test_ext().execute_with(|| {
+        let old = OldIdentity::<Test> {
+            owner: PATIENT,
+            id_type: NationalIdType::NIN,
+            id_hash: [5u8; 32],
+            verified: true,
+            registered_at: 3,
+            registered_by: DOCTOR,
+        };
+        frame_support::storage::unhashed::put(&Identities::<Test>::hashed_key_for(PATIENT), &old);
+        IdToAccountV1::<Test>::insert([5u8; 32], PATIENT);
+        StorageVersion::new(0).put::<PatientIdentity>();
+
+        MigrateToV1::<Test>::on_runtime_upgrade();
+        MigrateToV2::<Test>::on_runtime_upgrade();
+        assert_eq!(
+            PatientIdentity::on_chain_storage_version(),
+            StorageVersion::new(2)
+        );
+        // The reverse lookup moved to the `(id_type, id_hash)` key
+        assert!(!IdToAccountV1::<Test>::contains_key([5u8; 32]));
+        assert_eq!(
+            PatientIdentity::id_to_account(NationalIdType::NIN, [5u8; 32]),
+            Some(PATIENT)
+        );
+        MigrateToV3::<Test>::on_runtime_upgrade();
+        MigrateToV4::<Test>::on_runtime_upgrade();
+
+        let identity = PatientIdentity::identities(PATIENT).unwrap();
+        assert_eq!(identity.national_ids.len(), 1);
+        assert_eq!(identity.national_ids[0].id_type, NationalIdType::NIN);
+        assert_eq!(identity.national_ids[0].id_hash, [5u8; 32]);
+        assert_eq!(identity.national_ids[0].hash_version, 1);
+        assert_eq!(PatientIdentity::legacy_id_count(), 1);
+        assert_eq!(identity.status, IdentityStatus::Active);
+        // Legacy `verified = true` without a recorded attestation
+        assert_eq!(
+            identity.verification_level,
+            VerificationLevel::DocumentChecked
+        );
+        assert!(identity.attestation.is_none());
+        assert_eq!(
+            PatientIdentity::on_chain_storage_version(),
+            StorageVersion::new(4)
+        );
+    });
+}
+
+// ============================================================================
+// Verification Level Tests
+// ============================================================================
+
+/// Test the role policy for each verification level
+#[test]
+fn verification_policy_restricts_levels() {
+    new_test_ext().execute_with(|| {
+        register(PATIENT, [1u8; 32]);
+
+        assert_noop!(
+            PatientIdentity::verify_identity(
+                RuntimeOrigin::signed(NURSE),
+                PATIENT,
+                VerificationLevel::Biometric,
+                [9u8; 32],
+            ),
+            Error::<Test>::LevelNotAllowedForRole
+        );
+        assert_noop!(
+            PatientIdentity::verify_identity(
+                RuntimeOrigin::signed(DOCTOR),
+                PATIENT,
+                VerificationLevel::RegistryConfirmed,
+                [9u8; 32],
+            ),
+            Error::<Test>::LevelNotAllowedForRole
+        );
+        assert_noop!(
+            PatientIdentity::verify_identity(
+                RuntimeOrigin::signed(ADMIN),
+                PATIENT,
+                VerificationLevel::SelfAsserted,
+                [9u8; 32],
+            ),
+            Error::<Test>::LevelNotAllowedForRole
+        );
+        assert_ok!(PatientIdentity::verify_identity(
+            RuntimeOrigin::signed(ADMIN),
+            PATIENT,
+            VerificationLevel::RegistryConfirmed,
+            [9u8; 32],
+        ));
+        assert_eq!(
+            PatientIdentity::verification_level(&PATIENT),
+            Some(VerificationLevel::RegistryConfirmed)
+        );
+    });
+}
+
+/// Test attestations must raise the level
+#[test]
+fn verification_level_must_increase() {
+    new_test_ext().execute_with(|| {
+        register(PATIENT, [1u8; 32]);
+        assert_ok!(PatientIdentity::verify_identity(
+            RuntimeOrigin::signed(NURSE),
+            PATIENT,
+            VerificationLevel::DocumentChecked,
+            [9u8; 32],
+        ));
+
+        // Same level again (any verifier) is rejected
+        assert_noop!(
+            PatientIdentity::verify_identity(
+                RuntimeOrigin::signed(DOCTOR),
+                PATIENT,
+                VerificationLevel::DocumentChecked,
+                [8u8; 32],
+            ),
+            Error::<Test>::VerificationLevelNotHigher
+        );
+        assert_ok!(PatientIdentity::verify_identity(
+            RuntimeOrigin::signed(DOCTOR),
+            PATIENT,
+            VerificationLevel::Biometric,
+            [8u8; 32],
+        ));
+        assert_eq!(
+            PatientIdentity::identities(PATIENT)
+                .unwrap()
+                .attestation
+                .unwrap()
+                .verifier,
+            DOCTOR
+        );
+    });
+}
+
+/// Test providers cannot attest themselves and inactive identities cannot be attested
+#[test]
+fn verification_rejects_self_and_inactive() {
+    new_test_ext().execute_with(|| {
+        // A nurse who is also registered as a patient keeps the Nurse role
+        register(NURSE, [1u8; 32]);
+        register(PATIENT, [2u8; 32]);
+
+        assert_noop!(
+            PatientIdentity::verify_identity(
+                RuntimeOrigin::signed(NURSE),
+                NURSE,
+                VerificationLevel::DocumentChecked,
+                [9u8; 32],
+            ),
+            Error::<Test>::CannotAttestOwnIdentity
+        );
+
+        assert_ok!(PatientIdentity::deactivate_identity(
+            RuntimeOrigin::signed(DOCTOR),
+            PATIENT,
+        ));
+        assert_noop!(
+            PatientIdentity::verify_identity(
+                RuntimeOrigin::signed(DOCTOR),
+                PATIENT,
+                VerificationLevel::DocumentChecked,
+                [9u8; 32],
+            ),
+            Error::<Test>::IdentityNotActive
+        );
+    });
+}
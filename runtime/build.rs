@@ -0,0 +1,11 @@
+//! Builds the MediChain runtime WASM blob consumed by `medichain-node`.
+
+#[cfg(feature = "std")]
+fn main() {
+    substrate_wasm_builder::WasmBuilder::build_using_defaults();
+}
+
+/// The wasm builder is deactivated when compiling
+/// this crate for wasm to speed up the compilation.
+#[cfg(not(feature = "std"))]
+fn main() {}
@@ -3,86 +3,272 @@
 //! The runtime for MediChain - a blockchain-based emergency medical records system.
 //! Integrates patient identity, medical records, and emergency access control.
 //!
+//! Block authoring uses Aura (Proof-of-Authority) with GRANDPA finality, so the
+//! runtime can be executed by `medichain-node` both natively and as WASM.
+//!
 //! ## NASA Power of 10 Compliance
 //! - Rule 1: No recursion
 //! - Rule 2: All loops have fixed upper bounds
 //! - Rule 3: No dynamic memory after initialization
 
 #![cfg_attr(not(feature = "std"), no_std)]
+// `construct_runtime!` does a lot of recursion and requires us to increase the limit.
+#![recursion_limit = "256"]
+
+// Make the WASM binary available.
+#[cfg(feature = "std")]
+include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
+
+extern crate alloc;
 
-pub mod mock;
 pub mod tests;
 
+use alloc::{vec, vec::Vec};
 use frame_support::{
-    construct_runtime, derive_impl, parameter_types,
-    traits::ConstU32,
-    weights::{constants::WEIGHT_REF_TIME_PER_SECOND, Weight},
+    construct_runtime, derive_impl,
+    genesis_builder_helper::{build_state, get_preset},
+    parameter_types,
+    traits::{ConstBool, ConstU32, ConstU64, ConstU8, EitherOfDiverse},
+    weights::{
+        constants::{RocksDbWeight, WEIGHT_REF_TIME_PER_SECOND},
+        IdentityFee, Weight,
+    },
 };
+use frame_system::EnsureRoot;
+use pallet_grandpa::AuthorityId as GrandpaId;
+use pallet_transaction_payment::FungibleAdapter;
+use sp_api::impl_runtime_apis;
+use sp_consensus_aura::sr25519::AuthorityId as AuraId;
+use sp_core::{crypto::KeyTypeId, OpaqueMetadata};
+use sp_genesis_builder::PresetId;
 use sp_runtime::{
-    generic,
-    traits::{BlakeTwo256, IdentityLookup},
-    Perbill,
+    create_runtime_str, generic, impl_opaque_keys,
+    traits::{BlakeTwo256, Block as BlockT, IdentifyAccount, NumberFor, Verify},
+    transaction_validity::{TransactionSource, TransactionValidity},
+    ApplyExtrinsicResult, MultiAddress, MultiSignature, Perbill,
 };
+use sp_version::RuntimeVersion;
+
+#[cfg(feature = "std")]
+use sp_version::NativeVersion;
+
+// ============================================================================
+// PRIMITIVE TYPES
+// ============================================================================
 
 /// Block number type
-pub type BlockNumber = u64;
+pub type BlockNumber = u32;
+
+/// Signature type used by extrinsics (sr25519 by default)
+pub type Signature = MultiSignature;
 
-/// Account ID type
-pub type AccountId = u64;
+/// Account ID type, derived from the signer of an extrinsic
+pub type AccountId = <<Signature as Verify>::Signer as IdentifyAccount>::AccountId;
 
 /// Balance type
 pub type Balance = u128;
 
-/// Nonce type  
-pub type Nonce = u64;
+/// Nonce type
+pub type Nonce = u32;
 
 /// Hash type
 pub type Hash = sp_core::H256;
 
+/// Address format used to refer to accounts in extrinsics
+pub type Address = MultiAddress<AccountId, ()>;
+
 /// Block header type
 pub type Header = generic::Header<BlockNumber, BlakeTwo256>;
 
 /// Block type
 pub type Block = generic::Block<Header, UncheckedExtrinsic>;
 
+/// Signed extensions checked for every transaction
+///
+/// Every provider action is signed by an sr25519 (or ed25519/ecdsa) key,
+/// bound to this chain (genesis + spec/tx version), replay-protected by the
+/// nonce and mortality, and pays a fee so spam is not free.
+pub type SignedExtra = (
+    frame_system::CheckNonZeroSender<Runtime>,
+    frame_system::CheckSpecVersion<Runtime>,
+    frame_system::CheckTxVersion<Runtime>,
+    frame_system::CheckGenesis<Runtime>,
+    frame_system::CheckMortality<Runtime>,
+    frame_system::CheckNonce<Runtime>,
+    frame_system::CheckWeight<Runtime>,
+    pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
+);
+
 /// Unchecked extrinsic type
-pub type UncheckedExtrinsic = generic::UncheckedExtrinsic<AccountId, RuntimeCall, (), ()>;
+pub type UncheckedExtrinsic =
+    generic::UncheckedExtrinsic<Address, RuntimeCall, Signature, SignedExtra>;
+
+/// Payload signed by the sender of an extrinsic
+pub type SignedPayload = generic::SignedPayload<RuntimeCall, SignedExtra>;
+
+/// Storage migrations run on the next runtime upgrade
+pub type Migrations = (
+    pallet_patient_identity::migrations::v1::MigrateToV1<Runtime>,
+    pallet_patient_identity::migrations::v2::MigrateToV2<Runtime>,
+    pallet_patient_identity::migrations::v3::MigrateToV3<Runtime>,
+    pallet_patient_identity::migrations::v4::MigrateToV4<Runtime>,
+    pallet_medical_records::migrations::v1::MigrateToV1<Runtime>,
+    pallet_medical_records::migrations::v2::MigrateToV2<Runtime>,
+    pallet_medical_records::migrations::v3::MigrateToV3<Runtime>,
+    pallet_medical_records::migrations::v4::MigrateToV4<Runtime>,
+    pallet_access_control::migrations::v1::MigrateToV1<Runtime>,
+);
+
+/// Executive: dispatches calls to the pallets
+pub type Executive = frame_executive::Executive<
+    Runtime,
+    Block,
+    frame_system::ChainContext<Runtime>,
+    Runtime,
+    AllPalletsWithSystem,
+    Migrations,
+>;
+
+/// Opaque types used by the node, which does not need to know the
+/// runtime-specific call and extrinsic formats.
+pub mod opaque {
+    use super::*;
+
+    pub use sp_runtime::OpaqueExtrinsic as UncheckedExtrinsic;
+
+    /// Opaque block header type
+    pub type Header = generic::Header<BlockNumber, BlakeTwo256>;
+    /// Opaque block type
+    pub type Block = generic::Block<Header, UncheckedExtrinsic>;
+    /// Opaque block identifier type
+    pub type BlockId = generic::BlockId<Block>;
+
+    impl_opaque_keys! {
+        /// Session keys held by each authority node
+        pub struct SessionKeys {
+            pub aura: Aura,
+            pub grandpa: Grandpa,
+        }
+    }
+}
+
+// ============================================================================
+// VERSION & TIME
+// ============================================================================
+
+/// Runtime version (bump `spec_version` on every runtime logic change)
+#[sp_version::runtime_version]
+pub const VERSION: RuntimeVersion = RuntimeVersion {
+    spec_name: create_runtime_str!("medichain"),
+    impl_name: create_runtime_str!("medichain"),
+    authoring_version: 1,
+    spec_version: 107,
+    impl_version: 1,
+    apis: RUNTIME_API_VERSIONS,
+    transaction_version: 1,
+    state_version: 1,
+};
+
+/// Native version used by the node
+#[cfg(feature = "std")]
+pub fn native_version() -> NativeVersion {
+    NativeVersion {
+        runtime_version: VERSION,
+        can_author_with: Default::default(),
+    }
+}
+
+/// SS58 address prefix (42 = generic Substrate, until MediChain registers its own)
+pub const SS58_PREFIX: u16 = 42;
+
+/// Target block time (6 seconds, emergency access durations assume it)
+pub const MILLI_SECS_PER_BLOCK: u64 = 6000;
 
-/// Maximum block weight (75% of available time)
+/// Aura slot duration (one block per slot)
+pub const SLOT_DURATION: u64 = MILLI_SECS_PER_BLOCK;
+
+/// Blocks per minute
+pub const MINUTES: BlockNumber = 60_000 / (MILLI_SECS_PER_BLOCK as BlockNumber);
+/// Blocks per hour
+pub const HOURS: BlockNumber = MINUTES * 60;
+/// Blocks per day
+pub const DAYS: BlockNumber = HOURS * 24;
+
+/// Maximum block weight (2 seconds of compute)
 const MAXIMUM_BLOCK_WEIGHT: Weight =
     Weight::from_parts(WEIGHT_REF_TIME_PER_SECOND.saturating_mul(2), u64::MAX);
 
 /// Maximum block length (5 MB)
 const MAXIMUM_BLOCK_LENGTH: u32 = 5 * 1024 * 1024;
 
+/// Share of the block reserved for normal (non-operational) extrinsics
+const NORMAL_DISPATCH_RATIO: Perbill = Perbill::from_percent(75);
+
+// ============================================================================
+// PALLET CONFIGURATION
+// ============================================================================
+
 parameter_types! {
     pub const BlockHashCount: BlockNumber = 2400;
+    pub const Version: RuntimeVersion = VERSION;
+    pub const SS58Prefix: u16 = SS58_PREFIX;
     pub BlockWeights: frame_system::limits::BlockWeights =
-        frame_system::limits::BlockWeights::simple_max(MAXIMUM_BLOCK_WEIGHT);
+        frame_system::limits::BlockWeights::with_sensible_defaults(
+            MAXIMUM_BLOCK_WEIGHT,
+            NORMAL_DISPATCH_RATIO,
+        );
     pub BlockLength: frame_system::limits::BlockLength =
         frame_system::limits::BlockLength::max_with_normal_ratio(
             MAXIMUM_BLOCK_LENGTH,
-            Perbill::from_percent(75),
+            NORMAL_DISPATCH_RATIO,
         );
 }
 
 /// Frame System configuration
-#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+#[derive_impl(frame_system::config_preludes::SolochainDefaultConfig)]
 impl frame_system::Config for Runtime {
     type Block = Block;
+    type BlockWeights = BlockWeights;
+    type BlockLength = BlockLength;
     type AccountId = AccountId;
-    type Lookup = IdentityLookup<AccountId>;
+    type Nonce = Nonce;
+    type Hash = Hash;
+    type BlockHashCount = BlockHashCount;
+    type DbWeight = RocksDbWeight;
+    type Version = Version;
     type AccountData = pallet_balances::AccountData<Balance>;
+    type MaxConsumers = ConstU32<16>;
+    type SS58Prefix = SS58Prefix;
+}
+
+/// Aura (block authoring) configuration
+impl pallet_aura::Config for Runtime {
+    type AuthorityId = AuraId;
+    type DisabledValidators = ();
+    type MaxAuthorities = ConstU32<32>;
+    type AllowMultipleBlocksPerSlot = ConstBool<false>;
+    type SlotDuration = pallet_aura::MinimumPeriodTimesTwo<Runtime>;
+}
+
+/// GRANDPA (finality) configuration
+impl pallet_grandpa::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = ();
+    type MaxAuthorities = ConstU32<32>;
+    type MaxNominators = ConstU32<0>;
+    type MaxSetIdSessionEntries = ConstU64<0>;
+    type KeyOwnerProof = sp_core::Void;
+    type EquivocationReportSystem = ();
 }
 
 parameter_types! {
-    pub const MinimumPeriod: u64 = 3000; // 3 seconds (half block time)
+    pub const MinimumPeriod: u64 = SLOT_DURATION / 2; // 3 seconds (half block time)
 }
 
 /// Timestamp pallet configuration
 impl pallet_timestamp::Config for Runtime {
     type Moment = u64;
-    type OnTimestampSet = ();
+    type OnTimestampSet = Aura;
     type MinimumPeriod = MinimumPeriod;
     type WeightInfo = ();
 }
@@ -110,44 +296,137 @@ impl pallet_balances::Config for Runtime {
     type MaxFreezes = ConstU32<0>;
 }
 
-parameter_types! {
-    pub const TransactionByteFee: Balance = 1;
-    pub const OperationalFeeMultiplier: u8 = 5;
-}
-
 /// Transaction payment pallet configuration
 impl pallet_transaction_payment::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
-    type OnChargeTransaction = pallet_transaction_payment::FungibleAdapter<Balances, ()>;
-    type OperationalFeeMultiplier = OperationalFeeMultiplier;
-    type WeightToFee = frame_support::weights::IdentityFee<Balance>;
-    type LengthToFee = frame_support::weights::IdentityFee<Balance>;
+    type OnChargeTransaction = FungibleAdapter<Balances, ()>;
+    type OperationalFeeMultiplier = ConstU8<5>;
+    type WeightToFee = IdentityFee<Balance>;
+    type LengthToFee = IdentityFee<Balance>;
     type FeeMultiplierUpdate = ();
 }
 
+parameter_types! {
+    pub const MaxIdLength: u32 = 64;
+    /// National ID, passport, NHIS number, refugee ID, ... (one per type)
+    pub const MaxLinkedIds: u32 = 8;
+    /// HMAC-SHA256 with the registry pepper (`medichain_crypto::NATIONAL_ID_HASH_VERSION`)
+    pub const IdHashVersion: u8 = 2;
+}
+
 /// Patient Identity pallet configuration
 impl pallet_patient_identity::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = pallet_patient_identity::weights::SubstrateWeight<Runtime>;
+    type AccessPermissions = AccessControl;
+    type AdminOrigin = EnsureMinistryApproval;
+    type MaxIdLength = MaxIdLength;
+    type MaxLinkedIds = MaxLinkedIds;
+    type IdHashVersion = IdHashVersion;
+}
+
+parameter_types! {
+    pub const MaxAlerts: u32 = 10;
+    pub const MaxIpfsHashLength: u32 = 64;
+    pub const MaxRecordVersions: u32 = 100;
+    pub const MaxDocuments: u32 = 1_000;
 }
 
 /// Medical Records pallet configuration
 impl pallet_medical_records::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = pallet_medical_records::weights::SubstrateWeight<Runtime>;
+    type AccessPermissions = AccessControl;
+    type MaxAlerts = MaxAlerts;
+    type MaxIpfsHashLength = MaxIpfsHashLength;
+    type MaxRecordVersions = MaxRecordVersions;
+    type MaxDocuments = MaxDocuments;
+}
+
+parameter_types! {
+    /// Emergency access for Admins (~15 minutes)
+    pub const DefaultAccessDuration: BlockNumber = 15 * MINUTES;
+    /// Emergency access for Doctors (~30 minutes, covers a full assessment)
+    pub const DoctorAccessDuration: BlockNumber = 30 * MINUTES;
+    /// Emergency access for Nurses (~10 minutes, triage)
+    pub const NurseAccessDuration: BlockNumber = 10 * MINUTES;
+    pub const MaxReasonLength: u32 = 256;
+    pub const MaxActiveAccesses: u32 = 10;
+    pub const MaxExpiriesPerBlock: u32 = 64;
+    pub const MaxQueueSpill: u32 = 16;
+    pub const MaxAuditEntries: u32 = 1_000;
+    pub const MaxGuardians: u32 = 4;
+    /// Emergency accessors justify their grant within ~3 days
+    pub const JustificationPeriod: BlockNumber = 3 * DAYS;
+    /// HR is warned ~30 days before a provider's licence lapses
+    pub const CredentialWarningPeriod: BlockNumber = 30 * DAYS;
+    pub const MaxFacilityNameLength: u32 = 128;
+}
+
+parameter_types! {
+    pub const MinistryMotionDuration: BlockNumber = 3 * DAYS;
+    pub const MinistryMaxProposals: u32 = 32;
+    pub const MinistryMaxMembers: u32 = 16;
+    pub MaxProposalWeight: Weight = Perbill::from_percent(50) * BlockWeights::get().max_block;
+}
+
+/// Ministry of Health council that approves Admin operations
+pub type MinistryCollective = pallet_collective::Instance1;
+
+/// Root, or at least two thirds of the ministry council (M-of-N approval)
+pub type EnsureMinistryApproval = EitherOfDiverse<
+    EnsureRoot<AccountId>,
+    pallet_collective::EnsureProportionAtLeast<AccountId, MinistryCollective, 2, 3>,
+>;
+
+/// Ministry council configuration
+impl pallet_collective::Config<MinistryCollective> for Runtime {
+    type RuntimeOrigin = RuntimeOrigin;
+    type Proposal = RuntimeCall;
+    type RuntimeEvent = RuntimeEvent;
+    type MotionDuration = MinistryMotionDuration;
+    type MaxProposals = MinistryMaxProposals;
+    type MaxMembers = MinistryMaxMembers;
+    type DefaultVote = pallet_collective::PrimeDefaultVote;
+    type WeightInfo = pallet_collective::weights::SubstrateWeight<Runtime>;
+    type SetMembersOrigin = EnsureMinistryApproval;
+    type MaxProposalWeight = MaxProposalWeight;
 }
 
 /// Access Control pallet configuration
 impl pallet_access_control::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = pallet_access_control::weights::SubstrateWeight<Runtime>;
+    type AdminOrigin = EnsureMinistryApproval;
+    type PatientStatus = PatientIdentity;
+    type PatientFacilities = MedicalRecords;
+    type DefaultAccessDuration = DefaultAccessDuration;
+    type DoctorAccessDuration = DoctorAccessDuration;
+    type NurseAccessDuration = NurseAccessDuration;
+    type MaxReasonLength = MaxReasonLength;
+    type MaxActiveAccesses = MaxActiveAccesses;
+    type MaxExpiriesPerBlock = MaxExpiriesPerBlock;
+    type MaxQueueSpill = MaxQueueSpill;
+    type MaxAuditEntries = MaxAuditEntries;
+    type MaxGuardians = MaxGuardians;
+    type JustificationPeriod = JustificationPeriod;
+    type CredentialWarningPeriod = CredentialWarningPeriod;
+    type MaxFacilityNameLength = MaxFacilityNameLength;
 }
 
 // Construct the runtime by composing all pallets
+// (skipped by rustfmt, which would strip the `::` from instanced pallet paths)
+#[rustfmt::skip]
 construct_runtime!(
     pub struct Runtime {
         // Core pallets
         System: frame_system,
         Timestamp: pallet_timestamp,
+        Aura: pallet_aura,
+        Grandpa: pallet_grandpa,
         Balances: pallet_balances,
         TransactionPayment: pallet_transaction_payment,
+        MinistryCouncil: pallet_collective::<Instance1>,
 
         // MediChain custom pallets
         PatientIdentity: pallet_patient_identity,
@@ -155,3 +434,589 @@ construct_runtime!(
         AccessControl: pallet_access_control,
     }
 );
+
+// ============================================================================
+// GENESIS PRESETS
+// ============================================================================
+
+/// Genesis presets understood by `medichain-node` (`--dev`, `--chain local`)
+pub mod genesis_config_presets {
+    use super::*;
+    use pallet_access_control::{FacilityKind, IssuingBody, Role};
+    use serde_json::{json, Value};
+    use sp_keyring::{Ed25519Keyring, Sr25519Keyring};
+
+    /// Initial balance for endowed development accounts
+    const ENDOWMENT: Balance = 1 << 60;
+
+    /// Validity of the seeded providers' credentials
+    const CREDENTIAL_VALIDITY: BlockNumber = 365 * DAYS;
+
+    /// Build a genesis patch from authorities, endowed accounts and seeded roles
+    ///
+    /// Admins in `roles` also seed the ministry council, which must approve
+    /// (M-of-N) any later role changes, including new Admins. Licensed
+    /// providers get a credential at a development hospital (facility 0)
+    /// run by the first Admin.
+    pub fn testnet_genesis(
+        initial_authorities: Vec<(AuraId, GrandpaId)>,
+        endowed_accounts: Vec<AccountId>,
+        roles: Vec<(AccountId, Role)>,
+    ) -> Value {
+        // Genesis Admins form the initial ministry council
+        let council = roles
+            .iter()
+            .filter(|(_, role)| matches!(role, Role::Admin))
+            .map(|(account, _)| account.clone())
+            .collect::<Vec<_>>();
+        let facilities = council
+            .first()
+            .map(|admin| {
+                (
+                    FacilityKind::Hospital,
+                    b"MediChain Dev Hospital".to_vec(),
+                    admin.clone(),
+                )
+            })
+            .into_iter()
+            .collect::<Vec<_>>();
+        let credentials = roles
+            .iter()
+            .filter_map(|(account, role)| {
+                let issuing_body = match role {
+                    Role::Doctor => IssuingBody::MedicalCouncil,
+                    Role::Nurse => IssuingBody::NursingCouncil,
+                    Role::Pharmacist => IssuingBody::PharmacyCouncil,
+                    Role::LabTechnician => IssuingBody::HealthProfessionsCouncil,
+                    _ => return None,
+                };
+                Some((
+                    account.clone(),
+                    [0u8; 32],
+                    issuing_body,
+                    CREDENTIAL_VALIDITY,
+                    0u32,
+                ))
+            })
+            .collect::<Vec<_>>();
+
+        json!({
+            "balances": {
+                "balances": endowed_accounts
+                    .iter()
+                    .cloned()
+                    .map(|account| (account, ENDOWMENT))
+                    .collect::<Vec<_>>(),
+            },
+            "aura": {
+                "authorities": initial_authorities
+                    .iter()
+                    .map(|keys| keys.0.clone())
+                    .collect::<Vec<_>>(),
+            },
+            "grandpa": {
+                "authorities": initial_authorities
+                    .iter()
+                    .map(|keys| (keys.1.clone(), 1))
+                    .collect::<Vec<_>>(),
+            },
+            "ministryCouncil": {
+                "members": council,
+            },
+            "accessControl": {
+                "roles": roles,
+                "facilities": facilities,
+                "credentials": credentials,
+            },
+        })
+    }
+
+    /// Single-authority development chain (Alice authors every block, Alice is Admin)
+    pub fn development_config_genesis() -> Value {
+        testnet_genesis(
+            vec![(
+                Sr25519Keyring::Alice.public().into(),
+                Ed25519Keyring::Alice.public().into(),
+            )],
+            vec![
+                Sr25519Keyring::Alice.to_account_id(),
+                Sr25519Keyring::Bob.to_account_id(),
+                Sr25519Keyring::AliceStash.to_account_id(),
+                Sr25519Keyring::BobStash.to_account_id(),
+            ],
+            vec![
+                (Sr25519Keyring::Alice.to_account_id(), Role::Admin),
+                (Sr25519Keyring::Bob.to_account_id(), Role::Doctor),
+                (Sr25519Keyring::Charlie.to_account_id(), Role::Nurse),
+                (Sr25519Keyring::Dave.to_account_id(), Role::LabTechnician),
+                (Sr25519Keyring::Eve.to_account_id(), Role::Pharmacist),
+            ],
+        )
+    }
+
+    /// Two-authority local testnet (Alice and Bob, both Admins)
+    pub fn local_config_genesis() -> Value {
+        testnet_genesis(
+            vec![
+                (
+                    Sr25519Keyring::Alice.public().into(),
+                    Ed25519Keyring::Alice.public().into(),
+                ),
+                (
+                    Sr25519Keyring::Bob.public().into(),
+                    Ed25519Keyring::Bob.public().into(),
+                ),
+            ],
+            Sr25519Keyring::iter()
+                .filter(|keyring| {
+                    *keyring != Sr25519Keyring::One && *keyring != Sr25519Keyring::Two
+                })
+                .map(|keyring| keyring.to_account_id())
+                .collect::<Vec<_>>(),
+            vec![
+                (Sr25519Keyring::Alice.to_account_id(), Role::Admin),
+                (Sr25519Keyring::Bob.to_account_id(), Role::Admin),
+                (Sr25519Keyring::Charlie.to_account_id(), Role::Doctor),
+                (Sr25519Keyring::Dave.to_account_id(), Role::Nurse),
+                (Sr25519Keyring::Eve.to_account_id(), Role::LabTechnician),
+                (Sr25519Keyring::Ferdie.to_account_id(), Role::Pharmacist),
+            ],
+        )
+    }
+
+    /// Serialized genesis patch for a named preset
+    pub fn get_preset(id: &PresetId) -> Option<Vec<u8>> {
+        let patch = match core::str::from_utf8(id.as_ref()) {
+            Ok(sp_genesis_builder::DEV_RUNTIME_PRESET) => development_config_genesis(),
+            Ok(sp_genesis_builder::LOCAL_TESTNET_RUNTIME_PRESET) => local_config_genesis(),
+            _ => return None,
+        };
+        Some(
+            serde_json::to_string(&patch)
+                .expect("serialization to json is expected to work. qed.")
+                .into_bytes(),
+        )
+    }
+
+    /// Names of all available presets
+    pub fn preset_names() -> Vec<PresetId> {
+        vec![
+            PresetId::from(sp_genesis_builder::DEV_RUNTIME_PRESET),
+            PresetId::from(sp_genesis_builder::LOCAL_TESTNET_RUNTIME_PRESET),
+        ]
+    }
+}
+
+// ============================================================================
+// MEDICHAIN RUNTIME API
+// ============================================================================
+
+sp_api::decl_runtime_apis! {
+    /// Read-only queries over MediChain state
+    ///
+    /// Lets clients (API server, node RPC) read roles, access grants,
+    /// identities and health records via `state_call` without decoding
+    /// raw storage keys.
+    pub trait MediChainApi {
+        /// Role an account currently holds, if any (`None` once a licensed role's
+        /// credential lapses); facility roles apply only to that facility's patients
+        fn get_role(account: AccountId) -> Option<pallet_access_control::Role>;
+
+        /// Whether `accessor` holds valid (non-expired, non-revoked) access to `patient`
+        fn has_valid_access(patient: AccountId, accessor: AccountId) -> bool;
+
+        /// Guardian appointment of `guardian` for `patient`, if any (expired ones included)
+        fn guardianship(
+            patient: AccountId,
+            guardian: AccountId,
+        ) -> Option<pallet_access_control::Guardianship<Runtime>>;
+
+        /// Consent directives in force for a patient (defaults if never set)
+        fn consent_directives(patient: AccountId) -> pallet_access_control::ConsentDirectives;
+
+        /// What `viewer` may see of `patient`'s data under the patient's directives
+        fn disclosure(patient: AccountId, viewer: AccountId) -> pallet_access_control::Disclosure;
+
+        /// Open break-glass review of an emergency grant (`None` once approved or flagged)
+        fn break_glass_review(
+            review_id: u32,
+        ) -> Option<pallet_access_control::BreakGlassReview<Runtime>>;
+
+        /// Unjustified or flagged emergency grants counted against a provider
+        fn misuse_count(provider: AccountId) -> u32;
+
+        /// Professional credential of a provider, if recorded (lapsed ones included)
+        fn credential(provider: AccountId) -> Option<pallet_access_control::Credential<Runtime>>;
+
+        /// Registered facility (hospital, clinic, laboratory, ...)
+        fn facility(
+            facility_id: pallet_access_control::FacilityId,
+        ) -> Option<pallet_access_control::Facility<Runtime>>;
+
+        /// Role an account holds at a facility, if it is staff there
+        fn facility_role(
+            facility_id: pallet_access_control::FacilityId,
+            account: AccountId,
+        ) -> Option<pallet_access_control::Role>;
+
+        /// Registered identity of a patient account
+        fn identity(account: AccountId) -> Option<pallet_patient_identity::Identity<Runtime>>;
+
+        /// Account linked to an ID of the given type and hash (reverse lookup)
+        fn id_to_account(
+            id_type: pallet_patient_identity::NationalIdType,
+            id_hash: [u8; 32],
+        ) -> Option<AccountId>;
+
+        /// How strongly a patient's identity is established, for emergency views
+        fn verification_level(
+            account: AccountId,
+        ) -> Option<pallet_patient_identity::VerificationLevel>;
+
+        /// Health record metadata of a patient
+        fn health_record(patient: AccountId) -> Option<pallet_medical_records::HealthRecord<Runtime>>;
+
+        /// Active (unresolved) medical alerts of a patient, for emergency views
+        fn active_alerts(patient: AccountId) -> Vec<pallet_medical_records::MedicalAlert<Runtime>>;
+
+        /// IPFS hash a patient's record pointed to at `version` (if still retained)
+        fn ipfs_hash_at(patient: AccountId, version: u32) -> Option<Vec<u8>>;
+
+        /// Update that produced `version` of a patient's record (who, when, replaced hash)
+        fn record_version(
+            patient: AccountId,
+            version: u32,
+        ) -> Option<pallet_medical_records::RecordVersion<Runtime>>;
+
+        /// Page of a patient's documents (up to `limit` from document id `start`)
+        fn documents(
+            patient: AccountId,
+            start: u32,
+            limit: u32,
+        ) -> Vec<(u32, pallet_medical_records::MedicalDocument<Runtime>)>;
+
+        /// Page of a patient's audit log (up to `limit` entries from index `start`, oldest first)
+        fn audit_log(
+            patient: AccountId,
+            start: u32,
+            limit: u32,
+        ) -> Vec<(u32, pallet_access_control::AuditEntry<Runtime>)>;
+    }
+}
+
+// ============================================================================
+// BENCHMARKS
+// ============================================================================
+
+// Pallets benchmarked by `frame-omni-bencher` (see each pallet's `weights.rs`)
+#[cfg(feature = "runtime-benchmarks")]
+frame_benchmarking::define_benchmarks!(
+    [pallet_collective, MinistryCouncil]
+    [pallet_access_control, AccessControl]
+    [pallet_patient_identity, PatientIdentity]
+    [pallet_medical_records, MedicalRecords]
+);
+
+// ============================================================================
+// RUNTIME APIS
+// ============================================================================
+
+impl_runtime_apis! {
+    impl sp_api::Core<Block> for Runtime {
+        fn version() -> RuntimeVersion {
+            VERSION
+        }
+
+        fn execute_block(block: Block) {
+            Executive::execute_block(block);
+        }
+
+        fn initialize_block(header: &<Block as BlockT>::Header) -> sp_runtime::ExtrinsicInclusionMode {
+            Executive::initialize_block(header)
+        }
+    }
+
+    impl sp_api::Metadata<Block> for Runtime {
+        fn metadata() -> OpaqueMetadata {
+            OpaqueMetadata::new(Runtime::metadata().into())
+        }
+
+        fn metadata_at_version(version: u32) -> Option<OpaqueMetadata> {
+            Runtime::metadata_at_version(version)
+        }
+
+        fn metadata_versions() -> Vec<u32> {
+            Runtime::metadata_versions()
+        }
+    }
+
+    impl sp_block_builder::BlockBuilder<Block> for Runtime {
+        fn apply_extrinsic(extrinsic: <Block as BlockT>::Extrinsic) -> ApplyExtrinsicResult {
+            Executive::apply_extrinsic(extrinsic)
+        }
+
+        fn finalize_block() -> <Block as BlockT>::Header {
+            Executive::finalize_block()
+        }
+
+        fn inherent_extrinsics(data: sp_inherents::InherentData) -> Vec<<Block as BlockT>::Extrinsic> {
+            data.create_extrinsics()
+        }
+
+        fn check_inherents(
+            block: Block,
+            data: sp_inherents::InherentData,
+        ) -> sp_inherents::CheckInherentsResult {
+            data.check_extrinsics(&block)
+        }
+    }
+
+    impl sp_transaction_pool::runtime_api::TaggedTransactionQueue<Block> for Runtime {
+        fn validate_transaction(
+            source: TransactionSource,
+            tx: <Block as BlockT>::Extrinsic,
+            block_hash: <Block as BlockT>::Hash,
+        ) -> TransactionValidity {
+            Executive::validate_transaction(source, tx, block_hash)
+        }
+    }
+
+    impl sp_offchain::OffchainWorkerApi<Block> for Runtime {
+        fn offchain_worker(header: &<Block as BlockT>::Header) {
+            Executive::offchain_worker(header)
+        }
+    }
+
+    impl sp_consensus_aura::AuraApi<Block, AuraId> for Runtime {
+        fn slot_duration() -> sp_consensus_aura::SlotDuration {
+            sp_consensus_aura::SlotDuration::from_millis(Aura::slot_duration())
+        }
+
+        fn authorities() -> Vec<AuraId> {
+            pallet_aura::Authorities::<Runtime>::get().into_inner()
+        }
+    }
+
+    impl sp_session::SessionKeys<Block> for Runtime {
+        fn generate_session_keys(seed: Option<Vec<u8>>) -> Vec<u8> {
+            opaque::SessionKeys::generate(seed)
+        }
+
+        fn decode_session_keys(
+            encoded: Vec<u8>,
+        ) -> Option<Vec<(Vec<u8>, KeyTypeId)>> {
+            opaque::SessionKeys::decode_into_raw_public_keys(&encoded)
+        }
+    }
+
+    impl sp_consensus_grandpa::GrandpaApi<Block> for Runtime {
+        fn grandpa_authorities() -> sp_consensus_grandpa::AuthorityList {
+            Grandpa::grandpa_authorities()
+        }
+
+        fn current_set_id() -> sp_consensus_grandpa::SetId {
+            Grandpa::current_set_id()
+        }
+
+        fn submit_report_equivocation_unsigned_extrinsic(
+            _equivocation_proof: sp_consensus_grandpa::EquivocationProof<
+                <Block as BlockT>::Hash,
+                NumberFor<Block>,
+            >,
+            _key_owner_proof: sp_consensus_grandpa::OpaqueKeyOwnershipProof,
+        ) -> Option<()> {
+            None
+        }
+
+        fn generate_key_ownership_proof(
+            _set_id: sp_consensus_grandpa::SetId,
+            _authority_id: GrandpaId,
+        ) -> Option<sp_consensus_grandpa::OpaqueKeyOwnershipProof> {
+            // NOTE: this is the only implementation possible since we've
+            // defined our key owner proof type as a bottom type (i.e. a type
+            // with no values).
+            None
+        }
+    }
+
+    impl frame_system_rpc_runtime_api::AccountNonceApi<Block, AccountId, Nonce> for Runtime {
+        fn account_nonce(account: AccountId) -> Nonce {
+            System::account_nonce(account)
+        }
+    }
+
+    impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<Block, Balance> for Runtime {
+        fn query_info(
+            uxt: <Block as BlockT>::Extrinsic,
+            len: u32,
+        ) -> pallet_transaction_payment_rpc_runtime_api::RuntimeDispatchInfo<Balance> {
+            TransactionPayment::query_info(uxt, len)
+        }
+
+        fn query_fee_details(
+            uxt: <Block as BlockT>::Extrinsic,
+            len: u32,
+        ) -> pallet_transaction_payment::FeeDetails<Balance> {
+            TransactionPayment::query_fee_details(uxt, len)
+        }
+
+        fn query_weight_to_fee(weight: Weight) -> Balance {
+            TransactionPayment::weight_to_fee(weight)
+        }
+
+        fn query_length_to_fee(length: u32) -> Balance {
+            TransactionPayment::length_to_fee(length)
+        }
+    }
+
+    impl self::MediChainApi<Block> for Runtime {
+        fn get_role(account: AccountId) -> Option<pallet_access_control::Role> {
+            AccessControl::current_role(&account)
+        }
+
+        fn has_valid_access(patient: AccountId, accessor: AccountId) -> bool {
+            AccessControl::has_valid_access(&patient, &accessor)
+        }
+
+        fn guardianship(
+            patient: AccountId,
+            guardian: AccountId,
+        ) -> Option<pallet_access_control::Guardianship<Runtime>> {
+            AccessControl::guardianship(patient, guardian)
+        }
+
+        fn consent_directives(patient: AccountId) -> pallet_access_control::ConsentDirectives {
+            AccessControl::consent_directives(&patient)
+        }
+
+        fn disclosure(patient: AccountId, viewer: AccountId) -> pallet_access_control::Disclosure {
+            AccessControl::disclosure(&patient, &viewer)
+        }
+
+        fn break_glass_review(
+            review_id: u32,
+        ) -> Option<pallet_access_control::BreakGlassReview<Runtime>> {
+            AccessControl::break_glass_review(review_id)
+        }
+
+        fn misuse_count(provider: AccountId) -> u32 {
+            AccessControl::misuse_count(provider)
+        }
+
+        fn credential(provider: AccountId) -> Option<pallet_access_control::Credential<Runtime>> {
+            AccessControl::credential(provider)
+        }
+
+        fn facility(
+            facility_id: pallet_access_control::FacilityId,
+        ) -> Option<pallet_access_control::Facility<Runtime>> {
+            AccessControl::facility(facility_id)
+        }
+
+        fn facility_role(
+            facility_id: pallet_access_control::FacilityId,
+            account: AccountId,
+        ) -> Option<pallet_access_control::Role> {
+            AccessControl::facility_role(facility_id, account)
+        }
+
+        fn identity(account: AccountId) -> Option<pallet_patient_identity::Identity<Runtime>> {
+            PatientIdentity::identities(account)
+        }
+
+        fn id_to_account(
+            id_type: pallet_patient_identity::NationalIdType,
+            id_hash: [u8; 32],
+        ) -> Option<AccountId> {
+            PatientIdentity::id_to_account(id_type, id_hash)
+        }
+
+        fn verification_level(
+            account: AccountId,
+        ) -> Option<pallet_patient_identity::VerificationLevel> {
+            PatientIdentity::verification_level(&account)
+        }
+
+        fn health_record(patient: AccountId) -> Option<pallet_medical_records::HealthRecord<Runtime>> {
+            MedicalRecords::health_records(patient)
+        }
+
+        fn active_alerts(patient: AccountId) -> Vec<pallet_medical_records::MedicalAlert<Runtime>> {
+            MedicalRecords::active_alerts(&patient)
+        }
+
+        fn ipfs_hash_at(patient: AccountId, version: u32) -> Option<Vec<u8>> {
+            MedicalRecords::ipfs_hash_at(&patient, version).map(|hash| hash.into_inner())
+        }
+
+        fn record_version(
+            patient: AccountId,
+            version: u32,
+        ) -> Option<pallet_medical_records::RecordVersion<Runtime>> {
+            MedicalRecords::record_history(patient, version)
+        }
+
+        fn documents(
+            patient: AccountId,
+            start: u32,
+            limit: u32,
+        ) -> Vec<(u32, pallet_medical_records::MedicalDocument<Runtime>)> {
+            MedicalRecords::patient_documents(&patient, start, limit)
+        }
+
+        fn audit_log(
+            patient: AccountId,
+            start: u32,
+            limit: u32,
+        ) -> Vec<(u32, pallet_access_control::AuditEntry<Runtime>)> {
+            AccessControl::audit_log(&patient, start, limit)
+        }
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    impl frame_benchmarking::Benchmark<Block> for Runtime {
+        fn benchmark_metadata(extra: bool) -> (
+            Vec<frame_benchmarking::BenchmarkList>,
+            Vec<frame_support::traits::StorageInfo>,
+        ) {
+            use frame_benchmarking::{Benchmarking, BenchmarkList};
+            use frame_support::traits::StorageInfoTrait;
+
+            let mut list = Vec::<BenchmarkList>::new();
+            list_benchmarks!(list, extra);
+
+            let storage_info = AllPalletsWithSystem::storage_info();
+            (list, storage_info)
+        }
+
+        fn dispatch_benchmark(
+            config: frame_benchmarking::BenchmarkConfig,
+        ) -> Result<Vec<frame_benchmarking::BenchmarkBatch>, sp_runtime::RuntimeString> {
+            use frame_benchmarking::{Benchmarking, BenchmarkBatch};
+            use frame_support::traits::WhitelistedStorageKeys;
+            use sp_core::storage::TrackedStorageKey;
+
+            let whitelist: Vec<TrackedStorageKey> =
+                AllPalletsWithSystem::whitelisted_storage_keys();
+            let mut batches = Vec::<BenchmarkBatch>::new();
+            let params = (&config, &whitelist);
+            add_benchmarks!(params, batches);
+
+            Ok(batches)
+        }
+    }
+
+    impl sp_genesis_builder::GenesisBuilder<Block> for Runtime {
+        fn build_state(config: Vec<u8>) -> sp_genesis_builder::Result {
+            build_state::<RuntimeGenesisConfig>(config)
+        }
+
+        fn get_preset(id: &Option<PresetId>) -> Option<Vec<u8>> {
+            get_preset::<RuntimeGenesisConfig>(id, genesis_config_presets::get_preset)
+        }
+
+        fn preset_names() -> Vec<PresetId> {
+            genesis_config_presets::preset_names()
+        }
+    }
+}
@@ -1 +0,0 @@
-// Mock file for runtime
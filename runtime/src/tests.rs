@@ -1,5 +1,436 @@
 // Tests for runtime
+#![cfg(test)]
+
+use crate::{
+    genesis_config_presets, runtime_decl_for_medi_chain_api::MediChainApi, AccessControl, Block,
+    Executive, MedicalRecords, PatientIdentity, Runtime, RuntimeCall, RuntimeGenesisConfig,
+    RuntimeOrigin, SignedExtra, SignedPayload, System, UncheckedExtrinsic, SS58_PREFIX, VERSION,
+};
+use frame_support::genesis_builder_helper::build_state;
+use parity_scale_codec::Encode;
+use sp_genesis_builder::PresetId;
+use sp_keyring::Sr25519Keyring;
+use sp_runtime::{
+    generic::Era,
+    transaction_validity::{InvalidTransaction, TransactionSource},
+};
+
+/// Apply a preset patch on top of the default genesis config, as the node does
+fn genesis_from_preset(id: &'static str) -> Vec<u8> {
+    let preset = genesis_config_presets::get_preset(&PresetId::from(id)).expect("preset exists");
+    let patch: serde_json::Value = serde_json::from_slice(&preset).unwrap();
+    let mut config = serde_json::to_value(RuntimeGenesisConfig::default()).unwrap();
+
+    // Presets only patch pallet-level fields (Rule 1: no recursive merge)
+    for (pallet, fields) in patch.as_object().unwrap() {
+        for (field, value) in fields.as_object().unwrap() {
+            config[pallet.as_str()][field.as_str()] = value.clone();
+        }
+    }
+    serde_json::to_vec(&config).unwrap()
+}
+
 #[test]
 fn dummy_test() {
     assert_eq!(2 + 2, 4);
 }
+
+/// Node relies on the `development` and `local_testnet` presets for `--dev` / `--chain local`
+#[test]
+fn genesis_presets_are_exposed() {
+    let names = genesis_config_presets::preset_names();
+    assert!(names.contains(&PresetId::from(sp_genesis_builder::DEV_RUNTIME_PRESET)));
+    assert!(names.contains(&PresetId::from(
+        sp_genesis_builder::LOCAL_TESTNET_RUNTIME_PRESET
+    )));
+    assert!(genesis_config_presets::get_preset(&PresetId::from("unknown")).is_none());
+}
+
+/// Development preset builds a valid genesis state with endowed accounts
+#[test]
+fn development_preset_builds_genesis_state() {
+    let genesis = genesis_from_preset(sp_genesis_builder::DEV_RUNTIME_PRESET);
+
+    sp_io::TestExternalities::default().execute_with(|| {
+        assert!(build_state::<RuntimeGenesisConfig>(genesis).is_ok());
+
+        let alice = Sr25519Keyring::Alice.to_account_id();
+        assert!(System::account(&alice).data.free > 0);
+        assert_eq!(pallet_aura::Authorities::<Runtime>::get().len(), 1);
+    });
+}
+
+/// Presets seed Admin and provider roles so a fresh chain can bootstrap
+#[test]
+fn presets_seed_access_control_roles() {
+    use pallet_access_control::{Pallet as AccessControl, Role};
+
+    let genesis = genesis_from_preset(sp_genesis_builder::DEV_RUNTIME_PRESET);
+    sp_io::TestExternalities::default().execute_with(|| {
+        assert!(build_state::<RuntimeGenesisConfig>(genesis).is_ok());
+
+        let alice = Sr25519Keyring::Alice.to_account_id();
+        let bob = Sr25519Keyring::Bob.to_account_id();
+        assert!(AccessControl::<Runtime>::is_admin(&alice));
+        assert_eq!(AccessControl::<Runtime>::get_role(&bob), Some(Role::Doctor));
+
+        // Providers are seeded with a credential at the development hospital
+        assert_eq!(AccessControl::<Runtime>::facility(0).unwrap().admin, alice);
+        let credential = AccessControl::<Runtime>::credential(&bob).unwrap();
+        assert_eq!(credential.facility_id, 0);
+        assert!(AccessControl::<Runtime>::is_healthcare_provider(&bob));
+    });
+
+    let genesis = genesis_from_preset(sp_genesis_builder::LOCAL_TESTNET_RUNTIME_PRESET);
+    sp_io::TestExternalities::default().execute_with(|| {
+        assert!(build_state::<RuntimeGenesisConfig>(genesis).is_ok());
+
+        let bob = Sr25519Keyring::Bob.to_account_id();
+        assert!(AccessControl::<Runtime>::is_admin(&bob));
+    });
+}
+
+/// MediChainApi answers role, access, identity and record queries from genesis state
+#[test]
+fn medichain_api_queries_state() {
+    use pallet_access_control::Role;
+
+    let genesis = genesis_from_preset(sp_genesis_builder::DEV_RUNTIME_PRESET);
+    sp_io::TestExternalities::default().execute_with(|| {
+        assert!(build_state::<RuntimeGenesisConfig>(genesis).is_ok());
+
+        let alice = Sr25519Keyring::Alice.to_account_id();
+        let ferdie = Sr25519Keyring::Ferdie.to_account_id();
+
+        assert_eq!(
+            <Runtime as MediChainApi<Block>>::get_role(alice.clone()),
+            Some(Role::Admin)
+        );
+        assert_eq!(
+            <Runtime as MediChainApi<Block>>::get_role(ferdie.clone()),
+            None
+        );
+        assert!(!<Runtime as MediChainApi<Block>>::has_valid_access(
+            ferdie.clone(),
+            alice.clone()
+        ));
+        assert!(<Runtime as MediChainApi<Block>>::identity(ferdie.clone()).is_none());
+        assert!(<Runtime as MediChainApi<Block>>::health_record(ferdie.clone()).is_none());
+        assert!(<Runtime as MediChainApi<Block>>::active_alerts(ferdie.clone()).is_empty());
+
+        // Bob (Doctor) registers Ferdie and takes emergency access
+        System::set_block_number(1);
+        let bob = Sr25519Keyring::Bob.to_account_id();
+        let id_hash = [7u8; 32];
+        assert!(PatientIdentity::register_patient(
+            RuntimeOrigin::signed(bob.clone()),
+            ferdie.clone(),
+            pallet_patient_identity::NationalIdType::FaydaID,
+            id_hash,
+        )
+        .is_ok());
+        assert!(AccessControl::grant_emergency_access(
+            RuntimeOrigin::signed(bob.clone()),
+            ferdie.clone(),
+            [1u8; 32],
+        )
+        .is_ok());
+
+        assert_eq!(
+            <Runtime as MediChainApi<Block>>::get_role(ferdie.clone()),
+            Some(Role::Patient)
+        );
+        assert_eq!(
+            <Runtime as MediChainApi<Block>>::id_to_account(
+                pallet_patient_identity::NationalIdType::FaydaID,
+                id_hash
+            ),
+            Some(ferdie.clone())
+        );
+        assert!(<Runtime as MediChainApi<Block>>::id_to_account(
+            pallet_patient_identity::NationalIdType::Passport,
+            id_hash
+        )
+        .is_none());
+        let identity = <Runtime as MediChainApi<Block>>::identity(ferdie.clone()).unwrap();
+        assert_eq!(identity.registered_by, bob);
+        assert_eq!(
+            <Runtime as MediChainApi<Block>>::verification_level(ferdie.clone()),
+            Some(pallet_patient_identity::VerificationLevel::SelfAsserted)
+        );
+        let audit = <Runtime as MediChainApi<Block>>::audit_log(ferdie.clone(), 0, 10);
+        assert_eq!(audit.len(), 1);
+        assert_eq!(audit[0].1.who, bob);
+
+        assert!(MedicalRecords::add_document(
+            RuntimeOrigin::signed(bob.clone()),
+            ferdie.clone(),
+            pallet_medical_records::DocumentType::LabResult,
+            b"QmContent".to_vec(),
+            b"QmMetadata".to_vec(),
+            [5u8; 32],
+        )
+        .is_ok());
+        let documents = <Runtime as MediChainApi<Block>>::documents(ferdie.clone(), 0, 10);
+        assert_eq!(documents.len(), 1);
+        assert_eq!(documents[0].1.uploaded_by, bob);
+        assert!(<Runtime as MediChainApi<Block>>::has_valid_access(
+            ferdie.clone(),
+            bob.clone()
+        ));
+
+        // Ferdie names a relative without a role as healthcare proxy
+        let proxy = Sr25519Keyring::One.to_account_id();
+        assert!(
+            <Runtime as MediChainApi<Block>>::guardianship(ferdie.clone(), proxy.clone()).is_none()
+        );
+        assert!(AccessControl::appoint_guardian(
+            RuntimeOrigin::signed(ferdie.clone()),
+            ferdie.clone(),
+            proxy.clone(),
+            pallet_access_control::GuardianRelationship::HealthcareProxy,
+            pallet_access_control::GuardianScopes::ALL,
+            None,
+        )
+        .is_ok());
+        let guardianship =
+            <Runtime as MediChainApi<Block>>::guardianship(ferdie.clone(), proxy).unwrap();
+        assert_eq!(guardianship.appointed_by, Some(ferdie.clone()));
+
+        // Ferdie withholds sensitive conditions from non-treating providers
+        let directives = pallet_access_control::ConsentDirectives {
+            restrict_sensitive_to_treating: true,
+            ..Default::default()
+        };
+        assert!(AccessControl::set_consent_directives(
+            RuntimeOrigin::signed(ferdie.clone()),
+            ferdie.clone(),
+            directives,
+        )
+        .is_ok());
+        assert_eq!(
+            <Runtime as MediChainApi<Block>>::consent_directives(ferdie.clone()),
+            directives
+        );
+        let disclosure = <Runtime as MediChainApi<Block>>::disclosure(ferdie.clone(), bob.clone());
+        assert!(disclosure.emergency_subset);
+        assert!(!disclosure.sensitive_conditions);
+
+        // Bob's emergency grant opened a review, which he justifies
+        let review = <Runtime as MediChainApi<Block>>::break_glass_review(0).unwrap();
+        assert_eq!(review.accessor, bob);
+        assert_eq!(
+            review.status,
+            pallet_access_control::ReviewStatus::AwaitingJustification
+        );
+        assert!(AccessControl::submit_justification(
+            RuntimeOrigin::signed(bob.clone()),
+            0,
+            [9u8; 32],
+        )
+        .is_ok());
+        assert_eq!(
+            <Runtime as MediChainApi<Block>>::break_glass_review(0)
+                .unwrap()
+                .status,
+            pallet_access_control::ReviewStatus::Justified
+        );
+        assert_eq!(
+            <Runtime as MediChainApi<Block>>::misuse_count(bob.clone()),
+            0
+        );
+
+        // The ministry renews Bob's medical licence
+        assert!(AccessControl::register_credential(
+            RuntimeOrigin::root(),
+            bob.clone(),
+            [3u8; 32],
+            pallet_access_control::IssuingBody::MedicalCouncil,
+            2 * 365 * crate::DAYS,
+            0,
+        )
+        .is_ok());
+        let credential = <Runtime as MediChainApi<Block>>::credential(bob.clone()).unwrap();
+        assert_eq!(credential.licence_id_hash, [3u8; 32]);
+
+        // The ministry registers a hospital run by Bob and hires Charlie (a
+        // licensed nurse) there; a facility Admin cannot hire licensed staff
+        let charlie = Sr25519Keyring::Charlie.to_account_id();
+        let hospital = AccessControl::next_facility_id();
+        assert!(AccessControl::register_facility(
+            RuntimeOrigin::root(),
+            pallet_access_control::FacilityKind::Hospital,
+            b"Mulago Hospital".to_vec(),
+            bob.clone(),
+        )
+        .is_ok());
+        assert_eq!(
+            <Runtime as MediChainApi<Block>>::facility(hospital)
+                .unwrap()
+                .admin,
+            bob
+        );
+        assert!(AccessControl::add_facility_staff(
+            RuntimeOrigin::signed(bob),
+            hospital,
+            charlie.clone(),
+            pallet_access_control::Role::Nurse,
+        )
+        .is_err());
+        assert!(AccessControl::add_facility_staff(
+            RuntimeOrigin::root(),
+            hospital,
+            charlie.clone(),
+            pallet_access_control::Role::Nurse,
+        )
+        .is_ok());
+        assert_eq!(
+            <Runtime as MediChainApi<Block>>::facility_role(hospital, charlie),
+            Some(pallet_access_control::Role::Nurse)
+        );
+    });
+}
+
+/// Role changes need M-of-N ministry council approval, not a single Admin
+#[test]
+fn role_changes_require_ministry_council_approval() {
+    use crate::MinistryCouncil;
+    use frame_support::weights::Weight;
+    use pallet_access_control::Role;
+    use parity_scale_codec::Encode;
+    use sp_runtime::{traits::Hash, DispatchError};
+
+    let genesis = genesis_from_preset(sp_genesis_builder::LOCAL_TESTNET_RUNTIME_PRESET);
+    sp_io::TestExternalities::default().execute_with(|| {
+        assert!(build_state::<RuntimeGenesisConfig>(genesis).is_ok());
+        System::set_block_number(1);
+
+        let alice = Sr25519Keyring::Alice.to_account_id();
+        let bob = Sr25519Keyring::Bob.to_account_id();
+        // Ministry official without a role yet
+        let official = crate::AccountId::from([9u8; 32]);
+        // Genesis Admins (Alice, Bob) form the council
+        let council = pallet_collective::Members::<Runtime, crate::MinistryCollective>::get();
+        assert_eq!(council.len(), 2);
+        assert!(council.contains(&alice) && council.contains(&bob));
+
+        // A single Admin can no longer promote anyone
+        assert_eq!(
+            AccessControl::assign_role(
+                RuntimeOrigin::signed(alice.clone()),
+                official.clone(),
+                Role::Admin,
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+
+        let proposal = RuntimeCall::AccessControl(pallet_access_control::Call::assign_role {
+            account: official.clone(),
+            role: Role::Admin,
+        });
+        let length = proposal.encoded_size() as u32;
+        let hash = <Runtime as frame_system::Config>::Hashing::hash_of(&proposal);
+
+        assert!(MinistryCouncil::propose(
+            RuntimeOrigin::signed(alice.clone()),
+            2,
+            Box::new(proposal),
+            length,
+        )
+        .is_ok());
+        assert!(MinistryCouncil::vote(RuntimeOrigin::signed(alice.clone()), hash, 0, true).is_ok());
+
+        // One of two approvals is not enough
+        assert!(MinistryCouncil::close(
+            RuntimeOrigin::signed(alice.clone()),
+            hash,
+            0,
+            Weight::MAX,
+            length,
+        )
+        .is_err());
+
+        assert!(MinistryCouncil::vote(RuntimeOrigin::signed(bob), hash, 0, true).is_ok());
+        assert!(
+            MinistryCouncil::close(RuntimeOrigin::signed(alice), hash, 0, Weight::MAX, length,)
+                .is_ok()
+        );
+        assert!(AccessControl::is_admin(&official));
+    });
+}
+
+/// Build a signed `System::remark` as a wallet would
+fn signed_remark(signer: Sr25519Keyring, claimed: Sr25519Keyring) -> UncheckedExtrinsic {
+    let call = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+    let extra: SignedExtra = (
+        frame_system::CheckNonZeroSender::new(),
+        frame_system::CheckSpecVersion::new(),
+        frame_system::CheckTxVersion::new(),
+        frame_system::CheckGenesis::new(),
+        frame_system::CheckMortality::from(Era::Immortal),
+        frame_system::CheckNonce::from(0),
+        frame_system::CheckWeight::new(),
+        pallet_transaction_payment::ChargeTransactionPayment::from(0),
+    );
+    let payload = SignedPayload::new(call, extra).unwrap();
+    let signature = payload.using_encoded(|bytes| signer.sign(bytes));
+    let (call, extra, _) = payload.deconstruct();
+
+    UncheckedExtrinsic::new_signed(
+        call,
+        claimed.to_account_id().into(),
+        signature.into(),
+        extra,
+    )
+}
+
+/// Signed extrinsics are only valid when the signature matches the claimed account
+#[test]
+fn signed_extrinsics_require_valid_signature() {
+    let genesis = genesis_from_preset(sp_genesis_builder::DEV_RUNTIME_PRESET);
+    sp_io::TestExternalities::default().execute_with(|| {
+        assert!(build_state::<RuntimeGenesisConfig>(genesis).is_ok());
+        let parent_hash = System::block_hash(0);
+
+        let valid = signed_remark(Sr25519Keyring::Alice, Sr25519Keyring::Alice);
+        assert!(
+            Executive::validate_transaction(TransactionSource::External, valid, parent_hash)
+                .is_ok()
+        );
+
+        // Bob cannot act as Alice (e.g. to impersonate an Admin)
+        let forged = signed_remark(Sr25519Keyring::Bob, Sr25519Keyring::Alice);
+        assert_eq!(
+            Executive::validate_transaction(TransactionSource::External, forged, parent_hash),
+            Err(InvalidTransaction::BadProof.into())
+        );
+    });
+}
+
+/// Addresses use the SS58 prefix advertised by the runtime
+#[test]
+fn ss58_prefix_is_configured() {
+    assert_eq!(
+        <Runtime as frame_system::Config>::SS58Prefix::get(),
+        SS58_PREFIX
+    );
+}
+
+/// Emergency access policy gives Doctors longer windows than Nurses
+#[test]
+fn emergency_access_durations_are_configured() {
+    use crate::{
+        DefaultAccessDuration, DoctorAccessDuration, JustificationPeriod, NurseAccessDuration,
+    };
+
+    assert!(DoctorAccessDuration::get() > DefaultAccessDuration::get());
+    assert!(NurseAccessDuration::get() < DefaultAccessDuration::get());
+    // Justifications are written after the emergency, not during it
+    assert!(JustificationPeriod::get() > DoctorAccessDuration::get());
+}
+
+/// Runtime identifies itself as MediChain to the node
+#[test]
+fn runtime_version_is_medichain() {
+    assert_eq!(VERSION.spec_name, "medichain".into());
+}